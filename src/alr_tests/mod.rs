@@ -0,0 +1,47 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn alr_imm() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ALR_IMM),
+            (PC_START + 1, 0x03),
+        ]);
+        cpu.set_accumulator(0xFF);
+
+        cpu.exe(Some(instructions::ALR_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x01);
+        assert_eq!(cpu.get_cycles(), instructions::ALR_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C]).is_ok());
+    }
+
+    #[test]
+    fn alr_imm_wzero() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ALR_IMM),
+            (PC_START + 1, 0xF0),
+        ]);
+        cpu.set_accumulator(0x0F);
+
+        cpu.exe(Some(instructions::ALR_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::ALR_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z]).is_ok());
+    }
+}
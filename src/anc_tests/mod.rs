@@ -0,0 +1,48 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn anc_imm() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ANC_IMM),
+            (PC_START + 1, 0x80),
+        ]);
+        cpu.set_accumulator(0xFF);
+
+        cpu.exe(Some(instructions::ANC_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::ANC_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn anc_imm_2b_wzero() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ANC_IMM_2B),
+            (PC_START + 1, 0x70),
+        ]);
+        cpu.set_accumulator(0x0F);
+        cpu.set_status_flag(Mos6502Flags::C);
+
+        cpu.exe(Some(instructions::ANC_IMM_2B_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::ANC_IMM_2B_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z]).is_ok());
+    }
+}
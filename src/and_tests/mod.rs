@@ -0,0 +1,248 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn and_imm() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_IMM),
+                (PC_START + 1, 0x9f),
+            ],
+            0x90,
+            instructions::AND_IMM_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_imm_wzero() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_IMM),
+                (PC_START + 1, 0x0f),
+            ],
+            0x00,
+            instructions::AND_IMM_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::Z],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_zp() {
+        let addr = 0x42;
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ZP),
+                (PC_START + 1, addr),
+                (Word::from(addr), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ZP_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_zpx_wwrap() {
+        let (addr, xreg) = (0x80, 0xFF);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ZPX),
+                (PC_START + 1, addr),
+                (tests_utils::word_from_byte_addition(addr, xreg), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ZPX_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ABS_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_absx_wopage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x80, 1);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(xreg), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ABSX_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_absx_wpage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x02, 0xFF);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(xreg), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ABSX_CCOST + 1,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_absy_wopage_boundary() {
+        let (hi, lo, yreg) = (0x44, 0x80, 1);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ABSY),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ABSY_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_absy_wpage_boundary() {
+        let (hi, lo, yreg) = (0x44, 0x02, 0xFF);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ABSY),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ABSY_CCOST + 1,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_zpx_ind() {
+        let (hi, lo, xreg, arb_addr) = (0x80, 0x00, 0x04, 0x02);
+        let dest_addr = tests_utils::word_from_byte_addition(arb_addr, xreg);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ZPX_IND),
+                (PC_START + 1, arb_addr),
+                (dest_addr, lo),
+                (dest_addr + 1, hi),
+                (tests_utils::word_from_bytes(hi, lo), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ZPX_IND_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_zpy_ind_wopage_boundary() {
+        let (hi, lo, yreg, arb_addr) = (0x80, 0x00, 0x04, 0x02);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ZPY_IND),
+                (PC_START + 1, arb_addr),
+                (Word::from(arb_addr), lo),
+                (Word::from(arb_addr) + 1, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ZPY_IND_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_zpy_ind_wpage_boundary() {
+        let (hi, lo, yreg, arb_addr) = (0x80, 0x02, 0xFF, 0x02);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::AND_ZPY_IND),
+                (PC_START + 1, arb_addr),
+                (Word::from(arb_addr), lo),
+                (Word::from(arb_addr) + 1, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0x9f),
+            ],
+            0x90,
+            instructions::AND_ZPY_IND_CCOST + 1,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0xf0); })
+        );
+    }
+
+    #[test]
+    fn and_zp_ind() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::AND_ZP_IND),
+            (PC_START + 1, 0x20),
+            (0x0020, 0x00),
+            (0x0021, 0x44),
+            (0x4400, 0x00),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0xFF);
+
+        cpu.exe(Some(instructions::AND_ZP_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::AND_ZP_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z]).is_ok());
+    }
+}
@@ -0,0 +1,66 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn ane_imm() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ANE_IMM),
+            (PC_START + 1, 0xF3),
+        ]);
+        cpu.set_accumulator(0x01);
+        cpu.set_xreg(0x7F);
+
+        cpu.exe(Some(instructions::ANE_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x63);
+        assert_eq!(cpu.get_cycles(), instructions::ANE_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn ane_imm_wmagic() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ANE_IMM),
+            (PC_START + 1, 0xFF),
+        ]);
+        cpu.config_mut().magic = 0xFF;
+        cpu.set_xreg(0x80);
+
+        cpu.exe(Some(instructions::ANE_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::ANE_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn ane_imm_wzero() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ANE_IMM),
+            (PC_START + 1, 0xFF),
+        ]);
+        cpu.config_mut().magic = 0x00;
+        cpu.set_accumulator(0x0F);
+        cpu.set_xreg(0xF0);
+
+        cpu.exe(Some(instructions::ANE_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::ANE_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z]).is_ok());
+    }
+}
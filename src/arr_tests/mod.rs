@@ -0,0 +1,81 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn arr_imm() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ARR_IMM),
+            (PC_START + 1, 0xFF),
+        ]);
+        cpu.set_accumulator(0xFF);
+        cpu.set_status_flag(Mos6502Flags::C);
+
+        cpu.exe(Some(instructions::ARR_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0xFF);
+        assert_eq!(cpu.get_cycles(), instructions::ARR_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn arr_imm_woverflow() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ARR_IMM),
+            (PC_START + 1, 0x40),
+        ]);
+        cpu.set_accumulator(0xFF);
+
+        cpu.exe(Some(instructions::ARR_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x20);
+        assert_eq!(cpu.get_cycles(), instructions::ARR_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::V]).is_ok());
+    }
+
+    #[test]
+    fn arr_imm_wdecimal() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ARR_IMM),
+            (PC_START + 1, 0xFF),
+        ]);
+        cpu.set_accumulator(0xFF);
+        cpu.set_status_flag(Mos6502Flags::D);
+
+        cpu.exe(Some(instructions::ARR_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0xD5);
+        assert_eq!(cpu.get_cycles(), instructions::ARR_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::D]).is_ok());
+    }
+
+    #[test]
+    fn arr_imm_wricoh_ignores_decimal() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::ARR_IMM),
+            (PC_START + 1, 0xFF),
+        ], Mos6502Variant::Ricoh2A03);
+        cpu.set_accumulator(0xFF);
+        cpu.set_status_flag(Mos6502Flags::D);
+
+        cpu.exe(Some(instructions::ARR_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x7F);
+        assert_eq!(cpu.get_cycles(), instructions::ARR_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::D]).is_ok());
+    }
+}
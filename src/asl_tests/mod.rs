@@ -0,0 +1,175 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn asl_acc() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::ASL_ACC),
+            ],
+            0x82,
+            instructions::ASL_ACC_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x41); })
+        );
+    }
+
+    #[test]
+    fn asl_acc_wcarry() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::ASL_ACC),
+            ],
+            0x00,
+            instructions::ASL_ACC_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C, Mos6502Flags::Z],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn asl_zp() {
+        let addr = 0x42;
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::ASL_ZP),
+                (PC_START + 1, addr),
+                (Word::from(addr), 0xc1),
+            ],
+            Word::from(addr),
+            0x82,
+            instructions::ASL_ZP_CCOST,
+            vec![Mos6502Flags::C, Mos6502Flags::N],
+            None::<fn(&mut Mos6502)>
+        );
+    }
+
+    #[test]
+    fn asl_zpx_wwrap() {
+        let (addr, xreg) = (0x80, 0xFF);
+        let dest_addr = tests_utils::word_from_byte_addition(addr, xreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::ASL_ZPX),
+                (PC_START + 1, addr),
+                (dest_addr, 0xc1),
+            ],
+            dest_addr,
+            0x82,
+            instructions::ASL_ZPX_CCOST,
+            vec![Mos6502Flags::C, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); })
+        );
+    }
+
+    #[test]
+    fn asl_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::ASL_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (dest_addr, 0xc1),
+            ],
+            dest_addr,
+            0x82,
+            instructions::ASL_ABS_CCOST,
+            vec![Mos6502Flags::C, Mos6502Flags::N],
+            None::<fn(&mut Mos6502)>
+        );
+    }
+
+    #[test]
+    fn asl_absx_wopage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x80, 1);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(xreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::ASL_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (dest_addr, 0xc1),
+            ],
+            dest_addr,
+            0x82,
+            instructions::ASL_ABSX_CCOST,
+            vec![Mos6502Flags::C, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); })
+        );
+    }
+
+    #[test]
+    fn asl_absx_wpage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x02, 0xFF);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(xreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::ASL_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (dest_addr, 0xc1),
+            ],
+            dest_addr,
+            0x82,
+            instructions::ASL_ABSX_CCOST,
+            vec![Mos6502Flags::C, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); })
+        );
+    }
+    #[test]
+    fn asl_absx_wcmos() {
+        let (hi, lo, xreg) = (0x44, 0x80, 1);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(xreg);
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::ASL_ABSX),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (dest_addr, 0xc1),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_xreg(xreg);
+
+        cpu.exe(Some(instructions::ASL_ABSX_CMOS_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(dest_addr)), 0x82);
+        assert_eq!(cpu.get_cycles(), instructions::ASL_ABSX_CMOS_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn asl_absx_wcmos_wpage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x02, 0xFF);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(xreg);
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::ASL_ABSX),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (dest_addr, 0xc1),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_xreg(xreg);
+
+        cpu.exe(Some(instructions::ASL_ABSX_CMOS_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(dest_addr)), 0x82);
+        assert_eq!(cpu.get_cycles(), instructions::ASL_ABSX_CMOS_CCOST + 1);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::N]).is_ok());
+    }
+}
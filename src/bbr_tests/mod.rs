@@ -0,0 +1,82 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn bbr0_zp_rel() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x4400, instructions::BBR0_ZP_REL),
+            (0x4400 + 1, 0x42),
+            (0x4400 + 2, 0x10),
+            (0x0042, 0xFE),
+        ], Mos6502Variant::Rockwell65C02);
+        cpu.set_pc(0x4400);
+
+        cpu.exe(Some(instructions::BBR0_ZP_REL_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4413);
+        assert_eq!(cpu.get_cycles(), instructions::BBR0_ZP_REL_CCOST + 1);
+    }
+
+    #[test]
+    fn bbr0_zp_rel_wnot_taken() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x4400, instructions::BBR0_ZP_REL),
+            (0x4400 + 1, 0x42),
+            (0x4400 + 2, 0x10),
+            (0x0042, 0x01),
+        ], Mos6502Variant::Rockwell65C02);
+        cpu.set_pc(0x4400);
+
+        cpu.exe(Some(instructions::BBR0_ZP_REL_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4403);
+        assert_eq!(cpu.get_cycles(), instructions::BBR0_ZP_REL_CCOST);
+    }
+
+    #[test]
+    fn bbr7_zp_rel_wpage_boundary() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x44F0, instructions::BBR7_ZP_REL),
+            (0x44F0 + 1, 0x42),
+            (0x44F0 + 2, 0x20),
+            (0x0042, 0x7F),
+        ], Mos6502Variant::Rockwell65C02);
+        cpu.set_pc(0x44F0);
+
+        cpu.exe(Some(instructions::BBR7_ZP_REL_CCOST + 2)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4513);
+        assert_eq!(cpu.get_cycles(), instructions::BBR7_ZP_REL_CCOST + 2);
+    }
+
+    #[test]
+    fn bbr4_zp_rel_wbackward() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x4450, instructions::BBR4_ZP_REL),
+            (0x4450 + 1, 0x42),
+            (0x4450 + 2, 0xF0),
+            (0x0042, 0xEF),
+        ], Mos6502Variant::Rockwell65C02);
+        cpu.set_pc(0x4450);
+
+        cpu.exe(Some(instructions::BBR4_ZP_REL_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4443);
+        assert_eq!(cpu.get_cycles(), instructions::BBR4_ZP_REL_CCOST + 1);
+    }
+}
@@ -0,0 +1,66 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn bbs0_zp_rel() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x4400, instructions::BBS0_ZP_REL),
+            (0x4400 + 1, 0x42),
+            (0x4400 + 2, 0x10),
+            (0x0042, 0x01),
+        ], Mos6502Variant::Rockwell65C02);
+        cpu.set_pc(0x4400);
+
+        cpu.exe(Some(instructions::BBS0_ZP_REL_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4413);
+        assert_eq!(cpu.get_cycles(), instructions::BBS0_ZP_REL_CCOST + 1);
+    }
+
+    #[test]
+    fn bbs0_zp_rel_wnot_taken() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x4400, instructions::BBS0_ZP_REL),
+            (0x4400 + 1, 0x42),
+            (0x4400 + 2, 0x10),
+            (0x0042, 0xFE),
+        ], Mos6502Variant::Rockwell65C02);
+        cpu.set_pc(0x4400);
+
+        cpu.exe(Some(instructions::BBS0_ZP_REL_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4403);
+        assert_eq!(cpu.get_cycles(), instructions::BBS0_ZP_REL_CCOST);
+    }
+
+    #[test]
+    fn bbs7_zp_rel_wpage_boundary() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x44F0, instructions::BBS7_ZP_REL),
+            (0x44F0 + 1, 0x42),
+            (0x44F0 + 2, 0x20),
+            (0x0042, 0x80),
+        ], Mos6502Variant::Rockwell65C02);
+        cpu.set_pc(0x44F0);
+
+        cpu.exe(Some(instructions::BBS7_ZP_REL_CCOST + 2)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4513);
+        assert_eq!(cpu.get_cycles(), instructions::BBS7_ZP_REL_CCOST + 2);
+    }
+}
@@ -0,0 +1,22 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+
+    #[test]
+    fn bcc_rel() {
+        tests_utils::branch_matrix(
+            instructions::BCC_REL,
+            instructions::BCC_REL_CCOST,
+            |_: &mut Mos6502| {},
+            |cpu: &mut Mos6502| cpu.set_status_flag(Mos6502Flags::C),
+        );
+    }
+}
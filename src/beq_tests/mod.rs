@@ -0,0 +1,22 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+
+    #[test]
+    fn beq_rel() {
+        tests_utils::branch_matrix(
+            instructions::BEQ_REL,
+            instructions::BEQ_REL_CCOST,
+            |cpu: &mut Mos6502| cpu.set_status_flag(Mos6502Flags::Z),
+            |_: &mut Mos6502| {},
+        );
+    }
+}
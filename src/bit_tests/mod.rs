@@ -0,0 +1,119 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn bit_zp() {
+        let addr = 0x42;
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::BIT_ZP),
+                (PC_START + 1, addr),
+                (Word::from(addr), 0xc0),
+            ],
+            0x3f,
+            instructions::BIT_ZP_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N, Mos6502Flags::V, Mos6502Flags::Z],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x3f); })
+        );
+    }
+
+    #[test]
+    fn bit_zp_wnonzero() {
+        let addr = 0x42;
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::BIT_ZP),
+                (PC_START + 1, addr),
+                (Word::from(addr), 0x41),
+            ],
+            0x01,
+            instructions::BIT_ZP_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::V],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x01); })
+        );
+    }
+
+    #[test]
+    fn bit_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::BIT_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo), 0x80),
+            ],
+            0x80,
+            instructions::BIT_ABS_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn bit_imm() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::BIT_IMM),
+            (PC_START + 1, 0xC0),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x0F);
+        cpu.set_status_flag(Mos6502Flags::V);
+
+        cpu.exe(Some(instructions::BIT_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_cycles(), instructions::BIT_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z, Mos6502Flags::V]).is_ok());
+    }
+
+    #[test]
+    fn bit_zpx() {
+        let (addr, xreg) = (0x40, 0x02);
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::BIT_ZPX),
+            (PC_START + 1, addr),
+            (0x0042, 0xC1),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_xreg(xreg);
+        cpu.set_accumulator(0x01);
+
+        cpu.exe(Some(instructions::BIT_ZPX_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_cycles(), instructions::BIT_ZPX_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N, Mos6502Flags::V]).is_ok());
+    }
+
+    #[test]
+    fn bit_absx_wpage_boundary() {
+        let (lo, hi, xreg) = (0xFF, 0x44, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::BIT_ABSX),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4500, 0x40),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_xreg(xreg);
+
+        cpu.exe(Some(instructions::BIT_ABSX_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_cycles(), instructions::BIT_ABSX_CCOST + 1);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::V, Mos6502Flags::Z]).is_ok());
+    }
+}
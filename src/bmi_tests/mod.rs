@@ -0,0 +1,22 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+
+    #[test]
+    fn bmi_rel() {
+        tests_utils::branch_matrix(
+            instructions::BMI_REL,
+            instructions::BMI_REL_CCOST,
+            |cpu: &mut Mos6502| cpu.set_status_flag(Mos6502Flags::N),
+            |_: &mut Mos6502| {},
+        );
+    }
+}
@@ -0,0 +1,53 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+
+    fn bra_once(pc: Word, offset: Byte) -> Mos6502 {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (pc, instructions::BRA_REL),
+            (pc + 1, offset),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_pc(pc);
+        cpu
+    }
+
+    #[test]
+    fn bra_rel() {
+        let mut cpu = bra_once(0x4400, 0x10);
+        cpu.exe(Some(instructions::BRA_REL_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4412);
+        assert_eq!(cpu.get_cycles(), instructions::BRA_REL_CCOST);
+    }
+
+    #[test]
+    fn bra_rel_wbackward() {
+        let mut cpu = bra_once(0x4450, 0xF0);
+        cpu.exe(Some(instructions::BRA_REL_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4442);
+        assert_eq!(cpu.get_cycles(), instructions::BRA_REL_CCOST);
+    }
+
+    #[test]
+    fn bra_rel_wpage_boundary() {
+        let mut cpu = bra_once(0x44F0, 0x20);
+        cpu.exe(Some(instructions::BRA_REL_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4512);
+        assert_eq!(cpu.get_cycles(), instructions::BRA_REL_CCOST + 1);
+    }
+}
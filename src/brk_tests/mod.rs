@@ -0,0 +1,183 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+        BusCycle,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+
+    #[test]
+    fn brk_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::BRK_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+
+        cpu.set_pc(0x0200);
+        cpu.set_status_flag(Mos6502Flags::C);
+        cpu.exe(Some(instructions::BRK_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4000);
+        assert_eq!(cpu.get_sp(), 0xFA);
+        // The return address skips the padding byte after BRK.
+        assert_eq!(cpu.mem.get_byte(0x01FD), 0x02);
+        assert_eq!(cpu.mem.get_byte(0x01FC), 0x02);
+        assert_eq!(cpu.mem.get_byte(0x01FB), 0x31);
+        assert!(cpu.interrupts_disable_flag());
+        assert!(!cpu.break_flag());
+        assert_eq!(cpu.get_cycles(), instructions::BRK_IMP_CCOST);
+    }
+
+    #[test]
+    fn brk_imp_wrti_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::BRK_IMP),
+            (0x0202, instructions::LDA_IMM),
+            (0x0203, 0x84),
+            (0x4000, instructions::RTI_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+
+        cpu.set_pc(0x0200);
+        cpu.exe(Some(
+            instructions::BRK_IMP_CCOST
+            + instructions::RTI_IMP_CCOST
+            + instructions::LDA_IMM_CCOST
+        )).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x84);
+        assert_eq!(cpu.get_pc(), 0x0204);
+        assert_eq!(cpu.get_sp(), 0xFD);
+        assert!(!cpu.interrupts_disable_flag());
+    }
+
+    #[test]
+    fn brk_imp_wirq() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::BRK_IMP),
+            (0x0202, instructions::LDA_IMM),
+            (0x0203, 0x84),
+            (0x4000, instructions::RTI_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+
+        cpu.set_pc(0x0200);
+        cpu.set_irq_line(true);
+        cpu.exe(Some(instructions::BRK_IMP_CCOST)).unwrap();
+
+        // The IRQ takes the BRK over, it returns past the BRK and
+        // its signature byte with B clear on the stack.
+        assert_eq!(cpu.get_pc(), 0x4000);
+        assert_eq!(cpu.mem.get_byte(0x01FD), 0x02);
+        assert_eq!(cpu.mem.get_byte(0x01FC), 0x02);
+        assert_eq!(cpu.mem.get_byte(0x01FB), 0x20);
+        assert_eq!(cpu.get_instructions(), 0);
+
+        cpu.set_irq_line(false);
+        cpu.exe(Some(
+            instructions::BRK_IMP_CCOST
+            + instructions::RTI_IMP_CCOST
+            + instructions::LDA_IMM_CCOST
+        )).unwrap();
+
+        // The BRK is ignored, it doesn't run after the handler either.
+        assert_eq!(cpu.get_accumulator(), 0x84);
+        assert_eq!(cpu.get_pc(), 0x0204);
+        assert_eq!(cpu.get_sp(), 0xFD);
+        assert_eq!(cpu.get_instructions(), 2);
+    }
+
+    #[test]
+    fn brk_imp_wirq_wtick() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::BRK_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+
+        cpu.set_pc(0x0200);
+        cpu.set_irq_line(true);
+        let bus: Vec<BusCycle> = (0..instructions::BRK_IMP_CCOST).map(|_| cpu.tick().unwrap()).collect();
+
+        assert!(bus.iter().all(|b| !b.sync));
+        assert_eq!((bus[5].addr, bus[6].addr), (0xFFFE, 0xFFFF));
+        assert_eq!((bus[4].addr, bus[4].data), (0x01FB, 0x20));
+        assert_eq!(cpu.get_pc(), 0x4000);
+    }
+
+    #[test]
+    fn brk_imp_wnmi_hijack() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::BRK_IMP),
+            (0xFFFA, 0x00),
+            (0xFFFB, 0x50),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+
+        cpu.set_pc(0x0200);
+        for _ in 0..5 {
+            cpu.tick().unwrap();
+        }
+        cpu.trigger_nmi();
+        let vector = cpu.tick().unwrap();
+        cpu.tick().unwrap();
+
+        // The NMI lands before the vector fetch and takes over the BRK.
+        assert_eq!(vector.addr, 0xFFFA);
+        assert_eq!(cpu.get_pc(), 0x5000);
+        assert_eq!(cpu.mem.get_byte(0x01FB), 0x30);
+        assert_eq!(cpu.get_cycles(), instructions::BRK_IMP_CCOST);
+
+        // It was serviced by the hijack and is not taken again.
+        assert!(cpu.tick().unwrap().sync);
+    }
+
+    #[test]
+    fn brk_imp_wnmi_after_vector_fetch() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::BRK_IMP),
+            (0xFFFA, 0x00),
+            (0xFFFB, 0x50),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+
+        cpu.set_pc(0x0200);
+        for _ in 0..6 {
+            cpu.tick().unwrap();
+        }
+        cpu.trigger_nmi();
+        cpu.tick().unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4000);
+    }
+
+    #[test]
+    fn brk_imp_wcmos_clears_decimal() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x0200, instructions::BRK_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ], Mos6502Variant::Cmos65C02);
+
+        cpu.set_pc(0x0200);
+        cpu.set_status_flag(Mos6502Flags::D);
+        cpu.exe(Some(instructions::BRK_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4000);
+        // D is still set in the pushed copy.
+        assert_eq!(cpu.mem.get_byte(0x01FB), 0x38);
+        assert!(cpu.interrupts_disable_flag());
+        assert!(!cpu.decimal_mode_flag());
+    }
+}
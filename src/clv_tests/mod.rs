@@ -15,7 +15,7 @@ mod tests {
         let mut cpu = tests_utils::cpu_mem_set(vec![
             (0xFFFC, instructions::CLV_IMP),
         ]);
-        cpu.exe(Some(instructions::CLV_IMP_CCOST));
+        cpu.exe(Some(instructions::CLV_IMP_CCOST)).unwrap();
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
         assert_eq!(cpu.get_cycles(), instructions::CLV_IMP_CCOST);
     }
@@ -26,7 +26,7 @@ mod tests {
             (0xFFFC, instructions::CLV_IMP),
         ]);
         cpu.set_status_flag(Mos6502Flags::V);
-        cpu.exe(Some(instructions::CLV_IMP_CCOST));
+        cpu.exe(Some(instructions::CLV_IMP_CCOST)).unwrap();
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
         assert_eq!(cpu.get_cycles(), instructions::CLV_IMP_CCOST);
     }
@@ -0,0 +1,262 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn cmp_imm() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_IMM),
+                (PC_START + 1, 0x40),
+            ],
+            0x40,
+            instructions::CMP_IMM_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::Z, Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x40); })
+        );
+    }
+
+    #[test]
+    fn cmp_imm_wless() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_IMM),
+                (PC_START + 1, 0x41),
+            ],
+            0x40,
+            instructions::CMP_IMM_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x40); })
+        );
+    }
+
+    #[test]
+    fn cmp_imm_wgreater() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_IMM),
+                (PC_START + 1, 0x40),
+            ],
+            0x41,
+            instructions::CMP_IMM_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x41); })
+        );
+    }
+
+    #[test]
+    fn cmp_zp() {
+        let addr = 0x42;
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ZP),
+                (PC_START + 1, addr),
+                (Word::from(addr), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ZP_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_zpx_wwrap() {
+        let (addr, xreg) = (0x80, 0xFF);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ZPX),
+                (PC_START + 1, addr),
+                (tests_utils::word_from_byte_addition(addr, xreg), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ZPX_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ABS_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_absx_wopage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x80, 1);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(xreg), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ABSX_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_absx_wpage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x02, 0xFF);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(xreg), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ABSX_CCOST + 1,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_absy_wopage_boundary() {
+        let (hi, lo, yreg) = (0x44, 0x80, 1);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ABSY),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ABSY_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_absy_wpage_boundary() {
+        let (hi, lo, yreg) = (0x44, 0x02, 0xFF);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ABSY),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ABSY_CCOST + 1,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_zpx_ind() {
+        let (hi, lo, xreg, arb_addr) = (0x80, 0x00, 0x04, 0x02);
+        let dest_addr = tests_utils::word_from_byte_addition(arb_addr, xreg);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ZPX_IND),
+                (PC_START + 1, arb_addr),
+                (dest_addr, lo),
+                (dest_addr + 1, hi),
+                (tests_utils::word_from_bytes(hi, lo), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ZPX_IND_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_zpy_ind_wopage_boundary() {
+        let (hi, lo, yreg, arb_addr) = (0x80, 0x00, 0x04, 0x02);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ZPY_IND),
+                (PC_START + 1, arb_addr),
+                (Word::from(arb_addr), lo),
+                (Word::from(arb_addr) + 1, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ZPY_IND_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_zpy_ind_wpage_boundary() {
+        let (hi, lo, yreg, arb_addr) = (0x80, 0x02, 0xFF, 0x02);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CMP_ZPY_IND),
+                (PC_START + 1, arb_addr),
+                (Word::from(arb_addr), lo),
+                (Word::from(arb_addr) + 1, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0x10),
+            ],
+            0x80,
+            instructions::CMP_ZPY_IND_CCOST + 1,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0x80); })
+        );
+    }
+
+    #[test]
+    fn cmp_zp_ind() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::CMP_ZP_IND),
+            (PC_START + 1, 0x20),
+            (0x0020, 0x00),
+            (0x0021, 0x44),
+            (0x4400, 0x42),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x42);
+
+        cpu.exe(Some(instructions::CMP_ZP_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_cycles(), instructions::CMP_ZP_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z, Mos6502Flags::C]).is_ok());
+    }
+}
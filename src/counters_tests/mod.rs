@@ -0,0 +1,93 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Config,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+
+    fn nop_cpu() -> Mos6502 {
+        let mut cpu = tests_utils::cpu_mem_set((0x0200..0x0300).map(|addr| (addr, instructions::NOP_IMP)).collect());
+        cpu.set_pc(0x0200);
+        cpu
+    }
+
+    #[test]
+    fn instructions_retired() {
+        let mut cpu = nop_cpu();
+        cpu.exe(Some(3 * instructions::NOP_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_instructions(), 3);
+        assert_eq!(cpu.get_cycles(), 3 * instructions::NOP_IMP_CCOST);
+    }
+
+    #[test]
+    fn instructions_retired_wirq() {
+        let mut cpu = nop_cpu();
+        *cpu.mem.at(0xFFFE) = 0x00;
+        *cpu.mem.at(0xFFFF) = 0x02;
+        cpu.set_irq_line(true);
+        cpu.exe(Some(7 + instructions::NOP_IMP_CCOST)).unwrap();
+
+        // The interrupt sequence itself is not an instruction.
+        assert_eq!(cpu.get_instructions(), 1);
+    }
+
+    #[test]
+    fn reset_counters() {
+        let mut cpu = nop_cpu();
+        cpu.set_accumulator(0x42);
+        cpu.exe(Some(4 * instructions::NOP_IMP_CCOST)).unwrap();
+
+        cpu.reset_counters();
+
+        assert_eq!(cpu.get_cycles(), 0);
+        assert_eq!(cpu.get_instructions(), 0);
+        assert_eq!(cpu.get_pc(), 0x0204);
+        assert_eq!(cpu.get_accumulator(), 0x42);
+
+        cpu.exe(Some(instructions::NOP_IMP_CCOST)).unwrap();
+        assert_eq!(cpu.get_pc(), 0x0205);
+    }
+
+    #[test]
+    fn cycles_to_duration() {
+        let cpu = nop_cpu();
+
+        assert_eq!(cpu.cycles_to_duration(1_000_000), Duration::from_secs(1));
+        assert_eq!(cpu.cycles_to_duration(1), Duration::from_micros(1));
+
+        // Past where a 32-bit counter would have wrapped.
+        assert_eq!(cpu.cycles_to_duration(1 << 40), Duration::from_micros(1 << 40));
+    }
+
+    #[test]
+    fn cycles_to_duration_wclock_hz() {
+        let cpu = tests_utils::cpu_mem_set_wconfig(vec![], Mos6502Config {
+            clock_hz: 1_789_773,
+            ..Mos6502Config::default()
+        });
+
+        assert_eq!(cpu.cycles_to_duration(1_789_773), Duration::from_secs(1));
+        assert_eq!(cpu.cycles_to_duration(29_780), Duration::from_nanos(16_638_981));
+    }
+
+    #[test]
+    fn elapsed() {
+        let mut cpu = nop_cpu();
+        cpu.exe(Some(10 * instructions::NOP_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.elapsed(), Duration::from_micros(20));
+    }
+}
@@ -0,0 +1,82 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn cpx_imm() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CPX_IMM),
+                (PC_START + 1, 0x40),
+            ],
+            0x40,
+            instructions::CPX_IMM_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::Z, Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0x40); })
+        );
+    }
+
+    #[test]
+    fn cpx_imm_wless() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CPX_IMM),
+                (PC_START + 1, 0x01),
+            ],
+            0x00,
+            instructions::CPX_IMM_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0x00); })
+        );
+    }
+
+    #[test]
+    fn cpx_zp() {
+        let addr = 0x42;
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CPX_ZP),
+                (PC_START + 1, addr),
+                (Word::from(addr), 0x10),
+            ],
+            0x80,
+            instructions::CPX_ZP_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0x80); })
+        );
+    }
+
+    #[test]
+    fn cpx_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CPX_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo), 0x80),
+            ],
+            0x10,
+            instructions::CPX_ABS_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0x10); })
+        );
+    }
+}
@@ -0,0 +1,82 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn cpy_imm() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CPY_IMM),
+                (PC_START + 1, 0x40),
+            ],
+            0x40,
+            instructions::CPY_IMM_CCOST,
+            tests_utils::Registers::Y,
+            vec![Mos6502Flags::Z, Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(0x40); })
+        );
+    }
+
+    #[test]
+    fn cpy_imm_wless() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CPY_IMM),
+                (PC_START + 1, 0x01),
+            ],
+            0x00,
+            instructions::CPY_IMM_CCOST,
+            tests_utils::Registers::Y,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(0x00); })
+        );
+    }
+
+    #[test]
+    fn cpy_zp() {
+        let addr = 0x42;
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CPY_ZP),
+                (PC_START + 1, addr),
+                (Word::from(addr), 0x10),
+            ],
+            0x80,
+            instructions::CPY_ZP_CCOST,
+            tests_utils::Registers::Y,
+            vec![Mos6502Flags::C],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(0x80); })
+        );
+    }
+
+    #[test]
+    fn cpy_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::CPY_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo), 0x80),
+            ],
+            0x10,
+            instructions::CPY_ABS_CCOST,
+            tests_utils::Registers::Y,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(0x10); })
+        );
+    }
+}
@@ -0,0 +1,75 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn dcp_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::DCP_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0x43),
+        ]);
+        cpu.set_accumulator(0x42);
+
+        cpu.exe(Some(instructions::DCP_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x42);
+        assert_eq!(cpu.get_accumulator(), 0x42);
+        assert_eq!(cpu.get_cycles(), instructions::DCP_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::Z]).is_ok());
+    }
+
+    #[test]
+    fn dcp_absx_wwrap() {
+        let (lo, hi, xreg) = (0x00, 0x44, 0x05);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::DCP_ABSX),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4405, 0x00),
+        ]);
+        cpu.set_xreg(xreg);
+        cpu.set_accumulator(0x10);
+
+        cpu.exe(Some(instructions::DCP_ABSX_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4405_u16)), 0xFF);
+        assert_eq!(cpu.get_accumulator(), 0x10);
+        assert_eq!(cpu.get_cycles(), instructions::DCP_ABSX_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn dcp_zpy_ind_wpage_boundary() {
+        let (addr, yreg) = (0x20, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::DCP_ZPY_IND),
+            (PC_START + 1, addr),
+            (0x0020, 0xFF),
+            (0x0021, 0x44),
+            (0x4500, 0x81),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_accumulator(0x90);
+
+        cpu.exe(Some(instructions::DCP_ZPY_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4500_u16)), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::DCP_ZPY_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C]).is_ok());
+    }
+}
@@ -0,0 +1,66 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn dex_imp() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::DEX_IMP),
+            ],
+            0x00,
+            instructions::DEX_IMP_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::Z],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0x01); })
+        );
+    }
+
+    #[test]
+    fn dex_imp_wwrap() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::DEX_IMP),
+            ],
+            0xff,
+            instructions::DEX_IMP_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::N],
+            None::<fn(&mut Mos6502)>
+        );
+    }
+
+    #[test]
+    fn dex_imp_wbne_rel_loop() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::LDX_IMM),
+            (0x0201, 0x03),
+            (0x0202, instructions::DEX_IMP),
+            (0x0203, instructions::BNE_REL),
+            (0x0204, 0xFD), // back to DEX
+        ]);
+
+        let taken = instructions::DEX_IMP_CCOST + instructions::BNE_REL_CCOST + 1;
+        let not_taken = instructions::DEX_IMP_CCOST + instructions::BNE_REL_CCOST;
+
+        cpu.set_pc(0x0200);
+        cpu.exe(Some(instructions::LDX_IMM_CCOST + 2 * taken + not_taken)).unwrap();
+
+        assert_eq!(cpu.get_xreg(), 0x00);
+        assert!(cpu.zero_flag());
+        assert_eq!(cpu.get_pc(), 0x0205);
+    }
+}
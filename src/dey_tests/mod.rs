@@ -0,0 +1,45 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn dey_imp() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::DEY_IMP),
+            ],
+            0x00,
+            instructions::DEY_IMP_CCOST,
+            tests_utils::Registers::Y,
+            vec![Mos6502Flags::Z],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(0x01); })
+        );
+    }
+
+    #[test]
+    fn dey_imp_wwrap() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::DEY_IMP),
+            ],
+            0xff,
+            instructions::DEY_IMP_CCOST,
+            tests_utils::Registers::Y,
+            vec![Mos6502Flags::N],
+            None::<fn(&mut Mos6502)>
+        );
+    }
+}
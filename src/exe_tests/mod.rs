@@ -0,0 +1,111 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Config,
+        Mos6502Flags,
+        StopReason,
+        UnknownOpcodePolicy,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    // Unassigned on NMOS parts unless illegal opcodes are enabled.
+    const UNKNOWN_OPCODE: Byte = 0x1A;
+
+    fn nop_sled() -> Mos6502 {
+        let mut cpu = tests_utils::cpu_mem_set(vec![]);
+        for addr in 0x0200..0x0210 {
+            *cpu.mem.at(addr) = instructions::NOP_IMP;
+        }
+        cpu.set_pc(0x0200);
+        cpu
+    }
+
+    #[test]
+    fn exe_wcycle_limit() {
+        let mut cpu = nop_sled();
+
+        assert_eq!(cpu.exe(Some(3 * instructions::NOP_IMP_CCOST)).unwrap(), StopReason::CycleLimit);
+        assert_eq!(cpu.get_pc(), 0x0203);
+    }
+
+    #[test]
+    fn exe_wbreakpoint() {
+        let mut cpu = nop_sled();
+        cpu.add_breakpoint(0x0204);
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Breakpoint(0x0204));
+        assert_eq!(cpu.get_pc(), 0x0204);
+        assert_eq!(cpu.get_cycles(), 4 * instructions::NOP_IMP_CCOST);
+    }
+
+    #[test]
+    fn exe_wbreakpoint_resume() {
+        let mut cpu = nop_sled();
+        cpu.add_breakpoint(0x0202);
+        cpu.add_breakpoint(0x0205);
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Breakpoint(0x0202));
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Breakpoint(0x0205));
+
+        cpu.remove_breakpoint(0x0202);
+        cpu.set_pc(0x0200);
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Breakpoint(0x0205));
+    }
+
+    #[test]
+    fn exe_whalt_request() {
+        let mut cpu = nop_sled();
+        cpu.request_halt();
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::HaltRequested);
+        assert_eq!(cpu.get_pc(), 0x0200);
+
+        cpu.halt_handle().store(true, Ordering::Relaxed);
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::HaltRequested);
+
+        assert_eq!(cpu.exe(Some(instructions::NOP_IMP_CCOST)).unwrap(), StopReason::CycleLimit);
+    }
+
+    #[test]
+    fn exe_wunknown_opcode_error() {
+        let mut cpu = nop_sled();
+        *cpu.mem.at(0x0202) = UNKNOWN_OPCODE;
+
+        let err = cpu.exe(None).unwrap_err();
+        assert_eq!(err.opcode, UNKNOWN_OPCODE);
+        assert_eq!(err.pc, 0x0202);
+        assert_eq!(err.cycles, 2 * instructions::NOP_IMP_CCOST + 1);
+    }
+
+    #[test]
+    fn exe_wunknown_opcode_nop() {
+        let mut cpu = nop_sled();
+        cpu.config_mut().unknown_opcode = UnknownOpcodePolicy::Nop;
+        *cpu.mem.at(0x0200) = UNKNOWN_OPCODE;
+
+        assert_eq!(cpu.exe(Some(instructions::NOP_IMP_CCOST)).unwrap(), StopReason::CycleLimit);
+        assert_eq!(cpu.get_pc(), 0x0201);
+    }
+
+    #[test]
+    fn exe_wunknown_opcode_jam() {
+        let mut cpu = nop_sled();
+        cpu.config_mut().unknown_opcode = UnknownOpcodePolicy::Jam;
+        *cpu.mem.at(0x0200) = UNKNOWN_OPCODE;
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Jammed);
+        assert_eq!(cpu.get_pc(), 0x0201);
+    }
+}
@@ -0,0 +1,124 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn inc_zp() {
+        let addr = 0x42;
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::INC_ZP),
+                (PC_START + 1, addr),
+                (Word::from(addr), 0x7f),
+            ],
+            Word::from(addr),
+            0x80,
+            instructions::INC_ZP_CCOST,
+            vec![Mos6502Flags::N],
+            None::<fn(&mut Mos6502)>
+        );
+    }
+
+    #[test]
+    fn inc_zpx_wwrap() {
+        let (addr, xreg) = (0x80, 0xFF);
+        let dest_addr = tests_utils::word_from_byte_addition(addr, xreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::INC_ZPX),
+                (PC_START + 1, addr),
+                (dest_addr, 0x7f),
+            ],
+            dest_addr,
+            0x80,
+            instructions::INC_ZPX_CCOST,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); })
+        );
+    }
+
+    #[test]
+    fn inc_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::INC_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (dest_addr, 0x7f),
+            ],
+            dest_addr,
+            0x80,
+            instructions::INC_ABS_CCOST,
+            vec![Mos6502Flags::N],
+            None::<fn(&mut Mos6502)>
+        );
+    }
+
+    #[test]
+    fn inc_absx_wopage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x80, 1);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(xreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::INC_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (dest_addr, 0x7f),
+            ],
+            dest_addr,
+            0x80,
+            instructions::INC_ABSX_CCOST,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); })
+        );
+    }
+
+    #[test]
+    fn inc_absx_wpage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x02, 0xFF);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(xreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::INC_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (dest_addr, 0x7f),
+            ],
+            dest_addr,
+            0x80,
+            instructions::INC_ABSX_CCOST,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); })
+        );
+    }
+
+    #[test]
+    fn inc_acc() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::INC_ACC),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x7F);
+
+        cpu.exe(Some(instructions::INC_ACC_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::INC_ACC_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+}
@@ -24,56 +24,56 @@ type Byte = u8;
 // Bytes:           2
 // Cycles:          2
 pub const LDA_IMM: Byte = 0xA9;
-pub const LDA_IMM_CCOST: u32 = 2;
+pub const LDA_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $AD
 // Bytes:           3
 // Cycles:          4
 pub const LDA_ABS: Byte = 0xAD;
-pub const LDA_ABS_CCOST: u32 = 4;
+pub const LDA_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $BD
 // Bytes:           3
 // Cycles:          ~4
 pub const LDA_ABSX: Byte = 0xBD;
-pub const LDA_ABSX_CCOST: u32 = 4;
+pub const LDA_ABSX_CCOST: u64 = 4;
 
 // Addressing Mode: Y-Indexed Absolute
 // Opcode:          $B9
 // Bytes:           3
 // Cycles:          ~4
 pub const LDA_ABSY: Byte = 0xB9;
-pub const LDA_ABSY_CCOST: u32 = 4;
+pub const LDA_ABSY_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $A5
 // Bytes:           2
 // Cycles:          3
 pub const LDA_ZP: Byte = 0xA5;
-pub const LDA_ZP_CCOST: u32 = 3;
+pub const LDA_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $B5
 // Bytes:           2
 // Cycles:          4
 pub const LDA_ZPX: Byte = 0xB5;
-pub const LDA_ZPX_CCOST: u32 = 4;
+pub const LDA_ZPX_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Zero Page Indirect
 // Opcode:          $A1
 // Bytes:           2
 // Cycles:          6
 pub const LDA_ZPX_IND: Byte = 0xA1;
-pub const LDA_ZPX_IND_CCOST: u32 = 6;
+pub const LDA_ZPX_IND_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page Indirect Y-Indexed
 // Opcode:          $B1
 // Bytes:           2
 // Cycles:          ~5
 pub const LDA_ZPY_IND: Byte = 0xB1;
-pub const LDA_ZPY_IND_CCOST: u32 = 5;
+pub const LDA_ZPY_IND_CCOST: u64 = 5;
 
 ////////// Load Index Register X from Memory (LDX) //////////
 //
@@ -84,35 +84,35 @@ pub const LDA_ZPY_IND_CCOST: u32 = 5;
 // Bytes:           2
 // Cycles:          2
 pub const LDX_IMM: Byte = 0xA2;
-pub const LDX_IMM_CCOST: u32 = 2;
+pub const LDX_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $AE
 // Bytes:           3
 // Cycles:          4
 pub const LDX_ABS: Byte = 0xAE;
-pub const LDX_ABS_CCOST: u32 = 4;
+pub const LDX_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: Y-Indexed Absolute
 // Opcode:          $BE
 // Bytes:           3
 // Cycles:          ~4
 pub const LDX_ABSY: Byte = 0xBE;
-pub const LDX_ABSY_CCOST: u32 = 4;
+pub const LDX_ABSY_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $A6
 // Bytes:           2
 // Cycles:          3
 pub const LDX_ZP: Byte = 0xA6;
-pub const LDX_ZP_CCOST: u32 = 3;
+pub const LDX_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: Y-Indexed Zero Page
 // Opcode:          $B6
 // Bytes:           2
 // Cycles:          4
 pub const LDX_ZPY: Byte = 0xB6;
-pub const LDX_ZPY_CCOST: u32 = 4;
+pub const LDX_ZPY_CCOST: u64 = 4;
 
 ////////// Load Index Register Y from Memory (LDY) //////////
 //
@@ -123,35 +123,35 @@ pub const LDX_ZPY_CCOST: u32 = 4;
 // Bytes:           2
 // Cycles:          2
 pub const LDY_IMM: Byte = 0xA0;
-pub const LDY_IMM_CCOST: u32 = 2;
+pub const LDY_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $AC
 // Bytes:           3
 // Cycles:          4
 pub const LDY_ABS: Byte = 0xAC;
-pub const LDY_ABS_CCOST: u32 = 4;
+pub const LDY_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $BC
 // Bytes:           3
 // Cycles:          ~4
 pub const LDY_ABSX: Byte = 0xBC;
-pub const LDY_ABSX_CCOST: u32 = 4;
+pub const LDY_ABSX_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $A4
 // Bytes:           2
 // Cycles:          3
 pub const LDY_ZP: Byte = 0xA4;
-pub const LDY_ZP_CCOST: u32 = 3;
+pub const LDY_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $B4
 // Bytes:           2
 // Cycles:          4
 pub const LDY_ZPX: Byte = 0xB4;
-pub const LDY_ZPX_CCOST: u32 = 4;
+pub const LDY_ZPX_CCOST: u64 = 4;
 
 ////////// Store Accumulator in Memory (STA) //////////
 //
@@ -162,74 +162,74 @@ pub const LDY_ZPX_CCOST: u32 = 4;
 // Bytes:           3
 // Cycles:          4
 pub const STA_ABS: Byte = 0x8D;
-pub const STA_ABS_CCOST: u32 = 4;
+pub const STA_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $9D
 // Bytes:           3
 // Cycles:          5
 pub const STA_ABSX: Byte = 0x9D;
-pub const STA_ABSX_CCOST: u32 = 5;
+pub const STA_ABSX_CCOST: u64 = 5;
 
 // Addressing Mode: Y-Indexed Absolute
 // Opcode:          $99
 // Bytes:           3
 // Cycles:          5
 pub const STA_ABSY: Byte = 0x99;
-pub const STA_ABSY_CCOST: u32 = 5;
+pub const STA_ABSY_CCOST: u64 = 5;
 
 // Addressing Mode: Zero Page
 // Opcode:          $85
 // Bytes:           2
 // Cycles:          3
 pub const STA_ZP: Byte = 0x85;
-pub const STA_ZP_CCOST: u32 = 3;
+pub const STA_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $95
 // Bytes:           2
 // Cycles:          4
 pub const STA_ZPX: Byte = 0x95;
-pub const STA_ZPX_CCOST: u32 = 4;
+pub const STA_ZPX_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Zero Page Indirect
 // Opcode:          $81
 // Bytes:           2
 // Cycles:          6
 pub const STA_ZPX_IND: Byte = 0x81;
-pub const STA_ZPX_IND_CCOST: u32 = 6;
+pub const STA_ZPX_IND_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page Indirect Y-Indexed
 // Opcode:          $91
 // Bytes:           2
 // Cycles:          6
 pub const STA_ZPY_IND: Byte = 0x91;
-pub const STA_ZPY_IND_CCOST: u32 = 6;
+pub const STA_ZPY_IND_CCOST: u64 = 6;
 
 ////////// Store Index Register X in Memory (STX) //////////
 //
 // Status Flags Affected: ∅
 
 // Addressing Mode: Absolute
-// Opcode:          $91
+// Opcode:          $8E
 // Bytes:           3
 // Cycles:          4
 pub const STX_ABS: Byte = 0x8E;
-pub const STX_ABS_CCOST: u32 = 4;
+pub const STX_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $86
 // Bytes:           2
 // Cycles:          3
 pub const STX_ZP: Byte = 0x86;
-pub const STX_ZP_CCOST: u32 = 3;
+pub const STX_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: Y-Indexed Zero Page
 // Opcode:          $96
 // Bytes:           2
 // Cycles:          4
 pub const STX_ZPY: Byte = 0x96;
-pub const STX_ZPY_CCOST: u32 = 4;
+pub const STX_ZPY_CCOST: u64 = 4;
 
 ////////// Store Index Register Y in Memory (STY) //////////
 //
@@ -240,21 +240,21 @@ pub const STX_ZPY_CCOST: u32 = 4;
 // Bytes:           3
 // Cycles:          4
 pub const STY_ABS: Byte = 0x8C;
-pub const STY_ABS_CCOST: u32 = 4;
+pub const STY_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $84
 // Bytes:           2
 // Cycles:          3
 pub const STY_ZP: Byte = 0x84;
-pub const STY_ZP_CCOST: u32 = 3;
+pub const STY_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $94
 // Bytes:           2
 // Cycles:          4
 pub const STY_ZPX: Byte = 0x94;
-pub const STY_ZPX_CCOST: u32 = 4;
+pub const STY_ZPX_CCOST: u64 = 4;
 
 ////////// Transfer Accumulator to Index X (TAX) //////////
 //
@@ -265,7 +265,7 @@ pub const STY_ZPX_CCOST: u32 = 4;
 // Bytes:           1
 // Cycles:          2
 pub const TAX_IMP: Byte = 0xAA;
-pub const TAX_IMP_CCOST: u32 = 2;
+pub const TAX_IMP_CCOST: u64 = 2;
 
 ////////// Transfer Accumulator to Index Y //////////
 //
@@ -276,7 +276,7 @@ pub const TAX_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const TAY_IMP: Byte = 0xA8;
-pub const TAY_IMP_CCOST: u32 = 2;
+pub const TAY_IMP_CCOST: u64 = 2;
 
 ////////// Transfer Stack Pointer to Index X (TSX) //////////
 //
@@ -287,7 +287,7 @@ pub const TAY_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const TSX_IMP: Byte = 0xBA;
-pub const TSX_IMP_CCOST: u32 = 2;
+pub const TSX_IMP_CCOST: u64 = 2;
 
 ////////// Transfer Index X to Accumulator (TXA) //////////
 //
@@ -298,7 +298,7 @@ pub const TSX_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const TXA_IMP: Byte = 0x8A;
-pub const TXA_IMP_CCOST: u32 = 2;
+pub const TXA_IMP_CCOST: u64 = 2;
 
 ////////// Transfer Index X to Stack Pointer (TXS) //////////
 //
@@ -309,7 +309,7 @@ pub const TXA_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const TXS_IMP: Byte = 0x9A;
-pub const TXS_IMP_CCOST: u32 = 2;
+pub const TXS_IMP_CCOST: u64 = 2;
 
 ////////// Transfer Index Y to Accumulator (TYA) //////////
 //
@@ -320,7 +320,7 @@ pub const TXS_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const TYA_IMP: Byte = 0x98;
-pub const TYA_IMP_CCOST: u32 = 2;
+pub const TYA_IMP_CCOST: u64 = 2;
 
 ////////// Push Accumulator on Stack (PHA) //////////
 //
@@ -331,7 +331,7 @@ pub const TYA_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          3
 pub const PHA_IMP: Byte = 0x48;
-pub const PHA_IMP_CCOST: u32 = 3;
+pub const PHA_IMP_CCOST: u64 = 3;
 
 ////////// Push Processor Status on Stack (PHP) //////////
 //
@@ -342,7 +342,7 @@ pub const PHA_IMP_CCOST: u32 = 3;
 // Bytes:           1
 // Cycles:          3
 pub const PHP_IMP: Byte = 0x08;
-pub const PHP_IMP_CCOST: u32 = 3;
+pub const PHP_IMP_CCOST: u64 = 3;
 
 ////////// Pull Accumulator from Stack (PLA) //////////
 //
@@ -353,7 +353,7 @@ pub const PHP_IMP_CCOST: u32 = 3;
 // Bytes:           1
 // Cycles:          4
 pub const PLA_IMP: Byte = 0x68;
-pub const PLA_IMP_CCOST: u32 = 4;
+pub const PLA_IMP_CCOST: u64 = 4;
 
 ////////// Pull Processor Status from Stack (PLP) //////////
 //
@@ -364,7 +364,7 @@ pub const PLA_IMP_CCOST: u32 = 4;
 // Bytes:           1
 // Cycles:          4
 pub const PLP_IMP: Byte = 0x28;
-pub const PLP_IMP_CCOST: u32 = 4;
+pub const PLP_IMP_CCOST: u64 = 4;
 
 ////////// Arithmetic Shift Left (ASL) //////////
 //
@@ -375,35 +375,44 @@ pub const PLP_IMP_CCOST: u32 = 4;
 // Bytes:           1
 // Cycles:          2
 pub const ASL_ACC: Byte = 0x0A;
-pub const ASL_ACC_CCOST: u32 = 2;
+pub const ASL_ACC_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $0E
 // Bytes:           3
 // Cycles:          6
 pub const ASL_ABS: Byte = 0x0E;
-pub const ASL_ABS_CCOST: u32 = 6;
+pub const ASL_ABS_CCOST: u64 = 6;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $1E
 // Bytes:           3
 // Cycles:          7
 pub const ASL_ABSX: Byte = 0x1E;
-pub const ASL_ABSX_CCOST: u32 = 7;
+pub const ASL_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $1E
+// Bytes:           3
+// Cycles:          6 (+1 if page crossed)
+//
+// Note: Same opcode as `ASL_ABSX`, CMOS parts only spend the
+// indexing cycle when a page is crossed.
+pub const ASL_ABSX_CMOS_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page
 // Opcode:          $06
 // Bytes:           2
 // Cycles:          5
 pub const ASL_ZP: Byte = 0x06;
-pub const ASL_ZP_CCOST: u32 = 5;
+pub const ASL_ZP_CCOST: u64 = 5;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $16
 // Bytes:           2
 // Cycles:          6
 pub const ASL_ZPX: Byte = 0x16;
-pub const ASL_ZPX_CCOST: u32 = 6;
+pub const ASL_ZPX_CCOST: u64 = 6;
 
 ////////// Logical Shift Right (LSR) //////////
 //
@@ -414,35 +423,44 @@ pub const ASL_ZPX_CCOST: u32 = 6;
 // Bytes:           1
 // Cycles:          2
 pub const LSR_ACC: Byte = 0x4A;
-pub const LSR_ACC_CCOST: u32 = 2;
+pub const LSR_ACC_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $4E
 // Bytes:           3
 // Cycles:          6
 pub const LSR_ABS: Byte = 0x4E;
-pub const LSR_ABS_CCOST: u32 = 6;
+pub const LSR_ABS_CCOST: u64 = 6;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $5E
 // Bytes:           3
 // Cycles:          7
 pub const LSR_ABSX: Byte = 0x5E;
-pub const LSR_ABSX_CCOST: u32 = 7;
+pub const LSR_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $5E
+// Bytes:           3
+// Cycles:          6 (+1 if page crossed)
+//
+// Note: Same opcode as `LSR_ABSX`, CMOS parts only spend the
+// indexing cycle when a page is crossed.
+pub const LSR_ABSX_CMOS_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page
 // Opcode:          $46
 // Bytes:           2
 // Cycles:          5
 pub const LSR_ZP: Byte = 0x46;
-pub const LSR_ZP_CCOST: u32 = 5;
+pub const LSR_ZP_CCOST: u64 = 5;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $56
 // Bytes:           2
 // Cycles:          6
 pub const LSR_ZPX: Byte = 0x56;
-pub const LSR_ZPX_CCOST: u32 = 6;
+pub const LSR_ZPX_CCOST: u64 = 6;
 
 ////////// Rotate Left (ROL) //////////
 //
@@ -453,35 +471,44 @@ pub const LSR_ZPX_CCOST: u32 = 6;
 // Bytes:           1
 // Cycles:          2
 pub const ROL_ACC: Byte = 0x2A;
-pub const ROL_ACC_CCOST: u32 = 2;
+pub const ROL_ACC_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $2E
 // Bytes:           3
 // Cycles:          6
 pub const ROL_ABS: Byte = 0x2E;
-pub const ROL_ABS_CCOST: u32 = 6;
+pub const ROL_ABS_CCOST: u64 = 6;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $3E
 // Bytes:           3
 // Cycles:          7
 pub const ROL_ABSX: Byte = 0x3E;
-pub const ROL_ABSX_CCOST: u32 = 7;
+pub const ROL_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $3E
+// Bytes:           3
+// Cycles:          6 (+1 if page crossed)
+//
+// Note: Same opcode as `ROL_ABSX`, CMOS parts only spend the
+// indexing cycle when a page is crossed.
+pub const ROL_ABSX_CMOS_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page
 // Opcode:          $26
 // Bytes:           2
 // Cycles:          5
 pub const ROL_ZP: Byte = 0x26;
-pub const ROL_ZP_CCOST: u32 = 5;
+pub const ROL_ZP_CCOST: u64 = 5;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $36
 // Bytes:           2
 // Cycles:          6
 pub const ROL_ZPX: Byte = 0x36;
-pub const ROL_ZPX_CCOST: u32 = 6;
+pub const ROL_ZPX_CCOST: u64 = 6;
 
 ////////// Rotate Right (ROR) //////////
 //
@@ -492,35 +519,44 @@ pub const ROL_ZPX_CCOST: u32 = 6;
 // Bytes:           1
 // Cycles:          2
 pub const ROR_ACC: Byte = 0x6A;
-pub const ROR_ACC_CCOST: u32 = 2;
+pub const ROR_ACC_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $6E
 // Bytes:           3
 // Cycles:          6
 pub const ROR_ABS: Byte = 0x6E;
-pub const ROR_ABS_CCOST: u32 = 6;
+pub const ROR_ABS_CCOST: u64 = 6;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $7E
 // Bytes:           3
 // Cycles:          7
 pub const ROR_ABSX: Byte = 0x7E;
-pub const ROR_ABSX_CCOST: u32 = 7;
+pub const ROR_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $7E
+// Bytes:           3
+// Cycles:          6 (+1 if page crossed)
+//
+// Note: Same opcode as `ROR_ABSX`, CMOS parts only spend the
+// indexing cycle when a page is crossed.
+pub const ROR_ABSX_CMOS_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page
 // Opcode:          $66
 // Bytes:           2
 // Cycles:          5
 pub const ROR_ZP: Byte = 0x66;
-pub const ROR_ZP_CCOST: u32 = 5;
+pub const ROR_ZP_CCOST: u64 = 5;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $76
 // Bytes:           2
 // Cycles:          6
 pub const ROR_ZPX: Byte = 0x76;
-pub const ROR_ZPX_CCOST: u32 = 6;
+pub const ROR_ZPX_CCOST: u64 = 6;
 
 ////////// AND Memory with Accumulator (AND) //////////
 //
@@ -531,56 +567,56 @@ pub const ROR_ZPX_CCOST: u32 = 6;
 // Bytes:           2
 // Cycles:          2
 pub const AND_IMM: Byte = 0x29;
-pub const AND_IMM_CCOST: u32 = 2;
+pub const AND_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $2D
 // Bytes:           3
 // Cycles:          4
 pub const AND_ABS: Byte = 0x2D;
-pub const AND_ABS_CCOST: u32 = 4;
+pub const AND_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $3D
 // Bytes:           3
 // Cycles:          ~4
 pub const AND_ABSX: Byte = 0x3D;
-pub const AND_ABSX_CCOST: u32 = 4;
+pub const AND_ABSX_CCOST: u64 = 4;
 
 // Addressing Mode: Y-Indexed Absolute
 // Opcode:          $39
 // Bytes:           3
 // Cycles:          ~4
 pub const AND_ABSY: Byte = 0x39;
-pub const AND_ABSY_CCOST: u32 = 4;
+pub const AND_ABSY_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $25
 // Bytes:           2
 // Cycles:          3
 pub const AND_ZP: Byte = 0x25;
-pub const AND_ZP_CCOST: u32 = 3;
+pub const AND_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $35
 // Bytes:           2
 // Cycles:          4
 pub const AND_ZPX: Byte = 0x35;
-pub const AND_ZPX_CCOST: u32 = 4;
+pub const AND_ZPX_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Zero Page Indirect
 // Opcode:          $21
 // Bytes:           2
 // Cycles:          6
 pub const AND_ZPX_IND: Byte = 0x21;
-pub const AND_ZPX_IND_CCOST: u32 = 6;
+pub const AND_ZPX_IND_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page Indirect Y-Index
 // Opcode:          $31
 // Bytes:           2
 // Cycles:          ~5
 pub const AND_ZPY_IND: Byte = 0x31;
-pub const AND_ZPY_IND_CCOST: u32 = 5;
+pub const AND_ZPY_IND_CCOST: u64 = 5;
 
 ////////// Test Bits in Memory with Accumulator (BIT) //////////
 //
@@ -591,14 +627,14 @@ pub const AND_ZPY_IND_CCOST: u32 = 5;
 // Bytes:           3
 // Cycles:          4
 pub const BIT_ABS: Byte = 0x2C;
-pub const BIT_ABS_CCOST: u32 = 4;
+pub const BIT_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $24
 // Bytes:           2
 // Cycles:          3
 pub const BIT_ZP: Byte = 0x24;
-pub const BIT_ZP_CCOST: u32 = 3;
+pub const BIT_ZP_CCOST: u64 = 3;
 
 ////////// Exclusive OR Memory with Accumulator (EOR) //////////
 
@@ -607,56 +643,56 @@ pub const BIT_ZP_CCOST: u32 = 3;
 // Bytes:           2
 // Cycles:          2
 pub const EOR_IMM: Byte = 0x49;
-pub const EOR_IMM_CCOST: u32 = 2;
+pub const EOR_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $4D
 // Bytes:           3
 // Cycles:          4
 pub const EOR_ABS: Byte = 0x4D;
-pub const EOR_ABS_CCOST: u32 = 4;
+pub const EOR_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $5D
 // Bytes:           3
 // Cycles:          ~4
 pub const EOR_ABSX: Byte = 0x5D;
-pub const EOR_ABSX_CCOST: u32 = 4;
+pub const EOR_ABSX_CCOST: u64 = 4;
 
 // Addressing Mode: Y-Indexed Absolute
 // Opcode:          $59
 // Bytes:           3
 // Cycles:          ~4
 pub const EOR_ABSY: Byte = 0x59;
-pub const EOR_ABSY_CCOST: u32 = 4;
+pub const EOR_ABSY_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $45
 // Bytes:           2
 // Cycles:          3
 pub const EOR_ZP: Byte = 0x45;
-pub const EOR_ZP_CCOST: u32 = 3;
+pub const EOR_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $55
 // Bytes:           2
 // Cycles:          4
 pub const EOR_ZPX: Byte = 0x55;
-pub const EOR_ZPX_CCOST: u32 = 4;
+pub const EOR_ZPX_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Zero Page Indirect
 // Opcode:          $41
 // Bytes:           2
 // Cycles:          6
 pub const EOR_ZPX_IND: Byte = 0x41;
-pub const EOR_ZPX_IND_CCOST: u32 = 6;
+pub const EOR_ZPX_IND_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page Indirect Y-Indexed
 // Opcode:          $51
 // Bytes:           2
 // Cycles:          ~5
 pub const EOR_ZPY_IND: Byte = 0x51;
-pub const EOR_ZPY_IND_CCOST: u32 = 5;
+pub const EOR_ZPY_IND_CCOST: u64 = 5;
 
 ////////// OR Memory with Accumulator (ORA) //////////
 //
@@ -667,56 +703,56 @@ pub const EOR_ZPY_IND_CCOST: u32 = 5;
 // Bytes:           2
 // Cycles:          2
 pub const ORA_IMM: Byte = 0x09;
-pub const ORA_IMM_CCOST: u32 = 2;
+pub const ORA_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $0D
 // Bytes:           3
 // Cycles:          4
 pub const ORA_ABS: Byte = 0x0D;
-pub const ORA_ABS_CCOST: u32 = 4;
+pub const ORA_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $1D
 // Bytes:           3
 // Cycles:          ~4
 pub const ORA_ABSX: Byte = 0x1D;
-pub const ORA_ABSX_CCOST: u32 = 4;
+pub const ORA_ABSX_CCOST: u64 = 4;
 
 // Addressing Mode: Y-Indexed Absolute
 // Opcode:          $19
 // Bytes:           3
 // Cycles:          ~4
 pub const ORA_ABSY: Byte = 0x19;
-pub const ORA_ABSY_CCOST: u32 = 4;
+pub const ORA_ABSY_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $05
 // Bytes:           2
 // Cycles:          3
 pub const ORA_ZP: Byte = 0x05;
-pub const ORA_ZP_CCOST: u32 = 3;
+pub const ORA_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $15
 // Bytes:           2
 // Cycles:          4
 pub const ORA_ZPX: Byte = 0x15;
-pub const ORA_ZPX_CCOST: u32 = 4;
+pub const ORA_ZPX_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Zero Page Indirect
 // Opcode:          $01
 // Bytes:           2
 // Cycles:          6
 pub const ORA_ZPX_IND: Byte = 0x01;
-pub const ORA_ZPX_IND_CCOST: u32 = 6;
+pub const ORA_ZPX_IND_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page Indirect Y-Indexed
 // Opcode:          $11
 // Bytes:           2
 // Cycles:          ~5
 pub const ORA_ZPY_IND: Byte = 0x11;
-pub const ORA_ZPY_IND_CCOST: u32 = 5;
+pub const ORA_ZPY_IND_CCOST: u64 = 5;
 
 ////////// Add Memory to Accumulator with Carry (ADC) //////////
 //
@@ -729,56 +765,56 @@ pub const ORA_ZPY_IND_CCOST: u32 = 5;
 // Bytes:           2
 // Cycles:          2
 pub const ADC_IMM: Byte = 0x69;
-pub const ADC_IMM_CCOST: u32 = 2;
+pub const ADC_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $6D
 // Bytes:           3
 // Cycles:          4
 pub const ADC_ABS: Byte = 0x6D;
-pub const ADC_ABS_CCOST: u32 = 4;
+pub const ADC_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $7D
 // Bytes:           3
 // Cycles:          ~4
 pub const ADC_ABSX: Byte = 0x7D;
-pub const ADC_ABSX_CCOST: u32 = 4;
+pub const ADC_ABSX_CCOST: u64 = 4;
 
 // Addressing Mode: Y-Indexed Absolute
 // Opcode:          $79
 // Bytes:           3
 // Cycles:          ~4
 pub const ADC_ABSY: Byte = 0x79;
-pub const ADC_ABSY_CCOST: u32 = 4;
+pub const ADC_ABSY_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $65
 // Bytes:           2
 // Cycles:          3
 pub const ADC_ZP: Byte = 0x65;
-pub const ADC_ZP_CCOST: u32 = 3;
+pub const ADC_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $75
 // Bytes:           2
 // Cycles:          4
 pub const ADC_ZPX: Byte = 0x75;
-pub const ADC_ZPX_CCOST: u32 = 4;
+pub const ADC_ZPX_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Zero Page Indirect
 // Opcode:          $61
 // Bytes:           2
 // Cycles:          6
 pub const ADC_ZPX_IND: Byte = 0x61;
-pub const ADC_ZPX_IND_CCOST: u32 = 6;
+pub const ADC_ZPX_IND_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page Indirect Y-Indexed
 // Opcode:          $71
 // Bytes:           2
 // Cycles:          ~5
 pub const ADC_ZPY_IND: Byte = 0x71;
-pub const ADC_ZPY_IND_CCOST: u32 = 5;
+pub const ADC_ZPY_IND_CCOST: u64 = 5;
 
 ////////// Compare Memory and Accumulator (CMP) //////////
 //
@@ -789,56 +825,56 @@ pub const ADC_ZPY_IND_CCOST: u32 = 5;
 // Bytes:           2
 // Cycles:          2
 pub const CMP_IMM: Byte = 0xC9;
-pub const CMP_IMM_CCOST: u32 = 2;
+pub const CMP_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $CD
 // Bytes:           2
 // Cycles:          4
 pub const CMP_ABS: Byte = 0xCD;
-pub const CMP_ABS_CCOST: u32 = 4;
+pub const CMP_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $DD
 // Bytes:           3
 // Cycles:          ~4
 pub const CMP_ABSX: Byte = 0xDD;
-pub const CMP_ABSX_CCOST: u32 = 4;
+pub const CMP_ABSX_CCOST: u64 = 4;
 
 // Addressing Mode: Y-Indexed Absolute
 // Opcode:          $D9
 // Bytes:           3
 // Cycles:          ~4
 pub const CMP_ABSY: Byte = 0xD9;
-pub const CMP_ABSY_CCOST: u32 = 4;
+pub const CMP_ABSY_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $C5
 // Bytes:           2
 // Cycles:          3
 pub const CMP_ZP: Byte = 0xC5;
-pub const CMP_ZP_CCOST: u32 = 3;
+pub const CMP_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $D5
 // Bytes:           2
 // Cycles:          4
 pub const CMP_ZPX: Byte = 0xD5;
-pub const CMP_ZPX_CCOST: u32 = 4;
+pub const CMP_ZPX_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Zero Page Indirect
 // Opcode:          $C1
 // Bytes:           2
 // Cycles:          6
 pub const CMP_ZPX_IND: Byte = 0xC1;
-pub const CMP_ZPX_IND_CCOST: u32 = 6;
+pub const CMP_ZPX_IND_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page Indirect Y-Indexed
 // Opcode:          $D1
 // Bytes:           2
 // Cycles:          ~5
 pub const CMP_ZPY_IND: Byte = 0xD1;
-pub const CMP_ZPY_IND_CCOST: u32 = 5;
+pub const CMP_ZPY_IND_CCOST: u64 = 5;
 
 ////////// Compare Index Register X to Memory (CPX) //////////
 //
@@ -849,21 +885,21 @@ pub const CMP_ZPY_IND_CCOST: u32 = 5;
 // Bytes:           2
 // Cycles:          2
 pub const CPX_IMM: Byte = 0xE0;
-pub const CPX_IMM_CCOST: u32 = 2;
+pub const CPX_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $EC
 // Bytes:           3
 // Cycles:          4
 pub const CPX_ABS: Byte = 0xEC;
-pub const CPX_ABS_CCOST: u32 = 4;
+pub const CPX_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $E4
 // Bytes:           2
 // Cycles:          3
 pub const CPX_ZP: Byte = 0xE4;
-pub const CPX_ZP_CCOST: u32 = 3;
+pub const CPX_ZP_CCOST: u64 = 3;
 
 ////////// Compare Index Register Y to Memory (CPY) //////////
 //
@@ -874,21 +910,21 @@ pub const CPX_ZP_CCOST: u32 = 3;
 // Bytes:           2
 // Cycles:          2
 pub const CPY_IMM: Byte = 0xC0;
-pub const CPY_IMM_CCOST: u32 = 2;
+pub const CPY_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $CC
 // Bytes:           3
 // Cycles:          4
 pub const CPY_ABS: Byte = 0xCC;
-pub const CPY_ABS_CCOST: u32 = 4;
+pub const CPY_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $C4
 // Bytes:           2
 // Cycles:          3
 pub const CPY_ZP: Byte = 0xC4;
-pub const CPY_ZP_CCOST: u32 = 3;
+pub const CPY_ZP_CCOST: u64 = 3;
 
 ////////// Subtract Memory from Accumulator with Borrow (SBC) //////////
 //
@@ -901,56 +937,56 @@ pub const CPY_ZP_CCOST: u32 = 3;
 // Bytes:           2
 // Cycles:          2
 pub const SBC_IMM: Byte = 0xE9;
-pub const SBC_IMM_CCOST: u32 = 2;
+pub const SBC_IMM_CCOST: u64 = 2;
 
 // Addressing Mode: Absolute
 // Opcode:          $ED
 // Bytes:           3
 // Cycles:          4
 pub const SBC_ABS: Byte = 0xED;
-pub const SBC_ABS_CCOST: u32 = 4;
+pub const SBC_ABS_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $FD
 // Bytes:           3
 // Cycles:          ~4
 pub const SBC_ABSX: Byte = 0xFD;
-pub const SBC_ABSX_CCOST: u32 = 4;
+pub const SBC_ABSX_CCOST: u64 = 4;
 
 // Addressing Mode: Y-Indexed Absolute
 // Opcode:          $F9
 // Bytes:           3
 // Cycles:          ~4
 pub const SBC_ABSY: Byte = 0xF9;
-pub const SBC_ABSY_CCOST: u32 = 4;
+pub const SBC_ABSY_CCOST: u64 = 4;
 
 // Addressing Mode: Zero Page
 // Opcode:          $E5
 // Bytes:           2
 // Cycles:          3
 pub const SBC_ZP: Byte = 0xE5;
-pub const SBC_ZP_CCOST: u32 = 3;
+pub const SBC_ZP_CCOST: u64 = 3;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $F5
 // Bytes:           2
 // Cycles:          4
 pub const SBC_ZPX: Byte = 0xF5;
-pub const SBC_ZPX_CCOST: u32 = 4;
+pub const SBC_ZPX_CCOST: u64 = 4;
 
 // Addressing Mode: X-Indexed Zero Page Indirect
 // Opcode:          $E1
 // Bytes:           2
 // Cycles:          6
 pub const SBC_ZPX_IND: Byte = 0xE1;
-pub const SBC_ZPX_IND_CCOST: u32 = 6;
+pub const SBC_ZPX_IND_CCOST: u64 = 6;
 
 // Addressing Mode: Zero Page Indirect Y-Indexed
 // Opcode:          $F1
 // Bytes:           2
 // Cycles:          ~5
 pub const SBC_ZPY_IND: Byte = 0xF1;
-pub const SBC_ZPY_IND_CCOST: u32 = 5;
+pub const SBC_ZPY_IND_CCOST: u64 = 5;
 
 ////////// Decrement Memory by One (DEC) //////////
 //
@@ -961,28 +997,28 @@ pub const SBC_ZPY_IND_CCOST: u32 = 5;
 // Bytes:           3
 // Cycles:          6
 pub const DEC_ABS: Byte = 0xCE;
-pub const DEC_ABS_CCOST: u32 = 6;
+pub const DEC_ABS_CCOST: u64 = 6;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $DE
 // Bytes:           3
 // Cycles:          7
 pub const DEC_ABSX: Byte = 0xDE;
-pub const DEC_ABSX_CCOST: u32 = 7;
+pub const DEC_ABSX_CCOST: u64 = 7;
 
 // Addressing Mode: Zero Page
 // Opcode:          $C6
 // Bytes:           2
 // Cycles:          5
 pub const DEC_ZP: Byte = 0xC6;
-pub const DEC_ZP_CCOST: u32 = 5;
+pub const DEC_ZP_CCOST: u64 = 5;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $D6
 // Bytes:           2
 // Cycles:          6
 pub const DEC_ZPX: Byte = 0xD6;
-pub const DEC_ZPX_CCOST: u32 = 6;
+pub const DEC_ZPX_CCOST: u64 = 6;
 
 ////////// Decrement Index Register X by One (DEX) //////////
 //
@@ -993,7 +1029,7 @@ pub const DEC_ZPX_CCOST: u32 = 6;
 // Bytes:           1
 // Cycles:          2
 pub const DEX_IMP: Byte = 0xCA;
-pub const DEX_IMP_CCOST: u32 = 2;
+pub const DEX_IMP_CCOST: u64 = 2;
 
 ////////// Decrement Index Register Y by One (DEY) //////////
 //
@@ -1004,7 +1040,7 @@ pub const DEX_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const DEY_IMP: Byte = 0x88;
-pub const DEY_IMP_CCOST: u32 = 2;
+pub const DEY_IMP_CCOST: u64 = 2;
 
 ////////// Increment Memory by One (INC) //////////
 //
@@ -1015,28 +1051,28 @@ pub const DEY_IMP_CCOST: u32 = 2;
 // Bytes:           3
 // Cycles:          6
 pub const INC_ABS: Byte = 0xEE;
-pub const INC_ABS_CCOST: u32 = 6;
+pub const INC_ABS_CCOST: u64 = 6;
 
 // Addressing Mode: X-Indexed Absolute
 // Opcode:          $FE
 // Bytes:           3
 // Cycles:          7
 pub const INC_ABSX: Byte = 0xFE;
-pub const INC_ABSX_CCOST: u32 = 7;
+pub const INC_ABSX_CCOST: u64 = 7;
 
 // Addressing Mode: Zero Page
 // Opcode:          $E6
 // Bytes:           2
 // Cycles:          5
 pub const INC_ZP: Byte = 0xE6;
-pub const INC_ZP_CCOST: u32 = 5;
+pub const INC_ZP_CCOST: u64 = 5;
 
 // Addressing Mode: X-Indexed Zero Page
 // Opcode:          $F6
 // Bytes:           2
 // Cycles:          6
 pub const INC_ZPX: Byte = 0xF6;
-pub const INC_ZPX_CCOST: u32 = 6;
+pub const INC_ZPX_CCOST: u64 = 6;
 
 ////////// Increment Index Register X by One (INX) //////////
 //
@@ -1047,7 +1083,7 @@ pub const INC_ZPX_CCOST: u32 = 6;
 // Bytes:           1
 // Cycles:          2
 pub const INX_IMP: Byte = 0xE8;
-pub const INX_IMP_CCOST: u32 = 2;
+pub const INX_IMP_CCOST: u64 = 2;
 
 ////////// Increment Index Register Y by One (INY) //////////
 //
@@ -1058,7 +1094,7 @@ pub const INX_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const INY_IMP: Byte = 0xC8;
-pub const INY_IMP_CCOST: u32 = 2;
+pub const INY_IMP_CCOST: u64 = 2;
 
 ////////// Break Command (BRK) //////////
 //
@@ -1071,7 +1107,7 @@ pub const INY_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          7
 pub const BRK_IMP: Byte = 0x00;
-pub const BRK_IMP_CCOST: u32 = 7;
+pub const BRK_IMP_CCOST: u64 = 7;
 
 ////////// JMP Indirect (JMP) //////////
 //
@@ -1082,14 +1118,14 @@ pub const BRK_IMP_CCOST: u32 = 7;
 // Bytes:           3
 // Cycles:          3
 pub const JMP_ABS: Byte = 0x4C;
-pub const JMP_ABS_CCOST: u32 = 3;
+pub const JMP_ABS_CCOST: u64 = 3;
 
 // Addressing Mode: Absolute Indirect
 // Opcode:          $6C
 // Bytes:           3
 // Cycles:          5
 pub const JMP_ABS_IND: Byte = 0x6C;
-pub const JMP_ABS_IND_CCOST: u32 = 5;
+pub const JMP_ABS_IND_CCOST: u64 = 5;
 
 ////////// Jump to Subroutine (JSR) //////////
 //
@@ -1100,7 +1136,7 @@ pub const JMP_ABS_IND_CCOST: u32 = 5;
 // Bytes:           3
 // Cycles:          6
 pub const JSR_ABS: Byte = 0x20;
-pub const JSR_ABS_CCOST: u32 = 6;
+pub const JSR_ABS_CCOST: u64 = 6;
 
 ////////// Return From Interrupt (RTI) //////////
 //
@@ -1111,7 +1147,7 @@ pub const JSR_ABS_CCOST: u32 = 6;
 // Bytes:           1
 // Cycles:          6
 pub const RTI_IMP: Byte = 0x40;
-pub const RTI_IMP_CCOST: u32 = 6;
+pub const RTI_IMP_CCOST: u64 = 6;
 
 ////////// Return From Subroutine (RTS) //////////
 //
@@ -1122,7 +1158,7 @@ pub const RTI_IMP_CCOST: u32 = 6;
 // Bytes:           1
 // Cycles:          6
 pub const RTS_IMP: Byte = 0x60;
-pub const RTS_IMP_CCOST: u32 = 6;
+pub const RTS_IMP_CCOST: u64 = 6;
 
 ////////// Branch on Carry Clear (BCC) //////////
 //
@@ -1133,7 +1169,7 @@ pub const RTS_IMP_CCOST: u32 = 6;
 // Bytes:           2
 // Cycles:          ~*2
 pub const BCC_REL: Byte = 0x90;
-pub const BCC_REL_CCOST: u32 = 2;
+pub const BCC_REL_CCOST: u64 = 2;
 
 ////////// Branch on Carry Set (BCS) //////////
 //
@@ -1144,7 +1180,7 @@ pub const BCC_REL_CCOST: u32 = 2;
 // Bytes:           2
 // Cycles:          ~*2
 pub const BCS_REL: Byte = 0xB0;
-pub const BCS_REL_CCOST: u32 = 2;
+pub const BCS_REL_CCOST: u64 = 2;
 
 ////////// Branch on Result Zero (BEQ) //////////
 //
@@ -1155,7 +1191,7 @@ pub const BCS_REL_CCOST: u32 = 2;
 // Bytes:           2
 // Cycles:          ~*2
 pub const BEQ_REL: Byte = 0xF0;
-pub const BEQ_REL_CCOST: u32 = 2;
+pub const BEQ_REL_CCOST: u64 = 2;
 
 ////////// Branch on Result Minus (BMI) //////////
 //
@@ -1166,7 +1202,7 @@ pub const BEQ_REL_CCOST: u32 = 2;
 // Bytes:           2
 // Cycles:          ~*2
 pub const BMI_REL: Byte = 0x30;
-pub const BMI_REL_CCOST: u32 = 2;
+pub const BMI_REL_CCOST: u64 = 2;
 
 ////////// Branch on Result Not Zero (BNE) //////////
 //
@@ -1177,7 +1213,7 @@ pub const BMI_REL_CCOST: u32 = 2;
 // Bytes:           2
 // Cycles:          ~*2
 pub const BNE_REL: Byte = 0xD0;
-pub const BNE_REL_CCOST: u32 = 2;
+pub const BNE_REL_CCOST: u64 = 2;
 
 ////////// Branch on Result Plus (BPL) //////////
 //
@@ -1188,7 +1224,7 @@ pub const BNE_REL_CCOST: u32 = 2;
 // Bytes:           2
 // Cycles:          ~*2
 pub const BPL_REL: Byte = 0x10;
-pub const BPL_REL_CCOST: u32 = 2;
+pub const BPL_REL_CCOST: u64 = 2;
 
 ////////// Branch on Overflow Clear (BVC) //////////
 //
@@ -1199,7 +1235,7 @@ pub const BPL_REL_CCOST: u32 = 2;
 // Bytes:           2
 // Cycles:          ~*2
 pub const BVC_REL: Byte = 0x50;
-pub const BVC_REL_CCOST: u32 = 2;
+pub const BVC_REL_CCOST: u64 = 2;
 
 ////////// Branch on Overflow Set (BVS) //////////
 //
@@ -1210,7 +1246,7 @@ pub const BVC_REL_CCOST: u32 = 2;
 // Bytes:           2
 // Cycles:          ~*2
 pub const BVS_REL: Byte = 0x70;
-pub const BVS_REL_CCOST: u32 = 2;
+pub const BVS_REL_CCOST: u64 = 2;
 
 ////////// Clear Carry Flag (CLC) //////////
 //
@@ -1221,7 +1257,7 @@ pub const BVS_REL_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const CLC_IMP: Byte = 0x18;
-pub const CLC_IMP_CCOST: u32 = 2;
+pub const CLC_IMP_CCOST: u64 = 2;
 
 ////////// Clear Decimal Mode (CLD) //////////
 //
@@ -1234,7 +1270,7 @@ pub const CLC_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const CLD_IMP: Byte = 0xD8;
-pub const CLD_IMP_CCOST: u32 = 2;
+pub const CLD_IMP_CCOST: u64 = 2;
 
 ////////// Clear Interrupt Disable (CLI) //////////
 //
@@ -1245,7 +1281,7 @@ pub const CLD_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const CLI_IMP: Byte = 0x58;
-pub const CLI_IMP_CCOST: u32 = 2;
+pub const CLI_IMP_CCOST: u64 = 2;
 
 ////////// Clear Overflow Flag (CLV) //////////
 //
@@ -1256,7 +1292,7 @@ pub const CLI_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const CLV_IMP: Byte = 0xB8;
-pub const CLV_IMP_CCOST: u32 = 2;
+pub const CLV_IMP_CCOST: u64 = 2;
 
 ////////// Set Carry Flag (SEC) //////////
 //
@@ -1267,7 +1303,7 @@ pub const CLV_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const SEC_IMP: Byte = 0x38;
-pub const SEC_IMP_CCOST: u32 = 2;
+pub const SEC_IMP_CCOST: u64 = 2;
 
 ////////// Set Decimal Mode (SED) //////////
 //
@@ -1278,7 +1314,7 @@ pub const SEC_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const SED_IMP: Byte = 0xF8;
-pub const SED_IMP_CCOST: u32 = 2;
+pub const SED_IMP_CCOST: u64 = 2;
 
 ////////// Set Interrupt Disable Status (SEI) //////////
 //
@@ -1289,7 +1325,7 @@ pub const SED_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const SEI_IMP: Byte = 0x78;
-pub const SEI_IMP_CCOST: u32 = 2;
+pub const SEI_IMP_CCOST: u64 = 2;
 
 ////////// No Operation (NOP) //////////
 //
@@ -1300,4 +1336,2098 @@ pub const SEI_IMP_CCOST: u32 = 2;
 // Bytes:           1
 // Cycles:          2
 pub const NOP_IMP: Byte = 0xEA;
-pub const NOP_IMP_CCOST: u32 = 2;
+pub const NOP_IMP_CCOST: u64 = 2;
+
+////////// Undocumented NMOS Instructions //////////
+//
+// Only decoded when `Mos6502Config::illegal_opcodes` is set.
+//
+// Reference(s):
+//    https://www.masswerk.at/nowgobang/2021/6502-illegal-opcodes
+//    "No More Secrets - NMOS 6510 Unintended Opcodes"
+
+////////// ASL Memory then OR with Accumulator (SLO) //////////
+//
+// Status Flags Affected: {N, Z, C}
+
+// Addressing Mode: Zero Page
+// Opcode:          $07
+// Bytes:           2
+// Cycles:          5
+pub const SLO_ZP: Byte = 0x07;
+pub const SLO_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $17
+// Bytes:           2
+// Cycles:          6
+pub const SLO_ZPX: Byte = 0x17;
+pub const SLO_ZPX_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute
+// Opcode:          $0F
+// Bytes:           3
+// Cycles:          6
+pub const SLO_ABS: Byte = 0x0F;
+pub const SLO_ABS_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $1F
+// Bytes:           3
+// Cycles:          7
+pub const SLO_ABSX: Byte = 0x1F;
+pub const SLO_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $1B
+// Bytes:           3
+// Cycles:          7
+pub const SLO_ABSY: Byte = 0x1B;
+pub const SLO_ABSY_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Zero Page Indirect
+// Opcode:          $03
+// Bytes:           2
+// Cycles:          8
+pub const SLO_ZPX_IND: Byte = 0x03;
+pub const SLO_ZPX_IND_CCOST: u64 = 8;
+
+// Addressing Mode: Zero Page Indirect Y-Indexed
+// Opcode:          $13
+// Bytes:           2
+// Cycles:          8
+pub const SLO_ZPY_IND: Byte = 0x13;
+pub const SLO_ZPY_IND_CCOST: u64 = 8;
+
+////////// ROL Memory then AND with Accumulator (RLA) //////////
+//
+// Status Flags Affected: {N, Z, C}
+
+// Addressing Mode: Zero Page
+// Opcode:          $27
+// Bytes:           2
+// Cycles:          5
+pub const RLA_ZP: Byte = 0x27;
+pub const RLA_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $37
+// Bytes:           2
+// Cycles:          6
+pub const RLA_ZPX: Byte = 0x37;
+pub const RLA_ZPX_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute
+// Opcode:          $2F
+// Bytes:           3
+// Cycles:          6
+pub const RLA_ABS: Byte = 0x2F;
+pub const RLA_ABS_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $3F
+// Bytes:           3
+// Cycles:          7
+pub const RLA_ABSX: Byte = 0x3F;
+pub const RLA_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $3B
+// Bytes:           3
+// Cycles:          7
+pub const RLA_ABSY: Byte = 0x3B;
+pub const RLA_ABSY_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Zero Page Indirect
+// Opcode:          $23
+// Bytes:           2
+// Cycles:          8
+pub const RLA_ZPX_IND: Byte = 0x23;
+pub const RLA_ZPX_IND_CCOST: u64 = 8;
+
+// Addressing Mode: Zero Page Indirect Y-Indexed
+// Opcode:          $33
+// Bytes:           2
+// Cycles:          8
+pub const RLA_ZPY_IND: Byte = 0x33;
+pub const RLA_ZPY_IND_CCOST: u64 = 8;
+
+////////// LSR Memory then EOR with Accumulator (SRE) //////////
+//
+// Status Flags Affected: {N, Z, C}
+
+// Addressing Mode: Zero Page
+// Opcode:          $47
+// Bytes:           2
+// Cycles:          5
+pub const SRE_ZP: Byte = 0x47;
+pub const SRE_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $57
+// Bytes:           2
+// Cycles:          6
+pub const SRE_ZPX: Byte = 0x57;
+pub const SRE_ZPX_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute
+// Opcode:          $4F
+// Bytes:           3
+// Cycles:          6
+pub const SRE_ABS: Byte = 0x4F;
+pub const SRE_ABS_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $5F
+// Bytes:           3
+// Cycles:          7
+pub const SRE_ABSX: Byte = 0x5F;
+pub const SRE_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $5B
+// Bytes:           3
+// Cycles:          7
+pub const SRE_ABSY: Byte = 0x5B;
+pub const SRE_ABSY_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Zero Page Indirect
+// Opcode:          $43
+// Bytes:           2
+// Cycles:          8
+pub const SRE_ZPX_IND: Byte = 0x43;
+pub const SRE_ZPX_IND_CCOST: u64 = 8;
+
+// Addressing Mode: Zero Page Indirect Y-Indexed
+// Opcode:          $53
+// Bytes:           2
+// Cycles:          8
+pub const SRE_ZPY_IND: Byte = 0x53;
+pub const SRE_ZPY_IND_CCOST: u64 = 8;
+
+////////// ROR Memory then Add to Accumulator with Carry (RRA) //////////
+//
+// Status Flags Affected: {N, V, Z, C}
+//
+// Note: The ADC part honours decimal mode.
+
+// Addressing Mode: Zero Page
+// Opcode:          $67
+// Bytes:           2
+// Cycles:          5
+pub const RRA_ZP: Byte = 0x67;
+pub const RRA_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $77
+// Bytes:           2
+// Cycles:          6
+pub const RRA_ZPX: Byte = 0x77;
+pub const RRA_ZPX_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute
+// Opcode:          $6F
+// Bytes:           3
+// Cycles:          6
+pub const RRA_ABS: Byte = 0x6F;
+pub const RRA_ABS_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $7F
+// Bytes:           3
+// Cycles:          7
+pub const RRA_ABSX: Byte = 0x7F;
+pub const RRA_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $7B
+// Bytes:           3
+// Cycles:          7
+pub const RRA_ABSY: Byte = 0x7B;
+pub const RRA_ABSY_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Zero Page Indirect
+// Opcode:          $63
+// Bytes:           2
+// Cycles:          8
+pub const RRA_ZPX_IND: Byte = 0x63;
+pub const RRA_ZPX_IND_CCOST: u64 = 8;
+
+// Addressing Mode: Zero Page Indirect Y-Indexed
+// Opcode:          $73
+// Bytes:           2
+// Cycles:          8
+pub const RRA_ZPY_IND: Byte = 0x73;
+pub const RRA_ZPY_IND_CCOST: u64 = 8;
+
+////////// Store Accumulator AND Index X in Memory (SAX) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Zero Page
+// Opcode:          $87
+// Bytes:           2
+// Cycles:          3
+pub const SAX_ZP: Byte = 0x87;
+pub const SAX_ZP_CCOST: u64 = 3;
+
+// Addressing Mode: Y-Indexed Zero Page
+// Opcode:          $97
+// Bytes:           2
+// Cycles:          4
+pub const SAX_ZPY: Byte = 0x97;
+pub const SAX_ZPY_CCOST: u64 = 4;
+
+// Addressing Mode: Absolute
+// Opcode:          $8F
+// Bytes:           3
+// Cycles:          4
+pub const SAX_ABS: Byte = 0x8F;
+pub const SAX_ABS_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Zero Page Indirect
+// Opcode:          $83
+// Bytes:           2
+// Cycles:          6
+pub const SAX_ZPX_IND: Byte = 0x83;
+pub const SAX_ZPX_IND_CCOST: u64 = 6;
+
+////////// Load Accumulator and Index X from Memory (LAX) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Zero Page
+// Opcode:          $A7
+// Bytes:           2
+// Cycles:          3
+pub const LAX_ZP: Byte = 0xA7;
+pub const LAX_ZP_CCOST: u64 = 3;
+
+// Addressing Mode: Y-Indexed Zero Page
+// Opcode:          $B7
+// Bytes:           2
+// Cycles:          4
+pub const LAX_ZPY: Byte = 0xB7;
+pub const LAX_ZPY_CCOST: u64 = 4;
+
+// Addressing Mode: Absolute
+// Opcode:          $AF
+// Bytes:           3
+// Cycles:          4
+pub const LAX_ABS: Byte = 0xAF;
+pub const LAX_ABS_CCOST: u64 = 4;
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $BF
+// Bytes:           3
+// Cycles:          ~4
+pub const LAX_ABSY: Byte = 0xBF;
+pub const LAX_ABSY_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Zero Page Indirect
+// Opcode:          $A3
+// Bytes:           2
+// Cycles:          6
+pub const LAX_ZPX_IND: Byte = 0xA3;
+pub const LAX_ZPX_IND_CCOST: u64 = 6;
+
+// Addressing Mode: Zero Page Indirect Y-Indexed
+// Opcode:          $B3
+// Bytes:           2
+// Cycles:          ~5
+pub const LAX_ZPY_IND: Byte = 0xB3;
+pub const LAX_ZPY_IND_CCOST: u64 = 5;
+
+////////// DEC Memory then Compare with Accumulator (DCP) //////////
+//
+// Status Flags Affected: {N, Z, C}
+
+// Addressing Mode: Zero Page
+// Opcode:          $C7
+// Bytes:           2
+// Cycles:          5
+pub const DCP_ZP: Byte = 0xC7;
+pub const DCP_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $D7
+// Bytes:           2
+// Cycles:          6
+pub const DCP_ZPX: Byte = 0xD7;
+pub const DCP_ZPX_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute
+// Opcode:          $CF
+// Bytes:           3
+// Cycles:          6
+pub const DCP_ABS: Byte = 0xCF;
+pub const DCP_ABS_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $DF
+// Bytes:           3
+// Cycles:          7
+pub const DCP_ABSX: Byte = 0xDF;
+pub const DCP_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $DB
+// Bytes:           3
+// Cycles:          7
+pub const DCP_ABSY: Byte = 0xDB;
+pub const DCP_ABSY_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Zero Page Indirect
+// Opcode:          $C3
+// Bytes:           2
+// Cycles:          8
+pub const DCP_ZPX_IND: Byte = 0xC3;
+pub const DCP_ZPX_IND_CCOST: u64 = 8;
+
+// Addressing Mode: Zero Page Indirect Y-Indexed
+// Opcode:          $D3
+// Bytes:           2
+// Cycles:          8
+pub const DCP_ZPY_IND: Byte = 0xD3;
+pub const DCP_ZPY_IND_CCOST: u64 = 8;
+
+////////// INC Memory then Subtract from Accumulator with Borrow (ISC) //////////
+//
+// Status Flags Affected: {N, V, Z, C}
+//
+// Note: The SBC part honours decimal mode.
+
+// Addressing Mode: Zero Page
+// Opcode:          $E7
+// Bytes:           2
+// Cycles:          5
+pub const ISC_ZP: Byte = 0xE7;
+pub const ISC_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $F7
+// Bytes:           2
+// Cycles:          6
+pub const ISC_ZPX: Byte = 0xF7;
+pub const ISC_ZPX_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute
+// Opcode:          $EF
+// Bytes:           3
+// Cycles:          6
+pub const ISC_ABS: Byte = 0xEF;
+pub const ISC_ABS_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $FF
+// Bytes:           3
+// Cycles:          7
+pub const ISC_ABSX: Byte = 0xFF;
+pub const ISC_ABSX_CCOST: u64 = 7;
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $FB
+// Bytes:           3
+// Cycles:          7
+pub const ISC_ABSY: Byte = 0xFB;
+pub const ISC_ABSY_CCOST: u64 = 7;
+
+// Addressing Mode: X-Indexed Zero Page Indirect
+// Opcode:          $E3
+// Bytes:           2
+// Cycles:          8
+pub const ISC_ZPX_IND: Byte = 0xE3;
+pub const ISC_ZPX_IND_CCOST: u64 = 8;
+
+// Addressing Mode: Zero Page Indirect Y-Indexed
+// Opcode:          $F3
+// Bytes:           2
+// Cycles:          8
+pub const ISC_ZPY_IND: Byte = 0xF3;
+pub const ISC_ZPY_IND_CCOST: u64 = 8;
+
+////////// AND Memory with Accumulator then Copy N to C (ANC) //////////
+//
+// Status Flags Affected: {N, Z, C}
+
+// Addressing Mode: Immediate
+// Opcode:          $0B
+// Bytes:           2
+// Cycles:          2
+pub const ANC_IMM: Byte = 0x0B;
+pub const ANC_IMM_CCOST: u64 = 2;
+
+// Addressing Mode: Immediate
+// Opcode:          $2B
+// Bytes:           2
+// Cycles:          2
+pub const ANC_IMM_2B: Byte = 0x2B;
+pub const ANC_IMM_2B_CCOST: u64 = 2;
+
+////////// AND Memory with Accumulator then LSR (ALR) //////////
+//
+// Status Flags Affected: {N, Z, C}
+
+// Addressing Mode: Immediate
+// Opcode:          $4B
+// Bytes:           2
+// Cycles:          2
+pub const ALR_IMM: Byte = 0x4B;
+pub const ALR_IMM_CCOST: u64 = 2;
+
+////////// AND Memory with Accumulator then ROR (ARR) //////////
+//
+// Status Flags Affected: {N, V, Z, C}
+//
+// Note: C and V come from bits 6 and 5 of the result, and decimal mode
+// adjusts the result with its own rules.
+
+// Addressing Mode: Immediate
+// Opcode:          $6B
+// Bytes:           2
+// Cycles:          2
+pub const ARR_IMM: Byte = 0x6B;
+pub const ARR_IMM_CCOST: u64 = 2;
+
+////////// Subtract Memory from Accumulator AND Index X (SBX) //////////
+//
+// Status Flags Affected: {N, Z, C}
+//
+// Note: Compares like CMP, no borrow in and decimal mode is ignored.
+
+// Addressing Mode: Immediate
+// Opcode:          $CB
+// Bytes:           2
+// Cycles:          2
+pub const SBX_IMM: Byte = 0xCB;
+pub const SBX_IMM_CCOST: u64 = 2;
+
+////////// Subtract Memory from Accumulator with Borrow (USBC) //////////
+//
+// Status Flags Affected: {N, V, Z, C}
+//
+// Note: Same as SBC #imm.
+
+// Addressing Mode: Immediate
+// Opcode:          $EB
+// Bytes:           2
+// Cycles:          2
+pub const USBC_IMM: Byte = 0xEB;
+pub const USBC_IMM_CCOST: u64 = 2;
+
+////////// No Operation (NOP) //////////
+//
+// Status Flags Affected: ∅
+//
+// Note: Undocumented variants, the operand is read and thrown away.
+
+// Addressing Mode: Implied
+// Opcode:          $1A
+// Bytes:           1
+// Cycles:          2
+pub const NOP_IMP_1A: Byte = 0x1A;
+pub const NOP_IMP_1A_CCOST: u64 = 2;
+
+// Addressing Mode: Implied
+// Opcode:          $3A
+// Bytes:           1
+// Cycles:          2
+pub const NOP_IMP_3A: Byte = 0x3A;
+pub const NOP_IMP_3A_CCOST: u64 = 2;
+
+// Addressing Mode: Implied
+// Opcode:          $5A
+// Bytes:           1
+// Cycles:          2
+pub const NOP_IMP_5A: Byte = 0x5A;
+pub const NOP_IMP_5A_CCOST: u64 = 2;
+
+// Addressing Mode: Implied
+// Opcode:          $7A
+// Bytes:           1
+// Cycles:          2
+pub const NOP_IMP_7A: Byte = 0x7A;
+pub const NOP_IMP_7A_CCOST: u64 = 2;
+
+// Addressing Mode: Implied
+// Opcode:          $DA
+// Bytes:           1
+// Cycles:          2
+pub const NOP_IMP_DA: Byte = 0xDA;
+pub const NOP_IMP_DA_CCOST: u64 = 2;
+
+// Addressing Mode: Implied
+// Opcode:          $FA
+// Bytes:           1
+// Cycles:          2
+pub const NOP_IMP_FA: Byte = 0xFA;
+pub const NOP_IMP_FA_CCOST: u64 = 2;
+
+// Addressing Mode: Immediate
+// Opcode:          $80
+// Bytes:           2
+// Cycles:          2
+pub const NOP_IMM_80: Byte = 0x80;
+pub const NOP_IMM_80_CCOST: u64 = 2;
+
+// Addressing Mode: Immediate
+// Opcode:          $82
+// Bytes:           2
+// Cycles:          2
+pub const NOP_IMM_82: Byte = 0x82;
+pub const NOP_IMM_82_CCOST: u64 = 2;
+
+// Addressing Mode: Immediate
+// Opcode:          $89
+// Bytes:           2
+// Cycles:          2
+pub const NOP_IMM_89: Byte = 0x89;
+pub const NOP_IMM_89_CCOST: u64 = 2;
+
+// Addressing Mode: Immediate
+// Opcode:          $C2
+// Bytes:           2
+// Cycles:          2
+pub const NOP_IMM_C2: Byte = 0xC2;
+pub const NOP_IMM_C2_CCOST: u64 = 2;
+
+// Addressing Mode: Immediate
+// Opcode:          $E2
+// Bytes:           2
+// Cycles:          2
+pub const NOP_IMM_E2: Byte = 0xE2;
+pub const NOP_IMM_E2_CCOST: u64 = 2;
+
+// Addressing Mode: Zero Page
+// Opcode:          $04
+// Bytes:           2
+// Cycles:          3
+pub const NOP_ZP_04: Byte = 0x04;
+pub const NOP_ZP_04_CCOST: u64 = 3;
+
+// Addressing Mode: Zero Page
+// Opcode:          $44
+// Bytes:           2
+// Cycles:          3
+pub const NOP_ZP_44: Byte = 0x44;
+pub const NOP_ZP_44_CCOST: u64 = 3;
+
+// Addressing Mode: Zero Page
+// Opcode:          $64
+// Bytes:           2
+// Cycles:          3
+pub const NOP_ZP_64: Byte = 0x64;
+pub const NOP_ZP_64_CCOST: u64 = 3;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $14
+// Bytes:           2
+// Cycles:          4
+pub const NOP_ZPX_14: Byte = 0x14;
+pub const NOP_ZPX_14_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $34
+// Bytes:           2
+// Cycles:          4
+pub const NOP_ZPX_34: Byte = 0x34;
+pub const NOP_ZPX_34_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $54
+// Bytes:           2
+// Cycles:          4
+pub const NOP_ZPX_54: Byte = 0x54;
+pub const NOP_ZPX_54_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $74
+// Bytes:           2
+// Cycles:          4
+pub const NOP_ZPX_74: Byte = 0x74;
+pub const NOP_ZPX_74_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $D4
+// Bytes:           2
+// Cycles:          4
+pub const NOP_ZPX_D4: Byte = 0xD4;
+pub const NOP_ZPX_D4_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $F4
+// Bytes:           2
+// Cycles:          4
+pub const NOP_ZPX_F4: Byte = 0xF4;
+pub const NOP_ZPX_F4_CCOST: u64 = 4;
+
+// Addressing Mode: Absolute
+// Opcode:          $0C
+// Bytes:           3
+// Cycles:          4
+pub const NOP_ABS_0C: Byte = 0x0C;
+pub const NOP_ABS_0C_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $1C
+// Bytes:           3
+// Cycles:          ~4
+pub const NOP_ABSX_1C: Byte = 0x1C;
+pub const NOP_ABSX_1C_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $3C
+// Bytes:           3
+// Cycles:          ~4
+pub const NOP_ABSX_3C: Byte = 0x3C;
+pub const NOP_ABSX_3C_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $5C
+// Bytes:           3
+// Cycles:          ~4
+pub const NOP_ABSX_5C: Byte = 0x5C;
+pub const NOP_ABSX_5C_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $7C
+// Bytes:           3
+// Cycles:          ~4
+pub const NOP_ABSX_7C: Byte = 0x7C;
+pub const NOP_ABSX_7C_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $DC
+// Bytes:           3
+// Cycles:          ~4
+pub const NOP_ABSX_DC: Byte = 0xDC;
+pub const NOP_ABSX_DC_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $FC
+// Bytes:           3
+// Cycles:          ~4
+pub const NOP_ABSX_FC: Byte = 0xFC;
+pub const NOP_ABSX_FC_CCOST: u64 = 4;
+
+////////// Unstable Undocumented NMOS Instructions //////////
+//
+// Results of these depend on the individual chip. ANE and LXA are
+// modelled with `Mos6502Config::magic`; the SH* stores AND the value
+// with the high byte of the base address plus one and, when the
+// index crosses a page, the stored value replaces the high byte of
+// the target address.
+
+////////// AND X Register and Operand with (Accumulator OR Magic) (ANE) //////////
+//
+// Status Flags Affected: {N, Z}
+//
+// A = (A | magic) & X & operand
+
+// Addressing Mode: Immediate
+// Opcode:          $8B
+// Bytes:           2
+// Cycles:          2
+pub const ANE_IMM: Byte = 0x8B;
+pub const ANE_IMM_CCOST: u64 = 2;
+
+////////// Load Accumulator and X Register with (Accumulator OR Magic) AND Operand (LXA) //////////
+//
+// Status Flags Affected: {N, Z}
+//
+// A, X = (A | magic) & operand
+
+// Addressing Mode: Immediate
+// Opcode:          $AB
+// Bytes:           2
+// Cycles:          2
+pub const LXA_IMM: Byte = 0xAB;
+pub const LXA_IMM_CCOST: u64 = 2;
+
+////////// Store Accumulator AND X Register AND (High Byte + 1) (SHA) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $9F
+// Bytes:           3
+// Cycles:          5
+pub const SHA_ABSY: Byte = 0x9F;
+pub const SHA_ABSY_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Indirect Y-Indexed
+// Opcode:          $93
+// Bytes:           2
+// Cycles:          6
+pub const SHA_ZPY_IND: Byte = 0x93;
+pub const SHA_ZPY_IND_CCOST: u64 = 6;
+
+////////// Store X Register AND (High Byte + 1) (SHX) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $9E
+// Bytes:           3
+// Cycles:          5
+pub const SHX_ABSY: Byte = 0x9E;
+pub const SHX_ABSY_CCOST: u64 = 5;
+
+////////// Store Y Register AND (High Byte + 1) (SHY) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $9C
+// Bytes:           3
+// Cycles:          5
+pub const SHY_ABSX: Byte = 0x9C;
+pub const SHY_ABSX_CCOST: u64 = 5;
+
+////////// Transfer Accumulator AND X Register to Stack Pointer then SHA (TAS) //////////
+//
+// Status Flags Affected: ∅
+//
+// SP = A & X, then SP & (high byte + 1) is stored
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $9B
+// Bytes:           3
+// Cycles:          5
+pub const TAS_ABSY: Byte = 0x9B;
+pub const TAS_ABSY_CCOST: u64 = 5;
+
+////////// Load Accumulator, X Register and Stack Pointer with Memory AND Stack Pointer (LAS) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Y-Indexed Absolute
+// Opcode:          $BB
+// Bytes:           3
+// Cycles:          ~4
+pub const LAS_ABSY: Byte = 0xBB;
+pub const LAS_ABSY_CCOST: u64 = 4;
+
+////////// Halt the CPU (JAM) //////////
+//
+// Status Flags Affected: ∅
+//
+// Also known as KIL/HLT. Locks up the CPU until `reset()`, decoded
+// regardless of `Mos6502Config::illegal_opcodes`.
+
+// Addressing Mode: Implied
+// Opcode:          $02
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_02: Byte = 0x02;
+
+// Addressing Mode: Implied
+// Opcode:          $12
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_12: Byte = 0x12;
+
+// Addressing Mode: Implied
+// Opcode:          $22
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_22: Byte = 0x22;
+
+// Addressing Mode: Implied
+// Opcode:          $32
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_32: Byte = 0x32;
+
+// Addressing Mode: Implied
+// Opcode:          $42
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_42: Byte = 0x42;
+
+// Addressing Mode: Implied
+// Opcode:          $52
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_52: Byte = 0x52;
+
+// Addressing Mode: Implied
+// Opcode:          $62
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_62: Byte = 0x62;
+
+// Addressing Mode: Implied
+// Opcode:          $72
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_72: Byte = 0x72;
+
+// Addressing Mode: Implied
+// Opcode:          $92
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_92: Byte = 0x92;
+
+// Addressing Mode: Implied
+// Opcode:          $B2
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_B2: Byte = 0xB2;
+
+// Addressing Mode: Implied
+// Opcode:          $D2
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_D2: Byte = 0xD2;
+
+// Addressing Mode: Implied
+// Opcode:          $F2
+// Bytes:           1
+// Cycles:          ∞
+pub const JAM_IMP_F2: Byte = 0xF2;
+
+////////// 65C02 Instructions //////////
+//
+// Only decoded when `Mos6502Config::variant` is a CMOS part.
+//
+// Reference(s):
+//    https://www.westerndesigncenter.com/wdc/documentation/w65c02s.pdf
+
+////////// Branch Always (BRA) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Relative
+// Opcode:          $80
+// Bytes:           2
+// Cycles:          ~3
+pub const BRA_REL: Byte = 0x80;
+pub const BRA_REL_CCOST: u64 = 3;
+
+////////// Push X Register on Stack (PHX) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Implied
+// Opcode:          $DA
+// Bytes:           1
+// Cycles:          3
+pub const PHX_IMP: Byte = 0xDA;
+pub const PHX_IMP_CCOST: u64 = 3;
+
+////////// Push Y Register on Stack (PHY) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Implied
+// Opcode:          $5A
+// Bytes:           1
+// Cycles:          3
+pub const PHY_IMP: Byte = 0x5A;
+pub const PHY_IMP_CCOST: u64 = 3;
+
+////////// Pull X Register from Stack (PLX) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Implied
+// Opcode:          $FA
+// Bytes:           1
+// Cycles:          4
+pub const PLX_IMP: Byte = 0xFA;
+pub const PLX_IMP_CCOST: u64 = 4;
+
+////////// Pull Y Register from Stack (PLY) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Implied
+// Opcode:          $7A
+// Bytes:           1
+// Cycles:          4
+pub const PLY_IMP: Byte = 0x7A;
+pub const PLY_IMP_CCOST: u64 = 4;
+
+////////// Store Zero in Memory (STZ) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Zero Page
+// Opcode:          $64
+// Bytes:           2
+// Cycles:          3
+pub const STZ_ZP: Byte = 0x64;
+pub const STZ_ZP_CCOST: u64 = 3;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $74
+// Bytes:           2
+// Cycles:          4
+pub const STZ_ZPX: Byte = 0x74;
+pub const STZ_ZPX_CCOST: u64 = 4;
+
+// Addressing Mode: Absolute
+// Opcode:          $9C
+// Bytes:           3
+// Cycles:          4
+pub const STZ_ABS: Byte = 0x9C;
+pub const STZ_ABS_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $9E
+// Bytes:           3
+// Cycles:          5
+pub const STZ_ABSX: Byte = 0x9E;
+pub const STZ_ABSX_CCOST: u64 = 5;
+
+////////// Test and Reset Memory Bits with Accumulator (TRB) //////////
+//
+// Status Flags Affected: {Z}
+
+// Addressing Mode: Zero Page
+// Opcode:          $14
+// Bytes:           2
+// Cycles:          5
+pub const TRB_ZP: Byte = 0x14;
+pub const TRB_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Absolute
+// Opcode:          $1C
+// Bytes:           3
+// Cycles:          6
+pub const TRB_ABS: Byte = 0x1C;
+pub const TRB_ABS_CCOST: u64 = 6;
+
+////////// Test and Set Memory Bits with Accumulator (TSB) //////////
+//
+// Status Flags Affected: {Z}
+
+// Addressing Mode: Zero Page
+// Opcode:          $04
+// Bytes:           2
+// Cycles:          5
+pub const TSB_ZP: Byte = 0x04;
+pub const TSB_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Absolute
+// Opcode:          $0C
+// Bytes:           3
+// Cycles:          6
+pub const TSB_ABS: Byte = 0x0C;
+pub const TSB_ABS_CCOST: u64 = 6;
+
+////////// Increment Accumulator by One (INC) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Accumulator
+// Opcode:          $1A
+// Bytes:           1
+// Cycles:          2
+pub const INC_ACC: Byte = 0x1A;
+pub const INC_ACC_CCOST: u64 = 2;
+
+////////// Decrement Accumulator by One (DEC) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Accumulator
+// Opcode:          $3A
+// Bytes:           1
+// Cycles:          2
+pub const DEC_ACC: Byte = 0x3A;
+pub const DEC_ACC_CCOST: u64 = 2;
+
+////////// Test Bits in Memory with Accumulator (BIT) //////////
+//
+// Status Flags Affected: {N, V, Z}
+//
+// Immediate only affects Z.
+
+// Addressing Mode: Immediate
+// Opcode:          $89
+// Bytes:           2
+// Cycles:          2
+pub const BIT_IMM: Byte = 0x89;
+pub const BIT_IMM_CCOST: u64 = 2;
+
+// Addressing Mode: X-Indexed Zero Page
+// Opcode:          $34
+// Bytes:           2
+// Cycles:          4
+pub const BIT_ZPX: Byte = 0x34;
+pub const BIT_ZPX_CCOST: u64 = 4;
+
+// Addressing Mode: X-Indexed Absolute
+// Opcode:          $3C
+// Bytes:           3
+// Cycles:          ~4
+pub const BIT_ABSX: Byte = 0x3C;
+pub const BIT_ABSX_CCOST: u64 = 4;
+
+////////// Jump to New Location (JMP) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: X-Indexed Absolute Indirect
+// Opcode:          $7C
+// Bytes:           3
+// Cycles:          6
+pub const JMP_ABSX_IND: Byte = 0x7C;
+pub const JMP_ABSX_IND_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute Indirect
+// Opcode:          $6C
+// Bytes:           3
+// Cycles:          6
+//
+// Note: Same opcode as `JMP_ABS_IND`, CMOS parts take one more
+// cycle and carry into the pointer's high byte.
+pub const JMP_ABS_IND_CMOS_CCOST: u64 = 6;
+
+////////// OR Memory with Accumulator (ORA) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Zero Page Indirect
+// Opcode:          $12
+// Bytes:           2
+// Cycles:          5
+pub const ORA_ZP_IND: Byte = 0x12;
+pub const ORA_ZP_IND_CCOST: u64 = 5;
+
+////////// AND Memory with Accumulator (AND) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Zero Page Indirect
+// Opcode:          $32
+// Bytes:           2
+// Cycles:          5
+pub const AND_ZP_IND: Byte = 0x32;
+pub const AND_ZP_IND_CCOST: u64 = 5;
+
+////////// Exclusive-OR Memory with Accumulator (EOR) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Zero Page Indirect
+// Opcode:          $52
+// Bytes:           2
+// Cycles:          5
+pub const EOR_ZP_IND: Byte = 0x52;
+pub const EOR_ZP_IND_CCOST: u64 = 5;
+
+////////// Add Memory to Accumulator with Carry (ADC) //////////
+//
+// Status Flags Affected: {N, V, Z, C}
+
+// Addressing Mode: Zero Page Indirect
+// Opcode:          $72
+// Bytes:           2
+// Cycles:          5
+pub const ADC_ZP_IND: Byte = 0x72;
+pub const ADC_ZP_IND_CCOST: u64 = 5;
+
+////////// Store Accumulator in Memory (STA) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Zero Page Indirect
+// Opcode:          $92
+// Bytes:           2
+// Cycles:          5
+pub const STA_ZP_IND: Byte = 0x92;
+pub const STA_ZP_IND_CCOST: u64 = 5;
+
+////////// Load Accumulator with Memory (LDA) //////////
+//
+// Status Flags Affected: {N, Z}
+
+// Addressing Mode: Zero Page Indirect
+// Opcode:          $B2
+// Bytes:           2
+// Cycles:          5
+pub const LDA_ZP_IND: Byte = 0xB2;
+pub const LDA_ZP_IND_CCOST: u64 = 5;
+
+////////// Compare Memory with Accumulator (CMP) //////////
+//
+// Status Flags Affected: {N, Z, C}
+
+// Addressing Mode: Zero Page Indirect
+// Opcode:          $D2
+// Bytes:           2
+// Cycles:          5
+pub const CMP_ZP_IND: Byte = 0xD2;
+pub const CMP_ZP_IND_CCOST: u64 = 5;
+
+////////// Subtract Memory from Accumulator with Borrow (SBC) //////////
+//
+// Status Flags Affected: {N, V, Z, C}
+
+// Addressing Mode: Zero Page Indirect
+// Opcode:          $F2
+// Bytes:           2
+// Cycles:          5
+pub const SBC_ZP_IND: Byte = 0xF2;
+pub const SBC_ZP_IND_CCOST: u64 = 5;
+
+////////// R65C02/W65C02S Instructions //////////
+//
+// Only decoded for `Mos6502Variant::Rockwell65C02` and
+// `Mos6502Variant::Wdc65C02S`, WAI and STP only for the latter.
+
+////////// Reset Memory Bit (RMB) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Zero Page
+// Opcode:          $07
+// Bytes:           2
+// Cycles:          5
+pub const RMB0_ZP: Byte = 0x07;
+pub const RMB0_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $17
+// Bytes:           2
+// Cycles:          5
+pub const RMB1_ZP: Byte = 0x17;
+pub const RMB1_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $27
+// Bytes:           2
+// Cycles:          5
+pub const RMB2_ZP: Byte = 0x27;
+pub const RMB2_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $37
+// Bytes:           2
+// Cycles:          5
+pub const RMB3_ZP: Byte = 0x37;
+pub const RMB3_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $47
+// Bytes:           2
+// Cycles:          5
+pub const RMB4_ZP: Byte = 0x47;
+pub const RMB4_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $57
+// Bytes:           2
+// Cycles:          5
+pub const RMB5_ZP: Byte = 0x57;
+pub const RMB5_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $67
+// Bytes:           2
+// Cycles:          5
+pub const RMB6_ZP: Byte = 0x67;
+pub const RMB6_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $77
+// Bytes:           2
+// Cycles:          5
+pub const RMB7_ZP: Byte = 0x77;
+pub const RMB7_ZP_CCOST: u64 = 5;
+
+////////// Set Memory Bit (SMB) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Zero Page
+// Opcode:          $87
+// Bytes:           2
+// Cycles:          5
+pub const SMB0_ZP: Byte = 0x87;
+pub const SMB0_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $97
+// Bytes:           2
+// Cycles:          5
+pub const SMB1_ZP: Byte = 0x97;
+pub const SMB1_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $A7
+// Bytes:           2
+// Cycles:          5
+pub const SMB2_ZP: Byte = 0xA7;
+pub const SMB2_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $B7
+// Bytes:           2
+// Cycles:          5
+pub const SMB3_ZP: Byte = 0xB7;
+pub const SMB3_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $C7
+// Bytes:           2
+// Cycles:          5
+pub const SMB4_ZP: Byte = 0xC7;
+pub const SMB4_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $D7
+// Bytes:           2
+// Cycles:          5
+pub const SMB5_ZP: Byte = 0xD7;
+pub const SMB5_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $E7
+// Bytes:           2
+// Cycles:          5
+pub const SMB6_ZP: Byte = 0xE7;
+pub const SMB6_ZP_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page
+// Opcode:          $F7
+// Bytes:           2
+// Cycles:          5
+pub const SMB7_ZP: Byte = 0xF7;
+pub const SMB7_ZP_CCOST: u64 = 5;
+
+////////// Branch on Memory Bit Reset (BBR) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $0F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBR0_ZP_REL: Byte = 0x0F;
+pub const BBR0_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $1F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBR1_ZP_REL: Byte = 0x1F;
+pub const BBR1_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $2F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBR2_ZP_REL: Byte = 0x2F;
+pub const BBR2_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $3F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBR3_ZP_REL: Byte = 0x3F;
+pub const BBR3_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $4F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBR4_ZP_REL: Byte = 0x4F;
+pub const BBR4_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $5F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBR5_ZP_REL: Byte = 0x5F;
+pub const BBR5_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $6F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBR6_ZP_REL: Byte = 0x6F;
+pub const BBR6_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $7F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBR7_ZP_REL: Byte = 0x7F;
+pub const BBR7_ZP_REL_CCOST: u64 = 5;
+
+////////// Branch on Memory Bit Set (BBS) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $8F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBS0_ZP_REL: Byte = 0x8F;
+pub const BBS0_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $9F
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBS1_ZP_REL: Byte = 0x9F;
+pub const BBS1_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $AF
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBS2_ZP_REL: Byte = 0xAF;
+pub const BBS2_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $BF
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBS3_ZP_REL: Byte = 0xBF;
+pub const BBS3_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $CF
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBS4_ZP_REL: Byte = 0xCF;
+pub const BBS4_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $DF
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBS5_ZP_REL: Byte = 0xDF;
+pub const BBS5_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $EF
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBS6_ZP_REL: Byte = 0xEF;
+pub const BBS6_ZP_REL_CCOST: u64 = 5;
+
+// Addressing Mode: Zero Page Relative
+// Opcode:          $FF
+// Bytes:           3
+// Cycles:          ~*5
+pub const BBS7_ZP_REL: Byte = 0xFF;
+pub const BBS7_ZP_REL_CCOST: u64 = 5;
+
+////////// Wait for Interrupt (WAI) //////////
+//
+// Status Flags Affected: ∅
+//
+// Suspends the CPU until IRQ or NMI is asserted.
+
+// Addressing Mode: Implied
+// Opcode:          $CB
+// Bytes:           1
+// Cycles:          3
+pub const WAI_IMP: Byte = 0xCB;
+pub const WAI_IMP_CCOST: u64 = 3;
+
+////////// Stop the Clock (STP) //////////
+//
+// Status Flags Affected: ∅
+//
+// Stops the CPU until reset.
+
+// Addressing Mode: Implied
+// Opcode:          $DB
+// Bytes:           1
+// Cycles:          3
+pub const STP_IMP: Byte = 0xDB;
+pub const STP_IMP_CCOST: u64 = 3;
+
+////////// 65C816 Instructions //////////
+//
+// Only decoded by `W65c816`. Cycle counts are for emulation mode
+// with 8-bit registers and the direct page on a page boundary.
+// A 16-bit access costs one more cycle per extra byte.
+//
+// Reference(s):
+//    https://www.westerndesigncenter.com/wdc/documentation/w65c816s.pdf
+
+////////// Logical Inclusive OR (ORA) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Stack Relative
+// Opcode:          $03
+// Bytes:           2
+// Cycles:          4
+pub const ORA_SR: Byte = 0x03;
+pub const ORA_SR_CCOST: u64 = 4;
+
+// Addressing Mode: Direct Page Indirect Long
+// Opcode:          $07
+// Bytes:           2
+// Cycles:          6
+pub const ORA_DP_IND_LONG: Byte = 0x07;
+pub const ORA_DP_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute Long
+// Opcode:          $0F
+// Bytes:           4
+// Cycles:          5
+pub const ORA_LONG: Byte = 0x0F;
+pub const ORA_LONG_CCOST: u64 = 5;
+
+// Addressing Mode: Stack Relative Indirect Y-Indexed
+// Opcode:          $13
+// Bytes:           2
+// Cycles:          7
+pub const ORA_SRY_IND: Byte = 0x13;
+pub const ORA_SRY_IND_CCOST: u64 = 7;
+
+// Addressing Mode: Direct Page Indirect Long Y-Indexed
+// Opcode:          $17
+// Bytes:           2
+// Cycles:          6
+pub const ORA_DPY_IND_LONG: Byte = 0x17;
+pub const ORA_DPY_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute Long
+// Opcode:          $1F
+// Bytes:           4
+// Cycles:          5
+pub const ORA_LONGX: Byte = 0x1F;
+pub const ORA_LONGX_CCOST: u64 = 5;
+
+////////// Logical AND (AND) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Stack Relative
+// Opcode:          $23
+// Bytes:           2
+// Cycles:          4
+pub const AND_SR: Byte = 0x23;
+pub const AND_SR_CCOST: u64 = 4;
+
+// Addressing Mode: Direct Page Indirect Long
+// Opcode:          $27
+// Bytes:           2
+// Cycles:          6
+pub const AND_DP_IND_LONG: Byte = 0x27;
+pub const AND_DP_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute Long
+// Opcode:          $2F
+// Bytes:           4
+// Cycles:          5
+pub const AND_LONG: Byte = 0x2F;
+pub const AND_LONG_CCOST: u64 = 5;
+
+// Addressing Mode: Stack Relative Indirect Y-Indexed
+// Opcode:          $33
+// Bytes:           2
+// Cycles:          7
+pub const AND_SRY_IND: Byte = 0x33;
+pub const AND_SRY_IND_CCOST: u64 = 7;
+
+// Addressing Mode: Direct Page Indirect Long Y-Indexed
+// Opcode:          $37
+// Bytes:           2
+// Cycles:          6
+pub const AND_DPY_IND_LONG: Byte = 0x37;
+pub const AND_DPY_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute Long
+// Opcode:          $3F
+// Bytes:           4
+// Cycles:          5
+pub const AND_LONGX: Byte = 0x3F;
+pub const AND_LONGX_CCOST: u64 = 5;
+
+////////// Exclusive OR (EOR) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Stack Relative
+// Opcode:          $43
+// Bytes:           2
+// Cycles:          4
+pub const EOR_SR: Byte = 0x43;
+pub const EOR_SR_CCOST: u64 = 4;
+
+// Addressing Mode: Direct Page Indirect Long
+// Opcode:          $47
+// Bytes:           2
+// Cycles:          6
+pub const EOR_DP_IND_LONG: Byte = 0x47;
+pub const EOR_DP_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute Long
+// Opcode:          $4F
+// Bytes:           4
+// Cycles:          5
+pub const EOR_LONG: Byte = 0x4F;
+pub const EOR_LONG_CCOST: u64 = 5;
+
+// Addressing Mode: Stack Relative Indirect Y-Indexed
+// Opcode:          $53
+// Bytes:           2
+// Cycles:          7
+pub const EOR_SRY_IND: Byte = 0x53;
+pub const EOR_SRY_IND_CCOST: u64 = 7;
+
+// Addressing Mode: Direct Page Indirect Long Y-Indexed
+// Opcode:          $57
+// Bytes:           2
+// Cycles:          6
+pub const EOR_DPY_IND_LONG: Byte = 0x57;
+pub const EOR_DPY_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute Long
+// Opcode:          $5F
+// Bytes:           4
+// Cycles:          5
+pub const EOR_LONGX: Byte = 0x5F;
+pub const EOR_LONGX_CCOST: u64 = 5;
+
+////////// Add with Carry (ADC) //////////
+//
+// Status Flags Affected: N V Z C
+
+// Addressing Mode: Stack Relative
+// Opcode:          $63
+// Bytes:           2
+// Cycles:          4
+pub const ADC_SR: Byte = 0x63;
+pub const ADC_SR_CCOST: u64 = 4;
+
+// Addressing Mode: Direct Page Indirect Long
+// Opcode:          $67
+// Bytes:           2
+// Cycles:          6
+pub const ADC_DP_IND_LONG: Byte = 0x67;
+pub const ADC_DP_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute Long
+// Opcode:          $6F
+// Bytes:           4
+// Cycles:          5
+pub const ADC_LONG: Byte = 0x6F;
+pub const ADC_LONG_CCOST: u64 = 5;
+
+// Addressing Mode: Stack Relative Indirect Y-Indexed
+// Opcode:          $73
+// Bytes:           2
+// Cycles:          7
+pub const ADC_SRY_IND: Byte = 0x73;
+pub const ADC_SRY_IND_CCOST: u64 = 7;
+
+// Addressing Mode: Direct Page Indirect Long Y-Indexed
+// Opcode:          $77
+// Bytes:           2
+// Cycles:          6
+pub const ADC_DPY_IND_LONG: Byte = 0x77;
+pub const ADC_DPY_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute Long
+// Opcode:          $7F
+// Bytes:           4
+// Cycles:          5
+pub const ADC_LONGX: Byte = 0x7F;
+pub const ADC_LONGX_CCOST: u64 = 5;
+
+////////// Store Accumulator in Memory (STA) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Stack Relative
+// Opcode:          $83
+// Bytes:           2
+// Cycles:          4
+pub const STA_SR: Byte = 0x83;
+pub const STA_SR_CCOST: u64 = 4;
+
+// Addressing Mode: Direct Page Indirect Long
+// Opcode:          $87
+// Bytes:           2
+// Cycles:          6
+pub const STA_DP_IND_LONG: Byte = 0x87;
+pub const STA_DP_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute Long
+// Opcode:          $8F
+// Bytes:           4
+// Cycles:          5
+pub const STA_LONG: Byte = 0x8F;
+pub const STA_LONG_CCOST: u64 = 5;
+
+// Addressing Mode: Stack Relative Indirect Y-Indexed
+// Opcode:          $93
+// Bytes:           2
+// Cycles:          7
+pub const STA_SRY_IND: Byte = 0x93;
+pub const STA_SRY_IND_CCOST: u64 = 7;
+
+// Addressing Mode: Direct Page Indirect Long Y-Indexed
+// Opcode:          $97
+// Bytes:           2
+// Cycles:          6
+pub const STA_DPY_IND_LONG: Byte = 0x97;
+pub const STA_DPY_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute Long
+// Opcode:          $9F
+// Bytes:           4
+// Cycles:          5
+pub const STA_LONGX: Byte = 0x9F;
+pub const STA_LONGX_CCOST: u64 = 5;
+
+////////// Load Accumulator with Memory (LDA) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Stack Relative
+// Opcode:          $A3
+// Bytes:           2
+// Cycles:          4
+pub const LDA_SR: Byte = 0xA3;
+pub const LDA_SR_CCOST: u64 = 4;
+
+// Addressing Mode: Direct Page Indirect Long
+// Opcode:          $A7
+// Bytes:           2
+// Cycles:          6
+pub const LDA_DP_IND_LONG: Byte = 0xA7;
+pub const LDA_DP_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute Long
+// Opcode:          $AF
+// Bytes:           4
+// Cycles:          5
+pub const LDA_LONG: Byte = 0xAF;
+pub const LDA_LONG_CCOST: u64 = 5;
+
+// Addressing Mode: Stack Relative Indirect Y-Indexed
+// Opcode:          $B3
+// Bytes:           2
+// Cycles:          7
+pub const LDA_SRY_IND: Byte = 0xB3;
+pub const LDA_SRY_IND_CCOST: u64 = 7;
+
+// Addressing Mode: Direct Page Indirect Long Y-Indexed
+// Opcode:          $B7
+// Bytes:           2
+// Cycles:          6
+pub const LDA_DPY_IND_LONG: Byte = 0xB7;
+pub const LDA_DPY_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute Long
+// Opcode:          $BF
+// Bytes:           4
+// Cycles:          5
+pub const LDA_LONGX: Byte = 0xBF;
+pub const LDA_LONGX_CCOST: u64 = 5;
+
+////////// Compare Memory with Accumulator (CMP) //////////
+//
+// Status Flags Affected: N Z C
+
+// Addressing Mode: Stack Relative
+// Opcode:          $C3
+// Bytes:           2
+// Cycles:          4
+pub const CMP_SR: Byte = 0xC3;
+pub const CMP_SR_CCOST: u64 = 4;
+
+// Addressing Mode: Direct Page Indirect Long
+// Opcode:          $C7
+// Bytes:           2
+// Cycles:          6
+pub const CMP_DP_IND_LONG: Byte = 0xC7;
+pub const CMP_DP_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute Long
+// Opcode:          $CF
+// Bytes:           4
+// Cycles:          5
+pub const CMP_LONG: Byte = 0xCF;
+pub const CMP_LONG_CCOST: u64 = 5;
+
+// Addressing Mode: Stack Relative Indirect Y-Indexed
+// Opcode:          $D3
+// Bytes:           2
+// Cycles:          7
+pub const CMP_SRY_IND: Byte = 0xD3;
+pub const CMP_SRY_IND_CCOST: u64 = 7;
+
+// Addressing Mode: Direct Page Indirect Long Y-Indexed
+// Opcode:          $D7
+// Bytes:           2
+// Cycles:          6
+pub const CMP_DPY_IND_LONG: Byte = 0xD7;
+pub const CMP_DPY_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute Long
+// Opcode:          $DF
+// Bytes:           4
+// Cycles:          5
+pub const CMP_LONGX: Byte = 0xDF;
+pub const CMP_LONGX_CCOST: u64 = 5;
+
+////////// Subtract with Borrow (SBC) //////////
+//
+// Status Flags Affected: N V Z C
+
+// Addressing Mode: Stack Relative
+// Opcode:          $E3
+// Bytes:           2
+// Cycles:          4
+pub const SBC_SR: Byte = 0xE3;
+pub const SBC_SR_CCOST: u64 = 4;
+
+// Addressing Mode: Direct Page Indirect Long
+// Opcode:          $E7
+// Bytes:           2
+// Cycles:          6
+pub const SBC_DP_IND_LONG: Byte = 0xE7;
+pub const SBC_DP_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: Absolute Long
+// Opcode:          $EF
+// Bytes:           4
+// Cycles:          5
+pub const SBC_LONG: Byte = 0xEF;
+pub const SBC_LONG_CCOST: u64 = 5;
+
+// Addressing Mode: Stack Relative Indirect Y-Indexed
+// Opcode:          $F3
+// Bytes:           2
+// Cycles:          7
+pub const SBC_SRY_IND: Byte = 0xF3;
+pub const SBC_SRY_IND_CCOST: u64 = 7;
+
+// Addressing Mode: Direct Page Indirect Long Y-Indexed
+// Opcode:          $F7
+// Bytes:           2
+// Cycles:          6
+pub const SBC_DPY_IND_LONG: Byte = 0xF7;
+pub const SBC_DPY_IND_LONG_CCOST: u64 = 6;
+
+// Addressing Mode: X-Indexed Absolute Long
+// Opcode:          $FF
+// Bytes:           4
+// Cycles:          5
+pub const SBC_LONGX: Byte = 0xFF;
+pub const SBC_LONGX_CCOST: u64 = 5;
+
+////////// Co-Processor Enable (COP) //////////
+//
+// Status Flags Affected: I D
+
+// Addressing Mode: Stack/Interrupt
+// Opcode:          $02
+// Bytes:           2
+// Cycles:          7
+pub const COP_IMM: Byte = 0x02;
+pub const COP_IMM_CCOST: u64 = 7;
+
+////////// Push Direct Page Register on Stack (PHD) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Implied
+// Opcode:          $0B
+// Bytes:           1
+// Cycles:          4
+pub const PHD_IMP: Byte = 0x0B;
+pub const PHD_IMP_CCOST: u64 = 4;
+
+////////// Transfer Accumulator to Stack Pointer (TCS) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Implied
+// Opcode:          $1B
+// Bytes:           1
+// Cycles:          2
+pub const TCS_IMP: Byte = 0x1B;
+pub const TCS_IMP_CCOST: u64 = 2;
+
+////////// Jump to Subroutine Long (JSL) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Absolute Long
+// Opcode:          $22
+// Bytes:           4
+// Cycles:          8
+pub const JSL_LONG: Byte = 0x22;
+pub const JSL_LONG_CCOST: u64 = 8;
+
+////////// Pull Direct Page Register from Stack (PLD) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Implied
+// Opcode:          $2B
+// Bytes:           1
+// Cycles:          5
+pub const PLD_IMP: Byte = 0x2B;
+pub const PLD_IMP_CCOST: u64 = 5;
+
+////////// Transfer Stack Pointer to Accumulator (TSC) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Implied
+// Opcode:          $3B
+// Bytes:           1
+// Cycles:          2
+pub const TSC_IMP: Byte = 0x3B;
+pub const TSC_IMP_CCOST: u64 = 2;
+
+////////// Reserved for Future Expansion (WDM) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Immediate
+// Opcode:          $42
+// Bytes:           2
+// Cycles:          2
+pub const WDM_IMM: Byte = 0x42;
+pub const WDM_IMM_CCOST: u64 = 2;
+
+////////// Block Move Previous (MVP) //////////
+//
+// Status Flags Affected: ∅
+//
+// Moves one byte per 7 cycles until C wraps to $FFFF.
+
+// Addressing Mode: Block Move
+// Opcode:          $44
+// Bytes:           3
+// Cycles:          7
+pub const MVP_BLK: Byte = 0x44;
+pub const MVP_BLK_CCOST: u64 = 7;
+
+////////// Push Program Bank Register on Stack (PHK) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Implied
+// Opcode:          $4B
+// Bytes:           1
+// Cycles:          3
+pub const PHK_IMP: Byte = 0x4B;
+pub const PHK_IMP_CCOST: u64 = 3;
+
+////////// Block Move Next (MVN) //////////
+//
+// Status Flags Affected: ∅
+//
+// Moves one byte per 7 cycles until C wraps to $FFFF.
+
+// Addressing Mode: Block Move
+// Opcode:          $54
+// Bytes:           3
+// Cycles:          7
+pub const MVN_BLK: Byte = 0x54;
+pub const MVN_BLK_CCOST: u64 = 7;
+
+////////// Transfer Accumulator to Direct Page Register (TCD) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Implied
+// Opcode:          $5B
+// Bytes:           1
+// Cycles:          2
+pub const TCD_IMP: Byte = 0x5B;
+pub const TCD_IMP_CCOST: u64 = 2;
+
+////////// Jump Long (JML) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Absolute Long
+// Opcode:          $5C
+// Bytes:           4
+// Cycles:          4
+pub const JML_LONG: Byte = 0x5C;
+pub const JML_LONG_CCOST: u64 = 4;
+
+// Addressing Mode: Absolute Indirect Long
+// Opcode:          $DC
+// Bytes:           3
+// Cycles:          6
+pub const JML_ABS_IND_LONG: Byte = 0xDC;
+pub const JML_ABS_IND_LONG_CCOST: u64 = 6;
+
+////////// Push Effective Relative Address (PER) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Relative Long
+// Opcode:          $62
+// Bytes:           3
+// Cycles:          6
+pub const PER_REL_LONG: Byte = 0x62;
+pub const PER_REL_LONG_CCOST: u64 = 6;
+
+////////// Return from Subroutine Long (RTL) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Implied
+// Opcode:          $6B
+// Bytes:           1
+// Cycles:          6
+pub const RTL_IMP: Byte = 0x6B;
+pub const RTL_IMP_CCOST: u64 = 6;
+
+////////// Transfer Direct Page Register to Accumulator (TDC) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Implied
+// Opcode:          $7B
+// Bytes:           1
+// Cycles:          2
+pub const TDC_IMP: Byte = 0x7B;
+pub const TDC_IMP_CCOST: u64 = 2;
+
+////////// Branch Always Long (BRL) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Relative Long
+// Opcode:          $82
+// Bytes:           3
+// Cycles:          4
+pub const BRL_REL_LONG: Byte = 0x82;
+pub const BRL_REL_LONG_CCOST: u64 = 4;
+
+////////// Push Data Bank Register on Stack (PHB) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Implied
+// Opcode:          $8B
+// Bytes:           1
+// Cycles:          3
+pub const PHB_IMP: Byte = 0x8B;
+pub const PHB_IMP_CCOST: u64 = 3;
+
+////////// Transfer X to Y (TXY) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Implied
+// Opcode:          $9B
+// Bytes:           1
+// Cycles:          2
+pub const TXY_IMP: Byte = 0x9B;
+pub const TXY_IMP_CCOST: u64 = 2;
+
+////////// Pull Data Bank Register from Stack (PLB) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Implied
+// Opcode:          $AB
+// Bytes:           1
+// Cycles:          4
+pub const PLB_IMP: Byte = 0xAB;
+pub const PLB_IMP_CCOST: u64 = 4;
+
+////////// Transfer Y to X (TYX) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Implied
+// Opcode:          $BB
+// Bytes:           1
+// Cycles:          2
+pub const TYX_IMP: Byte = 0xBB;
+pub const TYX_IMP_CCOST: u64 = 2;
+
+////////// Reset Status Bits (REP) //////////
+//
+// Status Flags Affected: N V M X D I Z C
+//
+// M and X cannot be cleared in emulation mode.
+
+// Addressing Mode: Immediate
+// Opcode:          $C2
+// Bytes:           2
+// Cycles:          3
+pub const REP_IMM: Byte = 0xC2;
+pub const REP_IMM_CCOST: u64 = 3;
+
+////////// Push Effective Indirect Address (PEI) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Direct Page Indirect
+// Opcode:          $D4
+// Bytes:           2
+// Cycles:          6
+pub const PEI_DP_IND: Byte = 0xD4;
+pub const PEI_DP_IND_CCOST: u64 = 6;
+
+////////// Set Status Bits (SEP) //////////
+//
+// Status Flags Affected: N V M X D I Z C
+
+// Addressing Mode: Immediate
+// Opcode:          $E2
+// Bytes:           2
+// Cycles:          3
+pub const SEP_IMM: Byte = 0xE2;
+pub const SEP_IMM_CCOST: u64 = 3;
+
+////////// Exchange B and A Accumulators (XBA) //////////
+//
+// Status Flags Affected: N Z
+
+// Addressing Mode: Implied
+// Opcode:          $EB
+// Bytes:           1
+// Cycles:          3
+pub const XBA_IMP: Byte = 0xEB;
+pub const XBA_IMP_CCOST: u64 = 3;
+
+////////// Push Effective Absolute Address (PEA) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: Absolute
+// Opcode:          $F4
+// Bytes:           3
+// Cycles:          5
+pub const PEA_ABS: Byte = 0xF4;
+pub const PEA_ABS_CCOST: u64 = 5;
+
+////////// Exchange Carry and Emulation Flags (XCE) //////////
+//
+// Status Flags Affected: C E
+
+// Addressing Mode: Implied
+// Opcode:          $FB
+// Bytes:           1
+// Cycles:          2
+pub const XCE_IMP: Byte = 0xFB;
+pub const XCE_IMP_CCOST: u64 = 2;
+
+////////// Jump to Subroutine (JSR) //////////
+//
+// Status Flags Affected: ∅
+
+// Addressing Mode: X-Indexed Absolute Indirect
+// Opcode:          $FC
+// Bytes:           3
+// Cycles:          8
+pub const JSR_ABSX_IND: Byte = 0xFC;
+pub const JSR_ABSX_IND_CCOST: u64 = 8;
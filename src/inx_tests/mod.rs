@@ -0,0 +1,45 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn inx_imp() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::INX_IMP),
+            ],
+            0x80,
+            instructions::INX_IMP_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0x7F); })
+        );
+    }
+
+    #[test]
+    fn inx_imp_wwrap() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::INX_IMP),
+            ],
+            0x00,
+            instructions::INX_IMP_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::Z],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0xFF); })
+        );
+    }
+}
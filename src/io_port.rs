@@ -0,0 +1,82 @@
+use crate::memory::{
+    Byte,
+    Word,
+};
+
+pub const DDR_ADDR: Word = 0x0000;
+pub const PORT_ADDR: Word = 0x0001;
+
+// NOTE: The 6510's on-chip I/O port. `$00` is the data direction
+// register (a set bit makes the pin an output) and `$01` is the
+// port itself. A pin set as input reads whatever drives it: the
+// host, a pull-up resistor or, failing both, the level last driven
+// on it, which the pin's capacitance holds on to.
+pub struct IoPort {
+    ddr: Byte,
+    data: Byte,
+    pullups: Byte,
+    external_mask: Byte,
+    external: Byte,
+    floating: Byte,
+}
+
+#[allow(dead_code)]
+impl IoPort {
+    pub fn new(pullups: Byte) -> Self {
+        Self {
+            ddr: 0x00,
+            data: 0x00,
+            pullups,
+            external_mask: 0x00,
+            external: 0x00,
+            floating: 0x00,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.ddr = 0x00;
+        self.data = 0x00;
+    }
+
+    pub fn read(&self, addr: Word) -> Byte {
+        if addr == DDR_ADDR {
+            self.ddr
+        } else {
+            self.pins()
+        }
+    }
+
+    pub fn write(&mut self, addr: Word, data: Byte) {
+        if addr == DDR_ADDR {
+            self.ddr = data;
+        } else {
+            self.data = data;
+        }
+        self.floating = (self.floating & !self.ddr) | (self.data & self.ddr);
+    }
+
+    // The level on each of the port's pins. This is what the rest of
+    // the machine sees, e.g. the C64 banking lines.
+    pub fn pins(&self) -> Byte {
+        let undriven: Byte = !self.external_mask;
+        let inputs: Byte = (self.external & self.external_mask)
+            | (self.pullups & undriven)
+            | (self.floating & !self.pullups & undriven);
+        (self.data & self.ddr) | (inputs & !self.ddr)
+    }
+
+    // Drives the pins in `mask` to `level` from outside the CPU.
+    // Pins set as outputs ignore it.
+    pub fn drive(&mut self, mask: Byte, level: Byte) {
+        self.external_mask = mask;
+        self.external = level;
+    }
+
+    pub fn ddr(&self) -> Byte {
+        self.ddr
+    }
+
+    pub fn data(&self) -> Byte {
+        self.data
+    }
+}
@@ -0,0 +1,115 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+
+    fn c64_cpu(program: &[Byte]) -> Mos6502 {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![], Mos6502Variant::Mos6510);
+        for (i, b) in program.iter().enumerate() {
+            *cpu.mem.at(0x0200 + i) = *b;
+        }
+        cpu.set_pc(0x0200);
+        cpu
+    }
+
+    #[test]
+    fn io_port_wreset() {
+        let mut cpu = c64_cpu(&[
+            instructions::LDA_ZP, 0x01,
+            instructions::LDX_ZP, 0x00,
+        ]);
+
+        cpu.exe(Some(instructions::LDA_ZP_CCOST + instructions::LDX_ZP_CCOST)).unwrap();
+
+        // Every pin is an input, only the pulled up ones read high.
+        assert_eq!(cpu.get_accumulator(), 0x17);
+        assert_eq!(cpu.get_xreg(), 0x00);
+        assert_eq!(cpu.io_port().pins(), 0x17);
+    }
+
+    #[test]
+    fn io_port_woutputs() {
+        // The C64 KERNAL's banking setup, LORAM/HIRAM/CHAREN high.
+        let mut cpu = c64_cpu(&[
+            instructions::LDA_IMM, 0x2F,
+            instructions::STA_ZP, 0x00,
+            instructions::LDA_IMM, 0x35,
+            instructions::STA_ZP, 0x01,
+            instructions::LDA_ZP, 0x01,
+        ]);
+
+        cpu.exe(Some(
+            2 * instructions::LDA_IMM_CCOST
+            + 2 * instructions::STA_ZP_CCOST
+            + instructions::LDA_ZP_CCOST
+        )).unwrap();
+
+        assert_eq!(cpu.io_port().ddr(), 0x2F);
+        assert_eq!(cpu.io_port().data(), 0x35);
+        assert_eq!(cpu.io_port().pins() & 0x07, 0x05);
+        // P4 is an input and pulled up.
+        assert_eq!(cpu.get_accumulator(), 0x35 | 0x10);
+        // The writes still land in RAM.
+        assert_eq!(cpu.mem.get_byte(0x0000), 0x2F);
+        assert_eq!(cpu.mem.get_byte(0x0001), 0x35);
+    }
+
+    #[test]
+    fn io_port_wexternal_input() {
+        let mut cpu = c64_cpu(&[
+            instructions::LDA_ZP, 0x01,
+        ]);
+        // A pressed cassette button pulls P4 low.
+        cpu.io_port_mut().drive(0x10, 0x00);
+
+        cpu.exe(Some(instructions::LDA_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x07);
+    }
+
+    #[test]
+    fn io_port_wfloating_input() {
+        let mut cpu = c64_cpu(&[
+            instructions::LDA_IMM, 0xC0,
+            instructions::STA_ZP, 0x00,
+            instructions::STA_ZP, 0x01,
+            instructions::LDA_IMM, 0x00,
+            instructions::STA_ZP, 0x00,
+            instructions::LDA_ZP, 0x01,
+        ]);
+
+        cpu.exe(Some(
+            2 * instructions::LDA_IMM_CCOST
+            + 3 * instructions::STA_ZP_CCOST
+            + instructions::LDA_ZP_CCOST
+        )).unwrap();
+
+        // P6/P7 are inputs again but hold the level last driven.
+        assert_eq!(cpu.get_accumulator(), 0xC0 | 0x17);
+    }
+
+    #[test]
+    fn io_port_wnmos() {
+        let mut cpu = c64_cpu(&[
+            instructions::LDA_ZP, 0x01,
+        ]);
+        cpu.config_mut().variant = Mos6502Variant::Nmos6502;
+        *cpu.mem.at(0x0001) = 0x42;
+
+        cpu.exe(Some(instructions::LDA_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x42);
+    }
+}
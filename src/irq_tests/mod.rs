@@ -0,0 +1,103 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+
+    const IRQ_CCOST: u64 = 7;
+
+    fn irq_cpu() -> Mos6502 {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::NOP_IMP),
+            (0x0201, instructions::NOP_IMP),
+            (0x4000, instructions::RTI_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+        cpu.set_pc(0x0200);
+        cpu
+    }
+
+    #[test]
+    fn irq() {
+        let mut cpu = irq_cpu();
+
+        cpu.set_status_flag(Mos6502Flags::N);
+        cpu.set_irq_line(true);
+        cpu.exe(Some(IRQ_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4000);
+        assert_eq!(cpu.get_sp(), 0xFA);
+        assert_eq!(cpu.mem.get_byte(0x01FD), 0x02);
+        assert_eq!(cpu.mem.get_byte(0x01FC), 0x00);
+        // B is pushed clear for hardware interrupts.
+        assert_eq!(cpu.mem.get_byte(0x01FB), 0xA0);
+        assert!(cpu.interrupts_disable_flag());
+        assert_eq!(cpu.get_cycles(), IRQ_CCOST);
+    }
+
+    #[test]
+    fn irq_wmasked() {
+        let mut cpu = irq_cpu();
+
+        cpu.set_status_flag(Mos6502Flags::I);
+        cpu.set_irq_line(true);
+        cpu.exe(Some(instructions::NOP_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x0201);
+        assert_eq!(cpu.get_sp(), 0xFD);
+    }
+
+    #[test]
+    fn irq_wlevel() {
+        let mut cpu = irq_cpu();
+
+        cpu.set_irq_line(true);
+        cpu.exe(Some(IRQ_CCOST + instructions::RTI_IMP_CCOST + IRQ_CCOST)).unwrap();
+
+        // RTI clears I again, so a held line is taken a second time.
+        assert_eq!(cpu.get_pc(), 0x4000);
+        assert_eq!(cpu.get_sp(), 0xFA);
+
+        cpu.set_irq_line(false);
+        cpu.exe(Some(cpu.get_cycles() + instructions::RTI_IMP_CCOST + instructions::NOP_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x0201);
+        assert_eq!(cpu.get_sp(), 0xFD);
+    }
+
+    #[test]
+    fn irq_wcmos_clears_decimal() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x0200, instructions::NOP_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_pc(0x0200);
+
+        cpu.set_status_flag(Mos6502Flags::D);
+        cpu.set_irq_line(true);
+        cpu.exe(Some(IRQ_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4000);
+        assert!(!cpu.decimal_mode_flag());
+    }
+
+    #[test]
+    fn irq_wnmos_keeps_decimal() {
+        let mut cpu = irq_cpu();
+
+        cpu.set_status_flag(Mos6502Flags::D);
+        cpu.set_irq_line(true);
+        cpu.exe(Some(IRQ_CCOST)).unwrap();
+
+        assert!(cpu.decimal_mode_flag());
+    }
+}
@@ -0,0 +1,75 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn isc_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ISC_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0x0F),
+        ]);
+        cpu.set_accumulator(0x30);
+        cpu.set_status_flag(Mos6502Flags::C);
+
+        cpu.exe(Some(instructions::ISC_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x10);
+        assert_eq!(cpu.get_accumulator(), 0x20);
+        assert_eq!(cpu.get_cycles(), instructions::ISC_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C]).is_ok());
+    }
+
+    #[test]
+    fn isc_abs_wzero() {
+        let (lo, hi) = (0x00, 0x44);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ISC_ABS),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4400, 0xFF),
+        ]);
+        cpu.set_status_flag(Mos6502Flags::C);
+
+        cpu.exe(Some(instructions::ISC_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0x00);
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::ISC_ABS_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::Z]).is_ok());
+    }
+
+    #[test]
+    fn isc_zp_wdecimal() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::ISC_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0x08),
+        ]);
+        cpu.set_accumulator(0x20);
+        cpu.set_status_flag(Mos6502Flags::C);
+        cpu.set_status_flag(Mos6502Flags::D);
+
+        cpu.exe(Some(instructions::ISC_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x09);
+        assert_eq!(cpu.get_accumulator(), 0x11);
+        assert_eq!(cpu.get_cycles(), instructions::ISC_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::D]).is_ok());
+    }
+}
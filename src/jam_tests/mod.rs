@@ -0,0 +1,83 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        CpuState,
+        StopReason,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    const JAM_OPCODES: [Byte; 12] = [
+        instructions::JAM_IMP_02, instructions::JAM_IMP_12, instructions::JAM_IMP_22,
+        instructions::JAM_IMP_32, instructions::JAM_IMP_42, instructions::JAM_IMP_52,
+        instructions::JAM_IMP_62, instructions::JAM_IMP_72, instructions::JAM_IMP_92,
+        instructions::JAM_IMP_B2, instructions::JAM_IMP_D2, instructions::JAM_IMP_F2,
+    ];
+
+    #[test]
+    fn jam_imp() {
+        for opcode in JAM_OPCODES {
+            let mut cpu = tests_utils::cpu_mem_set(vec![
+                (PC_START, opcode),
+            ]);
+
+            assert_eq!(cpu.exe(None).unwrap(), StopReason::Jammed, "opcode: {opcode:02x}");
+            assert_eq!(cpu.state(), CpuState::Jammed);
+            assert_eq!(cpu.get_pc(), PC_START + 1);
+        }
+    }
+
+    #[test]
+    fn jam_imp_wstays_jammed() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JAM_IMP_02),
+            (PC_START + 1, instructions::NOP_IMP),
+        ]);
+        cpu.exe(None).unwrap();
+        let cycles = cpu.get_cycles();
+
+        // The clock keeps running, nothing executes.
+        assert_eq!(cpu.exe(Some(cycles + 100)).unwrap(), StopReason::Jammed);
+        assert_eq!(cpu.get_pc(), PC_START + 1);
+        assert_eq!(cpu.get_cycles(), cycles + 100);
+    }
+
+    #[test]
+    fn jam_imp_wignores_interrupts() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JAM_IMP_02),
+        ]);
+        cpu.exe(None).unwrap();
+        cpu.trigger_nmi();
+        cpu.set_irq_line(true);
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Jammed);
+        assert_eq!(cpu.get_pc(), PC_START + 1);
+        assert_eq!(cpu.get_sp(), 0xFD);
+    }
+
+    #[test]
+    fn jam_imp_wreset() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JAM_IMP_02),
+        ]);
+        cpu.exe(None).unwrap();
+        cpu.reset();
+        cpu.set_pc(PC_START);
+        *cpu.mem.at(usize::from(PC_START)) = instructions::NOP_IMP;
+
+        assert_eq!(cpu.state(), CpuState::Running);
+        assert_eq!(cpu.exe(Some(cpu.get_cycles() + instructions::NOP_IMP_CCOST)).unwrap(), StopReason::CycleLimit);
+        assert_eq!(cpu.get_pc(), PC_START + 1);
+    }
+}
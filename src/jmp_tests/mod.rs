@@ -0,0 +1,122 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Config,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn jmp_abs() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JMP_ABS),
+            (PC_START + 1, 0x37),
+            (PC_START + 2, 0x42),
+        ]);
+
+        cpu.exe(Some(instructions::JMP_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4237);
+        assert_eq!(cpu.get_cycles(), instructions::JMP_ABS_CCOST);
+    }
+
+    #[test]
+    fn jmp_abs_ind() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JMP_ABS_IND),
+            (PC_START + 1, 0x80),
+            (PC_START + 2, 0x44),
+            (0x4480, 0x37),
+            (0x4481, 0x42),
+        ]);
+
+        cpu.exe(Some(instructions::JMP_ABS_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4237);
+        assert_eq!(cpu.get_cycles(), instructions::JMP_ABS_IND_CCOST);
+    }
+
+    #[test]
+    fn jmp_abs_ind_wpage_wrap() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JMP_ABS_IND),
+            (PC_START + 1, 0xFF),
+            (PC_START + 2, 0x44),
+            (0x44FF, 0x37),
+            (0x4400, 0x42),
+            (0x4500, 0x24),
+        ]);
+
+        cpu.exe(Some(instructions::JMP_ABS_IND_CCOST)).unwrap();
+
+        // NMOS parts fetch the high byte from the start of the same page.
+        assert_eq!(cpu.get_pc(), 0x4237);
+        assert_eq!(cpu.get_cycles(), instructions::JMP_ABS_IND_CCOST);
+    }
+
+    #[test]
+    fn jmp_abs_ind_wopage_wrap_bug() {
+        let mut cpu = tests_utils::cpu_mem_set_wconfig(
+            vec![
+                (PC_START, instructions::JMP_ABS_IND),
+                (PC_START + 1, 0xFF),
+                (PC_START + 2, 0x44),
+                (0x44FF, 0x37),
+                (0x4400, 0x42),
+                (0x4500, 0x24),
+            ],
+            Mos6502Config { jmp_indirect_bug: false, ..Mos6502Config::default() },
+        );
+
+        cpu.exe(Some(instructions::JMP_ABS_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x2437);
+    }
+
+    #[test]
+    fn jmp_abs_ind_cmos() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(
+            vec![
+                (PC_START, instructions::JMP_ABS_IND),
+                (PC_START + 1, 0xFF),
+                (PC_START + 2, 0x44),
+                (0x44FF, 0x37),
+                (0x4400, 0x42),
+                (0x4500, 0x24),
+            ],
+            Mos6502Variant::Cmos65C02,
+        );
+
+        cpu.exe(Some(instructions::JMP_ABS_IND_CMOS_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x2437);
+        assert_eq!(cpu.get_cycles(), instructions::JMP_ABS_IND_CMOS_CCOST);
+    }
+
+    #[test]
+    fn jmp_absx_ind() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(
+            vec![
+                (PC_START, instructions::JMP_ABSX_IND),
+                (PC_START + 1, 0xFF),
+                (PC_START + 2, 0x44),
+                (0x4501, 0x37),
+                (0x4502, 0x24),
+            ],
+            Mos6502Variant::Cmos65C02,
+        );
+        cpu.set_xreg(0x02);
+
+        cpu.exe(Some(instructions::JMP_ABSX_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x2437);
+        assert_eq!(cpu.get_cycles(), instructions::JMP_ABSX_IND_CCOST);
+    }
+}
@@ -20,7 +20,8 @@ mod tests {
             (0x4243, 0x84),
         ]);
 
-        cpu.exe(Some(instructions::JSR_ABS_CCOST + instructions::LDA_IMM_CCOST));
+        assert_eq!(cpu.step().unwrap().unwrap().cycles, instructions::JSR_ABS_CCOST);
+        assert_eq!(cpu.step().unwrap().unwrap().cycles, instructions::LDA_IMM_CCOST);
 
         assert_eq!(cpu.get_accumulator(), 0x84);
         assert_eq!(cpu.get_cycles(), instructions::JSR_ABS_CCOST + instructions::LDA_IMM_CCOST);
@@ -28,4 +29,119 @@ mod tests {
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![Mos6502Flags::N]);
         assert!(cpu.negative_flag());
     }
+
+    #[test]
+    fn jsr_abs_stack() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0xFFFC, instructions::JSR_ABS),
+            (0xFFFD, 0x42),
+            (0xFFFE, 0x44),
+        ]);
+
+        cpu.exe(Some(instructions::JSR_ABS_CCOST)).unwrap();
+
+        // The pushed return address is the last byte of the JSR.
+        assert_eq!(cpu.get_pc(), 0x4442);
+        assert_eq!(cpu.get_sp(), 0xFB);
+        assert_eq!(cpu.mem.get_byte(0x01FD), 0xFF);
+        assert_eq!(cpu.mem.get_byte(0x01FC), 0xFE);
+        assert_eq!(cpu.get_cycles(), instructions::JSR_ABS_CCOST);
+    }
+
+    #[test]
+    fn jsr_abs_wrts_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0xFFFC, instructions::JSR_ABS),
+            (0xFFFD, 0x42),
+            (0xFFFE, 0x42),
+            (0xFFFF, instructions::SEC_IMP),
+            (0x4242, instructions::LDA_IMM),
+            (0x4243, 0x84),
+            (0x4244, instructions::RTS_IMP),
+        ]);
+
+        cpu.exe(Some(
+            instructions::JSR_ABS_CCOST
+            + instructions::LDA_IMM_CCOST
+            + instructions::RTS_IMP_CCOST
+        )).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0xFFFF);
+        assert_eq!(cpu.get_sp(), 0xFD);
+        assert_eq!(cpu.get_accumulator(), 0x84);
+
+        cpu.exe(Some(cpu.get_cycles() + instructions::SEC_IMP_CCOST)).unwrap();
+
+        assert!(cpu.carry_flag());
+        assert_eq!(cpu.get_pc(), 0x0000);
+    }
+
+    #[test]
+    fn rts_imp_ccost() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0xFFFC, instructions::RTS_IMP),
+            (0x01FE, 0x41),
+            (0x01FF, 0x42),
+        ]);
+
+        cpu.exe(Some(instructions::RTS_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4242);
+        assert_eq!(cpu.get_sp(), 0xFF);
+        assert_eq!(cpu.get_cycles(), instructions::RTS_IMP_CCOST);
+    }
+
+    #[test]
+    fn jsr_abs_nested() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0xFFFC, instructions::JSR_ABS),
+            (0xFFFD, 0x42),
+            (0xFFFE, 0x42),
+            (0x4242, instructions::JSR_ABS),
+            (0x4243, 0x00),
+            (0x4244, 0x50),
+            (0x4245, instructions::RTS_IMP),
+            (0x5000, instructions::RTS_IMP),
+        ]);
+
+        cpu.exe(Some(2 * instructions::JSR_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x5000);
+        assert_eq!(cpu.get_sp(), 0xF9);
+        assert_eq!(cpu.mem.get_byte(0x01FB), 0x42);
+        assert_eq!(cpu.mem.get_byte(0x01FA), 0x44);
+
+        cpu.exe(Some(cpu.get_cycles() + instructions::RTS_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4245);
+        assert_eq!(cpu.get_sp(), 0xFB);
+
+        cpu.exe(Some(cpu.get_cycles() + instructions::RTS_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0xFFFF);
+        assert_eq!(cpu.get_sp(), 0xFD);
+        assert_eq!(cpu.get_cycles(), 2 * (instructions::JSR_ABS_CCOST + instructions::RTS_IMP_CCOST));
+    }
+
+    #[test]
+    fn jsr_abs_wrts_imp_wwrap() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0xFFFC, instructions::JSR_ABS),
+            (0xFFFD, 0x42),
+            (0xFFFE, 0x42),
+            (0x4242, instructions::RTS_IMP),
+        ]);
+
+        cpu.set_sp(0x00);
+        cpu.exe(Some(instructions::JSR_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_sp(), 0xFE);
+        assert_eq!(cpu.mem.get_byte(0x0100), 0xFF);
+        assert_eq!(cpu.mem.get_byte(0x01FF), 0xFE);
+
+        cpu.exe(Some(cpu.get_cycles() + instructions::RTS_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0xFFFF);
+        assert_eq!(cpu.get_sp(), 0x00);
+    }
 }
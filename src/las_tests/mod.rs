@@ -0,0 +1,58 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn las_absy() {
+        let (lo, hi, yreg) = (0x00, 0x44, 0x10);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LAS_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4410, 0xF0),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_sp(0x9F);
+
+        cpu.exe(Some(instructions::LAS_ABSY_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x90);
+        assert_eq!(cpu.get_xreg(), 0x90);
+        assert_eq!(cpu.get_sp(), 0x90);
+        assert_eq!(cpu.get_cycles(), instructions::LAS_ABSY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn las_absy_wpage_boundary() {
+        let (lo, hi, yreg) = (0xFF, 0x44, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LAS_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4500, 0x0F),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_sp(0xF0);
+
+        cpu.exe(Some(instructions::LAS_ABSY_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_sp(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::LAS_ABSY_CCOST + 1);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z]).is_ok());
+    }
+}
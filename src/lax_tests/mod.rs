@@ -0,0 +1,130 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn lax_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LAX_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0x80),
+        ]);
+
+        cpu.exe(Some(instructions::LAX_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_xreg(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::LAX_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn lax_zpy() {
+        let (addr, yreg) = (0x40, 0x02);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LAX_ZPY),
+            (PC_START + 1, addr),
+            (0x0042, 0x00),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_accumulator(0x12);
+        cpu.set_xreg(0x34);
+
+        cpu.exe(Some(instructions::LAX_ZPY_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_xreg(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::LAX_ZPY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z]).is_ok());
+    }
+
+    #[test]
+    fn lax_absy() {
+        let (lo, hi, yreg) = (0x00, 0x44, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LAX_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4401, 0x37),
+        ]);
+        cpu.set_yreg(yreg);
+
+        cpu.exe(Some(instructions::LAX_ABSY_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_xreg(), 0x37);
+        assert_eq!(cpu.get_cycles(), instructions::LAX_ABSY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn lax_absy_wpage_boundary() {
+        let (lo, hi, yreg) = (0xFF, 0x44, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LAX_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4500, 0x37),
+        ]);
+        cpu.set_yreg(yreg);
+
+        cpu.exe(Some(instructions::LAX_ABSY_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_xreg(), 0x37);
+        assert_eq!(cpu.get_cycles(), instructions::LAX_ABSY_CCOST + 1);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn lax_zpy_ind_wpage_boundary() {
+        let (addr, yreg) = (0x20, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LAX_ZPY_IND),
+            (PC_START + 1, addr),
+            (0x0020, 0xFF),
+            (0x0021, 0x44),
+            (0x4500, 0xAA),
+        ]);
+        cpu.set_yreg(yreg);
+
+        cpu.exe(Some(instructions::LAX_ZPY_IND_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0xAA);
+        assert_eq!(cpu.get_xreg(), 0xAA);
+        assert_eq!(cpu.get_cycles(), instructions::LAX_ZPY_IND_CCOST + 1);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn lax_zp_wricoh() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::LAX_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0x80),
+        ], Mos6502Variant::Ricoh2A03);
+
+        cpu.exe(Some(instructions::LAX_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_xreg(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::LAX_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+}
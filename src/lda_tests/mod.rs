@@ -5,6 +5,7 @@ mod tests {
     use crate::mos6502::{
         Mos6502,
         Mos6502Flags,
+        Mos6502Variant,
     };
     use crate::memory::{
         Memory,
@@ -71,7 +72,7 @@ mod tests {
             vec![
                 (PC_START, instructions::LDA_ZPX),
                 (PC_START + 1, addr),
-                ((addr + xreg) as u16, val),
+                (u16::from(addr + xreg), val),
             ],
             val,
             instructions::LDA_ZPX_CCOST,
@@ -222,7 +223,7 @@ mod tests {
     fn lda_zpy_ind_wopage_boundary() {
         let (hi, lo, yreg, val) = (0x80, 0x00, 0x04, 0x37);
         let arb_addr = 0x02;
-        let dest_addr = tests_utils::word_from_bytes(hi, lo) + yreg as Word;
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(yreg);
         tests_utils::ld_into_reg(
             vec![
                 (PC_START, instructions::LDA_ZPY_IND),
@@ -243,7 +244,7 @@ mod tests {
     fn lda_zpy_ind_wpage_boundary() {
         let (hi, lo, yreg, val) = (0x80, 0x02, 0xFF, 0x37);
         let arb_addr = 0x02;
-        let dest_addr = tests_utils::word_from_bytes(hi, lo) + yreg as Word;
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(yreg);
         tests_utils::ld_into_reg(
             vec![
                 (PC_START, instructions::LDA_ZPY_IND),
@@ -259,4 +260,58 @@ mod tests {
             Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg) })
         );
     }
+
+    #[test]
+    fn lda_zpx_ind_wwrap() {
+        let (hi, lo, xreg, val) = (0x80, 0x00, 0x01u8, 0x37);
+        let arb_addr = 0xFEu8;
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::LDA_ZPX_IND),
+                (PC_START + 1, arb_addr),
+                (0x00FF, lo),
+                (0x0000, hi), // the pointer wraps within the zero page
+                (tests_utils::word_from_bytes(hi, lo), val),
+            ],
+            val,
+            instructions::LDA_ZPX_IND_CCOST,
+            tests_utils::Registers::A,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg) })
+        );
+    }
+
+    #[test]
+    fn lda_zp_ind() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::LDA_ZP_IND),
+            (PC_START + 1, 0x20),
+            (0x0020, 0x00),
+            (0x0021, 0x44),
+            (0x4400, 0x80),
+        ], Mos6502Variant::Cmos65C02);
+
+        cpu.exe(Some(instructions::LDA_ZP_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::LDA_ZP_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn lda_zp_ind_wwrap() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::LDA_ZP_IND),
+            (PC_START + 1, 0xFF),
+            (0x00FF, 0x00),
+            (0x0000, 0x44),
+            (0x4400, 0x37),
+        ], Mos6502Variant::Cmos65C02);
+
+        cpu.exe(Some(instructions::LDA_ZP_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_cycles(), instructions::LDA_ZP_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
 }
@@ -69,7 +69,7 @@ mod tests {
     #[test]
     fn ldx_absy_wopage_boundary() {
         let (hi, lo, yreg, val) = (0x44, 0x80, 1u8, 0x37);
-        let dest_addr = tests_utils::word_from_bytes(hi, lo) + yreg as Word;
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(yreg);
         tests_utils::ld_into_reg(
             vec![
                 (PC_START, instructions::LDX_ABSY),
@@ -88,7 +88,7 @@ mod tests {
     #[test]
     fn ldx_absy_wpage_boundary() {
         let (hi, lo, yreg, val) = (0x44, 0x02, 0xFF, 0x37);
-        let dest_addr = tests_utils::word_from_bytes(hi, lo) + yreg as Word;
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(yreg);
         tests_utils::ld_into_reg(
             vec![
                 (PC_START, instructions::LDX_ABSY),
@@ -112,7 +112,7 @@ mod tests {
             (0x0042, 0x37),
         ]);
 
-        cpu.exe(Some(instructions::LDX_ZP_CCOST));
+        cpu.exe(Some(instructions::LDX_ZP_CCOST)).unwrap();
 
         assert_eq!(cpu.get_xreg(), 0x37);
         assert_eq!(cpu.get_cycles(), instructions::LDX_ZP_CCOST);
@@ -127,10 +127,10 @@ mod tests {
             (0x0047, 0x37),
         ]);
 
-        cpu.set_xreg(5);
-        cpu.exe(Some(instructions::LDX_ZPY_CCOST));
+        cpu.set_yreg(5);
+        cpu.exe(Some(instructions::LDX_ZPY_CCOST)).unwrap();
 
-        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_xreg(), 0x37);
         assert_eq!(cpu.get_cycles(), instructions::LDX_ZPY_CCOST);
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
     }
@@ -143,10 +143,10 @@ mod tests {
             (0x007F, 0x37),
         ]);
 
-        cpu.set_xreg(0xFF);
-        cpu.exe(Some(instructions::LDX_ZPY_CCOST));
+        cpu.set_yreg(0xFF);
+        cpu.exe(Some(instructions::LDX_ZPY_CCOST)).unwrap();
 
-        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_xreg(), 0x37);
         assert_eq!(cpu.get_cycles(), instructions::LDX_ZPY_CCOST);
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
     }
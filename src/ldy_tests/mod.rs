@@ -17,9 +17,9 @@ mod tests {
             (0xFFFD, 0x84),
         ]);
 
-        cpu.exe(Some(instructions::LDY_IMM_CCOST));
+        cpu.exe(Some(instructions::LDY_IMM_CCOST)).unwrap();
 
-        assert_eq!(cpu.get_accumulator(), 0x84);
+        assert_eq!(cpu.get_yreg(), 0x84);
         assert_eq!(cpu.get_cycles(), instructions::LDY_IMM_CCOST);
 
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![Mos6502Flags::N]);
@@ -34,7 +34,7 @@ mod tests {
         ]);
 
         cpu.set_xreg(0x44);
-        cpu.exe(Some(instructions::LDY_IMM_CCOST));
+        cpu.exe(Some(instructions::LDY_IMM_CCOST)).unwrap();
 
         assert_eq!(cpu.get_cycles(), instructions::LDY_IMM_CCOST);
 
@@ -51,9 +51,9 @@ mod tests {
             (0x4480, 0x37),
         ]);
 
-        cpu.exe(Some(instructions::LDY_ABS_CCOST));
+        cpu.exe(Some(instructions::LDY_ABS_CCOST)).unwrap();
 
-        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_yreg(), 0x37);
         assert_eq!(cpu.get_cycles(), instructions::LDY_ABS_CCOST);
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
     }
@@ -67,10 +67,10 @@ mod tests {
             (0x4481, 0x37),
         ]);
 
-        cpu.set_yreg(1);
-        cpu.exe(Some(instructions::LDY_ABSX_CCOST));
+        cpu.set_xreg(1);
+        cpu.exe(Some(instructions::LDY_ABSX_CCOST)).unwrap();
 
-        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_yreg(), 0x37);
         assert_eq!(cpu.get_cycles(), instructions::LDY_ABSX_CCOST);
 
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
@@ -85,10 +85,10 @@ mod tests {
             (0x4501, 0x37), // 0x4402 + 0xFF crosses page boundary
         ]);
 
-        cpu.set_yreg(0xFF);
-        cpu.exe(Some(instructions::LDY_ABSX_CCOST + 1));
+        cpu.set_xreg(0xFF);
+        cpu.exe(Some(instructions::LDY_ABSX_CCOST + 1)).unwrap();
 
-        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_yreg(), 0x37);
         assert_eq!(cpu.get_cycles(), instructions::LDY_ABSX_CCOST + 1);
 
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
@@ -102,9 +102,9 @@ mod tests {
             (0x0042, 0x37),
         ]);
 
-        cpu.exe(Some(instructions::LDY_ZP_CCOST));
+        cpu.exe(Some(instructions::LDY_ZP_CCOST)).unwrap();
 
-        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_yreg(), 0x37);
         assert_eq!(cpu.get_cycles(), instructions::LDY_ZP_CCOST);
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
     }
@@ -118,9 +118,9 @@ mod tests {
         ]);
 
         cpu.set_xreg(5);
-        cpu.exe(Some(instructions::LDY_ZPX_CCOST));
+        cpu.exe(Some(instructions::LDY_ZPX_CCOST)).unwrap();
 
-        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_yreg(), 0x37);
         assert_eq!(cpu.get_cycles(), instructions::LDY_ZPX_CCOST);
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
     }
@@ -134,9 +134,9 @@ mod tests {
         ]);
 
         cpu.set_xreg(0xFF);
-        cpu.exe(Some(instructions::LDY_ZPX_CCOST));
+        cpu.exe(Some(instructions::LDY_ZPX_CCOST)).unwrap();
 
-        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_yreg(), 0x37);
         assert_eq!(cpu.get_cycles(), instructions::LDY_ZPX_CCOST);
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
     }
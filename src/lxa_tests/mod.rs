@@ -0,0 +1,49 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn lxa_imm() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LXA_IMM),
+            (PC_START + 1, 0x3C),
+        ]);
+        cpu.set_accumulator(0x01);
+
+        cpu.exe(Some(instructions::LXA_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x2C);
+        assert_eq!(cpu.get_xreg(), 0x2C);
+        assert_eq!(cpu.get_cycles(), instructions::LXA_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn lxa_imm_wmagic() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LXA_IMM),
+            (PC_START + 1, 0x80),
+        ]);
+        cpu.config_mut().magic = 0xFF;
+
+        cpu.exe(Some(instructions::LXA_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_xreg(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::LXA_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+}
@@ -3,11 +3,59 @@
 mod instructions;
 mod mos6502;
 mod memory;
+mod io_port;
+mod opcodes;
+mod w65c816;
 
+mod adc_tests;
+mod sbc_tests;
+mod asl_tests;
+mod lsr_tests;
+mod rol_tests;
+mod ror_tests;
 mod lda_tests;
+mod and_tests;
+mod ora_tests;
+mod eor_tests;
 mod ldx_tests;
 mod ldy_tests;
+mod sta_tests;
+mod stx_tests;
+mod sty_tests;
 mod jsr_tests;
+mod jmp_tests;
+mod brk_tests;
+mod rti_tests;
+mod irq_tests;
+mod nmi_tests;
+mod tax_tests;
+mod tay_tests;
+mod tsx_tests;
+mod txa_tests;
+mod txs_tests;
+mod tya_tests;
+mod inx_tests;
+mod iny_tests;
+mod dex_tests;
+mod dey_tests;
+mod inc_tests;
+mod dec_tests;
+mod cmp_tests;
+mod cpx_tests;
+mod cpy_tests;
+mod bit_tests;
+mod bpl_tests;
+mod bmi_tests;
+mod bvc_tests;
+mod bvs_tests;
+mod bcc_tests;
+mod bcs_tests;
+mod bne_tests;
+mod beq_tests;
+mod pha_tests;
+mod pla_tests;
+mod php_tests;
+mod plp_tests;
 mod nop_tests;
 mod sec_tests;
 mod sei_tests;
@@ -15,6 +63,48 @@ mod sed_tests;
 mod cli_tests;
 mod clv_tests;
 mod cld_tests;
+mod slo_tests;
+mod rla_tests;
+mod sre_tests;
+mod rra_tests;
+mod dcp_tests;
+mod isc_tests;
+mod sax_tests;
+mod lax_tests;
+mod anc_tests;
+mod alr_tests;
+mod arr_tests;
+mod sbx_tests;
+mod ane_tests;
+mod lxa_tests;
+mod sha_tests;
+mod shx_tests;
+mod shy_tests;
+mod tas_tests;
+mod las_tests;
+mod jam_tests;
+mod exe_tests;
+mod bra_tests;
+mod phx_tests;
+mod phy_tests;
+mod plx_tests;
+mod ply_tests;
+mod stz_tests;
+mod trb_tests;
+mod tsb_tests;
+mod rmb_tests;
+mod smb_tests;
+mod bbr_tests;
+mod bbs_tests;
+mod wai_tests;
+mod stp_tests;
+mod io_port_tests;
+mod w65c816_tests;
+mod step_tests;
+mod tick_tests;
+mod run_for_tests;
+mod counters_tests;
+mod reset_tests;
 mod tests_utils;
 
 fn cpu_mem_set(instrs: Vec<(u16, u8)>) -> mos6502::Mos6502 {
@@ -23,7 +113,7 @@ fn cpu_mem_set(instrs: Vec<(u16, u8)>) -> mos6502::Mos6502 {
         *mem.at(addr as usize) = opcode;
     }
     let mut cpu = mos6502::Mos6502::new(mem);
-    cpu.reset(false);
+    cpu.power_on();
     cpu
 }
 
@@ -32,9 +122,11 @@ fn cpu_mem_set(instrs: Vec<(u16, u8)>) -> mos6502::Mos6502 {
 #[allow(unused_variables)]
 fn main() {
     let mut cpu = cpu_mem_set(vec![
-        (0xFFFC, instructions::LDA_IMM),
-        (0xFFFD, 0x84),
+        (0xFFFC, 0x00),
+        (0xFFFD, 0x02),
+        (0x0200, instructions::LDA_IMM),
+        (0x0201, 0x84),
     ]);
-    cpu.exe(Some(instructions::LDA_IMM_CCOST));
+    cpu.exe(Some(cpu.get_cycles() + instructions::LDA_IMM_CCOST)).unwrap();
     println!("{cpu}");
 }
@@ -1,20 +1,25 @@
 pub const MEM_MAX: usize = 1024 * 64;
+pub const LONG_MEM_MAX: usize = 1024 * 1024 * 16;
 
 pub type Byte = u8;
 pub type Word = u16;
 
 pub struct Memory {
-    data: [u8; MEM_MAX],
+    data: Box<[u8]>,
 }
 
 #[allow(dead_code)]
 impl Memory {
     pub fn new() -> Self {
-        Self { data: [0; MEM_MAX] }
+        Self::with_size(MEM_MAX)
+    }
+
+    pub fn with_size(size: usize) -> Self {
+        Self { data: vec![0; size].into_boxed_slice() }
     }
 
     pub fn clear(&mut self) {
-        (0..MEM_MAX).for_each(|i| self.data[i] = 0);
+        self.data.fill(0);
     }
 
     pub fn get_byte(&self, idx: usize) -> Byte {
@@ -35,7 +40,7 @@ impl Memory {
     }
 
     pub fn print_written_mem(&self) {
-        (0..MEM_MAX).for_each(|i| {
+        (0..self.data.len()).for_each(|i| {
             if self.data[i] != 0x00 {
                 println!("addr: {:x}, {:x}", i, self.data[i]);
             }
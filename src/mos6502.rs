@@ -1,6 +1,14 @@
 #![allow(dead_code)]
 
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
 use crate::memory::Memory;
+use crate::io_port::{IoPort, PORT_ADDR};
+use crate::instructions::BRK_IMP;
+use crate::opcodes::{opcode_info, AddressingMode};
 
 type Byte = u8;
 type Word = u16;
@@ -26,11 +34,337 @@ impl Mos6502Flags {
         *status &= !(self as Byte);
     }
 
+    fn assign(self, status: &mut Byte, state: bool) {
+        if state {
+            self.set(status);
+        } else {
+            self.clear(status);
+        }
+    }
+
     pub fn get(self, status: Byte) -> bool {
         status & self as Byte != 0
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mos6502Variant {
+    // The original NMOS 6502.
+    Nmos6502,
+    // The CMOS 65C02 without the Rockwell/WDC bit instructions.
+    Cmos65C02,
+    // Rockwell R65C02, adds RMB/SMB/BBR/BBS.
+    Rockwell65C02,
+    // WDC W65C02S, the Rockwell set plus WAI and STP.
+    Wdc65C02S,
+    // Ricoh 2A03 (NES). NMOS with the undocumented opcodes always
+    // decoded, D can be set but ADC/SBC ignore it.
+    Ricoh2A03,
+    // MOS 6510 (C64). NMOS with the I/O port at `$00`/`$01`.
+    Mos6510,
+}
+
+impl Mos6502Variant {
+    pub fn is_cmos(self) -> bool {
+        matches!(self, Mos6502Variant::Cmos65C02 | Mos6502Variant::Rockwell65C02 | Mos6502Variant::Wdc65C02S)
+    }
+
+    pub fn has_decimal_mode(self) -> bool {
+        self != Mos6502Variant::Ricoh2A03
+    }
+}
+
+// What `exe` does when it fetches an opcode that has no handler.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UnknownOpcodePolicy {
+    Error,
+    Nop,
+    Jam,
+}
+
+pub struct Mos6502Config {
+    pub variant: Mos6502Variant,
+    // NMOS parts fetch the high byte of a `JMP ($xxFF)` pointer
+    // from `$xx00` instead of the next page.
+    pub jmp_indirect_bug: bool,
+    // Decode the undocumented NMOS opcodes (LAX, SAX, DCP, ...).
+    pub illegal_opcodes: bool,
+    // Chip dependent constant OR'd into the accumulator by the
+    // unstable ANE ($8B) and LXA ($AB) opcodes.
+    pub magic: Byte,
+    pub unknown_opcode: UnknownOpcodePolicy,
+    // 6510 port pins pulled high when nothing drives them. The
+    // default matches the C64, P0-P2 and P4.
+    pub io_port_pullups: Byte,
+    // Only used to turn cycles into emulated time.
+    pub clock_hz: u64,
+}
+
+impl Default for Mos6502Config {
+    fn default() -> Self {
+        Self {
+            variant: Mos6502Variant::Nmos6502,
+            jmp_indirect_bug: true,
+            illegal_opcodes: false,
+            magic: 0xEE,
+            unknown_opcode: UnknownOpcodePolicy::Error,
+            io_port_pullups: 0x17,
+            clock_hz: 1_000_000,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CpuState {
+    Running,
+    // A JAM/KIL opcode locked up the bus, only `reset()` recovers.
+    Jammed,
+    // WAI, resumes once IRQ or NMI is asserted.
+    Waiting,
+    // STP, only `reset()` recovers.
+    Stopped,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StopReason {
+    CycleLimit,
+    // Holds the address of the breakpoint, nothing there has run yet.
+    Breakpoint(Word),
+    Jammed,
+    Waiting,
+    Stopped,
+    HaltRequested,
+}
+
+#[derive(Debug)]
+pub struct ExecError {
+    pub opcode: Byte,
+    pub pc: Word,
+    pub cycles: u64,
+}
+
+impl std::fmt::Display for ExecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Illegal opcode: {:02x} at pc: {:04x} (cycles: {})", self.opcode, self.pc, self.cycles)
+    }
+}
+
+impl std::error::Error for ExecError {}
+
+// The pins of one clock as seen by `tick()`. `read` is the R/W
+// line, `sync` is high on opcode fetches. `bank` is the top byte
+// of a 65C816 address and always zero on the 6502.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BusCycle {
+    pub bank: Byte,
+    pub addr: Word,
+    pub data: Byte,
+    pub read: bool,
+    pub sync: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RegisterState {
+    pub a: Byte,
+    pub x: Byte,
+    pub y: Byte,
+    pub sp: Byte,
+    pub pc: Word,
+    pub status: Byte,
+}
+
+// What `step()` ran. `effective_addr` is the address the operand
+// resolved to, the target for jumps and branches, and `None` for
+// implied, accumulator and immediate operands.
+#[derive(Clone, Debug)]
+pub struct StepRecord {
+    pub pc: Word,
+    pub opcode: Byte,
+    pub operand: Vec<Byte>,
+    pub mnemonic: &'static str,
+    pub mode: AddressingMode,
+    pub effective_addr: Option<Word>,
+    pub cycles: u64,
+    pub before: RegisterState,
+    pub after: RegisterState,
+}
+
+// What an in flight `tick()` is working through.
+#[derive(Clone, Copy)]
+enum TickOp {
+    Idle,
+    // True for NMI, false for IRQ.
+    Interrupt(bool),
+    Instruction,
+}
+
+// NOTE: `tick()` re-runs the instruction from this snapshot on
+// every clock. Cycles already on the bus are replayed out of the
+// log, so only the next one reaches memory.
+#[derive(Clone, Copy)]
+struct InFlight {
+    op: TickOp,
+    regs: RegisterState,
+    state: CpuState,
+    effective_addr: Option<Word>,
+    instructions: u64,
+    nmi_hijack: Option<bool>,
+}
+
+// How a bus cycle was carried out while ticking.
+enum Access {
+    Live,
+    Replayed(Byte),
+    Ahead,
+}
+
+// NOTE: The run loop, shared by every CPU built around a
+// `Mos6502`. The core keeps the clock, the bus and the tick log,
+// the interrupt lines, the run state and the halt flag. `Mos6502`
+// is its own core, the 65C816 wraps one and fills in its own
+// registers, breakpoints and instructions.
+pub(crate) trait Core {
+    fn core(&self) -> &Mos6502;
+
+    fn core_mut(&mut self) -> &mut Mos6502;
+
+    // `Some(StopReason::Breakpoint(..))` when the next instruction
+    // sits on a breakpoint.
+    fn breakpoint(&self) -> Option<StopReason>;
+
+    fn irq_masked(&self) -> bool;
+
+    // One internal cycle while jammed, waiting or stopped.
+    fn idle(&mut self);
+
+    // The 7 cycle IRQ or NMI sequence, the line is already taken.
+    fn service_interrupt(&mut self, nmi: bool);
+
+    fn run_instruction(&mut self) -> Result<(), ExecError>;
+
+    // Registers the core doesn't hold, `tick()` rewinds them along
+    // with its own between clocks.
+    fn save_registers(&mut self) {}
+
+    fn restore_registers(&mut self) {}
+
+    // Body of `exe`.
+    fn run_until(&mut self, cycle_limit: Option<u64>) -> Result<StopReason, ExecError> {
+        self.finish_instruction()?;
+        let mut first = true;
+        loop {
+            let core: &mut Mos6502 = self.core_mut();
+            if cycle_limit.is_some_and(|lim| core.cycles >= lim) {
+                return Ok(StopReason::CycleLimit);
+            }
+            if let Some(reason) = core.wake() {
+                if let Some(lim) = cycle_limit {
+                    core.cycles = lim;
+                }
+                return Ok(reason);
+            }
+            if core.halt.swap(false, Ordering::Relaxed) {
+                return Ok(StopReason::HaltRequested);
+            }
+            if let Some(reason) = self.breakpoint().filter(|_| !first) {
+                return Ok(reason);
+            }
+            first = false;
+
+            if self.take_interrupt() {
+                continue;
+            }
+            self.run_instruction()?;
+        }
+    }
+
+    // Body of `run_for`.
+    fn run_slice(&mut self, cycles: u64) -> Result<(u64, StopReason), ExecError> {
+        let core: &mut Mos6502 = self.core_mut();
+        if core.cycle_debt >= cycles {
+            core.cycle_debt -= cycles;
+            return Ok((0, StopReason::CycleLimit));
+        }
+        let budget: u64 = cycles - core.cycle_debt;
+        let start: u64 = core.cycles;
+
+        let reason: StopReason = self.run_until(Some(start + budget))?;
+        let core: &mut Mos6502 = self.core_mut();
+        let elapsed: u64 = core.cycles - start;
+        core.cycle_debt = if reason == StopReason::CycleLimit { elapsed - budget } else { 0 };
+        Ok((elapsed, reason))
+    }
+
+    // Body of `tick`.
+    fn clock(&mut self) -> Result<BusCycle, ExecError> {
+        if self.core().in_flight.is_none() {
+            let irq_masked: bool = self.irq_masked();
+            let op: TickOp = self.core_mut().next_tick_op(irq_masked);
+            self.save_registers();
+            let core: &mut Mos6502 = self.core_mut();
+            core.in_flight = Some(InFlight {
+                op,
+                regs: core.registers(),
+                state: core.state,
+                effective_addr: core.effective_addr,
+                instructions: core.instructions,
+                nmi_hijack: None,
+            });
+        }
+        let flight: InFlight = self.core().in_flight.expect("an instruction is in flight");
+
+        let core: &mut Mos6502 = self.core_mut();
+        core.tick_live = core.tick_log.len();
+        core.tick_pos = 0;
+        let result = match flight.op {
+            TickOp::Idle => {
+                self.idle();
+                Ok(())
+            }
+            TickOp::Interrupt(nmi) => {
+                self.service_interrupt(nmi);
+                Ok(())
+            }
+            TickOp::Instruction => self.run_instruction(),
+        };
+
+        let core: &mut Mos6502 = self.core_mut();
+        let bus: BusCycle = core.tick_log[core.tick_live];
+        if result.is_err() || core.tick_pos == core.tick_log.len() {
+            core.in_flight = None;
+            core.tick_log.clear();
+            result?;
+        } else {
+            let flight: InFlight = core.in_flight.expect("an instruction is in flight");
+            core.set_registers(flight.regs);
+            core.state = flight.state;
+            core.effective_addr = flight.effective_addr;
+            core.instructions = flight.instructions;
+            core.line_sample = Some((core.irq_line, core.nmi_pending));
+            self.restore_registers();
+        }
+        Ok(bus)
+    }
+
+    // NOTE: Finishes an instruction left half way by `tick()`.
+    fn finish_instruction(&mut self) -> Result<(), ExecError> {
+        while self.core().in_flight.is_some() {
+            self.clock()?;
+        }
+        self.core_mut().line_sample = None;
+        Ok(())
+    }
+
+    fn take_interrupt(&mut self) -> bool {
+        let nmi: bool = std::mem::take(&mut self.core_mut().nmi_pending);
+        if !nmi && (!self.core().irq_line || self.irq_masked()) {
+            return false;
+        }
+        self.service_interrupt(nmi);
+        true
+    }
+}
+
 pub struct Mos6502 {
     a: Byte,
     x: Byte,
@@ -38,7 +372,22 @@ pub struct Mos6502 {
     status: Byte,
     sp: Byte,
     pc: Word,
-    cycles: u32,
+    cycles: u64,
+    instructions: u64,
+    irq_line: bool,
+    nmi_pending: bool,
+    state: CpuState,
+    effective_addr: Option<Word>,
+    cycle_debt: u64,
+    in_flight: Option<InFlight>,
+    tick_log: Vec<BusCycle>,
+    tick_pos: usize,
+    tick_live: usize,
+    line_sample: Option<(bool, bool)>,
+    breakpoints: HashSet<Word>,
+    halt: Arc<AtomicBool>,
+    io_port: IoPort,
+    config: Mos6502Config,
     pub mem: Memory,
 }
 
@@ -50,12 +399,17 @@ impl std::fmt::Display for Mos6502 {
 }
 
 const LOOKUP_TBL_SIZE: usize = 0x100;
+const STACK_BASE: Word = 0x0100;
+
+const NMI_VECTOR: Word = 0xFFFA;
+const RESET_VECTOR: Word = 0xFFFC;
+const IRQ_VECTOR: Word = 0xFFFE;
 
 // (ﾉಥДಥ)ﾉ ︵┻━┻･/
-const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
+const LOOKUP_NMOS: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     Some(Mos6502::brk_imp),
     Some(Mos6502::ora_zpx_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     None,
     Some(Mos6502::ora_zp),
@@ -71,7 +425,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::bpl_rel),
     Some(Mos6502::ora_zpy_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     None,
     Some(Mos6502::ora_zpx),
@@ -87,7 +441,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::jsr_abs),
     Some(Mos6502::and_zpx_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     Some(Mos6502::bit_zp),
     Some(Mos6502::and_zp),
@@ -103,7 +457,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::bmi_rel),
     Some(Mos6502::and_zpy_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     None,
     Some(Mos6502::and_zpx),
@@ -119,7 +473,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::rti_imp),
     Some(Mos6502::eor_zpx_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     None,
     Some(Mos6502::eor_zp),
@@ -135,7 +489,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::bvc_rel),
     Some(Mos6502::eor_zpy_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     None,
     Some(Mos6502::eor_zpx),
@@ -151,7 +505,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::rts_imp),
     Some(Mos6502::adc_zpx_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     None,
     Some(Mos6502::adc_zp),
@@ -167,7 +521,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::bvs_rel),
     Some(Mos6502::adc_zpy_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     None,
     Some(Mos6502::adc_zpx),
@@ -199,7 +553,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::bcc_rel),
     Some(Mos6502::sta_zpy_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     Some(Mos6502::sty_zpx),
     Some(Mos6502::sta_zpx),
@@ -231,7 +585,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::bcs_rel),
     Some(Mos6502::lda_zpy_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     Some(Mos6502::ldy_zpx),
     Some(Mos6502::lda_zpx),
@@ -263,7 +617,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::bne_rel),
     Some(Mos6502::cmp_zpy_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     None,
     Some(Mos6502::cmp_zpx),
@@ -295,7 +649,7 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
     Some(Mos6502::beq_rel),
     Some(Mos6502::sbc_zpy_ind),
-    None,
+    Some(Mos6502::jam_imp),
     None,
     None,
     Some(Mos6502::sbc_zpx),
@@ -311,951 +665,3817 @@ const LOOKUP: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
     None,
 ];
 
-impl Mos6502 {
-
-    ////////// PUBLIC FUNCTIONS //////////
-
-    pub fn new(mem: Memory) -> Self {
-        Self {
-            a: 0x00,
-            x: 0x00,
-            y: 0x00,
-            status: 0x00,
-            cycles: 0,
-            sp: 0x00,
-            pc: 0x0000,
-            mem,
-        }
-    }
-
-    pub fn reset(&mut self, reset_vector: bool) {
-        (self.a, self.x, self.y) = (0x00, 0x00, 0x00);
-
-        if reset_vector {
-            let lo: Byte = self.mem.get_byte(0xFFFC);
-            let hi: Byte = self.mem.get_byte(0xFFFC + 1);
-            self.pc = (Word::from(hi) << 8u8) | Word::from(lo);
-        } else {
-            self.pc = 0xFFFC;
-        }
-
-        self.sp = 0xFD;
-
-        self.status = 0x00;
-
-        // TODO: increment cycles by 8.
-    }
-
-    pub fn exe(&mut self, cycle_limit: Option<u32>) {
-        while match cycle_limit { Some(lim) => self.cycles < lim, _ => true } {
-            let opcode: Byte = self.fetch_next_byte();
-            if let Some(instruction) = LOOKUP[opcode as usize] {
-                instruction(self);
-            } else {
-                panic!("Illegal opcode: {opcode}");
-            }
-        }
-    }
-
-    pub fn zero_flag(&self) -> bool {
-        Mos6502Flags::Z.get(self.status)
-    }
-
-    pub fn negative_flag(&self) -> bool {
-        Mos6502Flags::N.get(self.status)
-    }
-
-    pub fn carry_flag(&self) -> bool {
-        Mos6502Flags::C.get(self.status)
-    }
-
-    pub fn interrupts_disable_flag(&self) -> bool {
-        Mos6502Flags::I.get(self.status)
-    }
-
-    pub fn decimal_mode_flag(&self) -> bool {
-        Mos6502Flags::D.get(self.status)
-    }
-
-    pub fn break_flag(&self) -> bool {
-        Mos6502Flags::B.get(self.status)
-    }
-
-    pub fn unused_flag(&self) -> bool {
-        Mos6502Flags::U.get(self.status)
-    }
-
-    pub fn overflow_flag(&self) -> bool {
-        Mos6502Flags::V.get(self.status)
-    }
-
-    pub fn set_xreg(&mut self, data: Byte) {
-        self.x = data;
-    }
-
-    pub fn set_yreg(&mut self, data: Byte) {
-        self.y = data;
-    }
-
-    pub fn set_accumulator(&mut self, data: Byte) {
-        self.a = data;
-    }
-
-    pub fn get_accumulator(&self) -> Byte {
-        self.a
-    }
-
-    pub fn get_yreg(&self) -> Byte {
-        self.y
-    }
-
-    pub fn get_xreg(&self) -> Byte {
-        self.x
-    }
-
-    pub fn get_status(&self) -> Byte {
-        self.status
-    }
-
-    pub fn get_cycles(&self) -> u32 {
-        self.cycles
-    }
-
-    pub fn set_status_flag(&mut self, flag: Mos6502Flags) {
-        flag.set(&mut self.status);
-    }
-
-    pub fn clear_status_flag(&mut self, flag: Mos6502Flags) {
-        flag.clear(&mut self.status);
-    }
-
-    ////////// HELPER FUNCTIONS //////////
-
-    fn cycle(&mut self) {
-        self.cycles += 1;
-    }
-
-    fn push_byte(&mut self, addr: usize, data: Byte) {
-        *self.mem.at(addr) = data;
-        self.sp -= 1;
-        self.cycle();
-    }
-
-    fn push_word(&mut self, addr: usize, data: Word) {
-        self.mem.write_word(addr, data);
-        self.cycle();
-        self.cycle();
-        self.sp -= 2;
-    }
-
-    fn pop(&mut self) {
-        self.sp += 1;
-        self.cycle();
-    }
-
-    fn pc_assign_wcycle(&mut self, data: Word) {
-        self.pc = data;
-        self.cycle();
-    }
-
-    fn acc_assign_wcycle(&mut self, data: Byte) {
-        self.a = data;
-        self.cycle();
-    }
-
-    fn xreg_assign_wcycle(&mut self, data: Byte) {
-        self.x = data;
-        self.cycle();
-    }
-
-    fn yreg_assign_wcycle(&mut self, data: Byte) {
-        self.y = data;
-        self.cycle();
-    }
-
-    fn offset_byte_wcycle(&mut self, target: &mut Byte, offset: Byte) {
-        let sum = u16::from(*target) + u16::from(offset);
-        *target = (sum % 256) as Byte;
-        self.cycle();
-    }
-
-    fn offset_byte_wocycle(&mut self, target: &mut Byte, offset: Byte) {
-        let sum = u16::from(*target) + u16::from(offset);
-        *target = (sum % 256) as Byte;
-    }
-
-    fn offset_word_wocycle(&mut self, target: &mut Word, offset: Byte) {
-        let sum = u16::from(*target) + u16::from(offset);
-        *target = sum % Word::MAX;
-    }
-
-    fn offset_word_wcycle(&mut self, target: &mut Word, offset: Byte) {
-        let sum = u16::from(*target) + u16::from(offset);
-        *target = sum % Word::MAX;
-        self.cycle();
-    }
-
-    fn program_counter(&mut self) {
-        self.pc = (self.pc + 1) % Word::MAX;
-    }
-
-    fn read_byte_at_addr(&mut self, addr: Word) -> Byte {
-        // NOTE: This function is needed to retrieve a byte
-        // and not increment the program counter.
-        // NOTE: When dealing with zero page, this function
-        // is still being used even though the address is
-        // a `Word` and zero page address is a `Byte`. Just
-        // cast to a `Word`.
-        self.cycle();
-        self.mem.get_byte(usize::from(addr))
-    }
-
-    fn fetch_next_byte(&mut self) -> Byte {
-        let b: Byte = self.mem.get_byte(self.pc as usize);
-        self.program_counter();
-        self.cycle();
-        b
-    }
+// NOTE: Undocumented NMOS opcodes, consulted for the slots that
+// are `None` in `LOOKUP_NMOS` when `Mos6502Config::illegal_opcodes`
+// is set.
+const LOOKUP_ILLEGAL: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
+    None,
+    None,
+    None,
+    Some(Mos6502::slo_zpx_ind),
+    Some(Mos6502::nop_zp),
+    None,
+    None,
+    Some(Mos6502::slo_zp),
+    None,
+    None,
+    None,
+    Some(Mos6502::anc_imm),
+    Some(Mos6502::nop_abs),
+    None,
+    None,
+    Some(Mos6502::slo_abs),
+    None,
+    None,
+    None,
+    Some(Mos6502::slo_zpy_ind),
+    Some(Mos6502::nop_zpx),
+    None,
+    None,
+    Some(Mos6502::slo_zpx),
+    None,
+    None,
+    Some(Mos6502::nop_imp),
+    Some(Mos6502::slo_absy),
+    Some(Mos6502::nop_absx),
+    None,
+    None,
+    Some(Mos6502::slo_absx),
+    None,
+    None,
+    None,
+    Some(Mos6502::rla_zpx_ind),
+    None,
+    None,
+    None,
+    Some(Mos6502::rla_zp),
+    None,
+    None,
+    None,
+    Some(Mos6502::anc_imm),
+    None,
+    None,
+    None,
+    Some(Mos6502::rla_abs),
+    None,
+    None,
+    None,
+    Some(Mos6502::rla_zpy_ind),
+    Some(Mos6502::nop_zpx),
+    None,
+    None,
+    Some(Mos6502::rla_zpx),
+    None,
+    None,
+    Some(Mos6502::nop_imp),
+    Some(Mos6502::rla_absy),
+    Some(Mos6502::nop_absx),
+    None,
+    None,
+    Some(Mos6502::rla_absx),
+    None,
+    None,
+    None,
+    Some(Mos6502::sre_zpx_ind),
+    Some(Mos6502::nop_zp),
+    None,
+    None,
+    Some(Mos6502::sre_zp),
+    None,
+    None,
+    None,
+    Some(Mos6502::alr_imm),
+    None,
+    None,
+    None,
+    Some(Mos6502::sre_abs),
+    None,
+    None,
+    None,
+    Some(Mos6502::sre_zpy_ind),
+    Some(Mos6502::nop_zpx),
+    None,
+    None,
+    Some(Mos6502::sre_zpx),
+    None,
+    None,
+    Some(Mos6502::nop_imp),
+    Some(Mos6502::sre_absy),
+    Some(Mos6502::nop_absx),
+    None,
+    None,
+    Some(Mos6502::sre_absx),
+    None,
+    None,
+    None,
+    Some(Mos6502::rra_zpx_ind),
+    Some(Mos6502::nop_zp),
+    None,
+    None,
+    Some(Mos6502::rra_zp),
+    None,
+    None,
+    None,
+    Some(Mos6502::arr_imm),
+    None,
+    None,
+    None,
+    Some(Mos6502::rra_abs),
+    None,
+    None,
+    None,
+    Some(Mos6502::rra_zpy_ind),
+    Some(Mos6502::nop_zpx),
+    None,
+    None,
+    Some(Mos6502::rra_zpx),
+    None,
+    None,
+    Some(Mos6502::nop_imp),
+    Some(Mos6502::rra_absy),
+    Some(Mos6502::nop_absx),
+    None,
+    None,
+    Some(Mos6502::rra_absx),
+    Some(Mos6502::nop_imm),
+    None,
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::sax_zpx_ind),
+    None,
+    None,
+    None,
+    Some(Mos6502::sax_zp),
+    None,
+    Some(Mos6502::nop_imm),
+    None,
+    Some(Mos6502::ane_imm),
+    None,
+    None,
+    None,
+    Some(Mos6502::sax_abs),
+    None,
+    None,
+    None,
+    Some(Mos6502::sha_zpy_ind),
+    None,
+    None,
+    None,
+    Some(Mos6502::sax_zpy),
+    None,
+    None,
+    None,
+    Some(Mos6502::tas_absy),
+    Some(Mos6502::shy_absx),
+    None,
+    Some(Mos6502::shx_absy),
+    Some(Mos6502::sha_absy),
+    None,
+    None,
+    None,
+    Some(Mos6502::lax_zpx_ind),
+    None,
+    None,
+    None,
+    Some(Mos6502::lax_zp),
+    None,
+    None,
+    None,
+    Some(Mos6502::lxa_imm),
+    None,
+    None,
+    None,
+    Some(Mos6502::lax_abs),
+    None,
+    None,
+    None,
+    Some(Mos6502::lax_zpy_ind),
+    None,
+    None,
+    None,
+    Some(Mos6502::lax_zpy),
+    None,
+    None,
+    None,
+    Some(Mos6502::las_absy),
+    None,
+    None,
+    None,
+    Some(Mos6502::lax_absy),
+    None,
+    None,
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::dcp_zpx_ind),
+    None,
+    None,
+    None,
+    Some(Mos6502::dcp_zp),
+    None,
+    None,
+    None,
+    Some(Mos6502::sbx_imm),
+    None,
+    None,
+    None,
+    Some(Mos6502::dcp_abs),
+    None,
+    None,
+    None,
+    Some(Mos6502::dcp_zpy_ind),
+    Some(Mos6502::nop_zpx),
+    None,
+    None,
+    Some(Mos6502::dcp_zpx),
+    None,
+    None,
+    Some(Mos6502::nop_imp),
+    Some(Mos6502::dcp_absy),
+    Some(Mos6502::nop_absx),
+    None,
+    None,
+    Some(Mos6502::dcp_absx),
+    None,
+    None,
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::isc_zpx_ind),
+    None,
+    None,
+    None,
+    Some(Mos6502::isc_zp),
+    None,
+    None,
+    None,
+    Some(Mos6502::sbc_imm),
+    None,
+    None,
+    None,
+    Some(Mos6502::isc_abs),
+    None,
+    None,
+    None,
+    Some(Mos6502::isc_zpy_ind),
+    Some(Mos6502::nop_zpx),
+    None,
+    None,
+    Some(Mos6502::isc_zpx),
+    None,
+    None,
+    Some(Mos6502::nop_imp),
+    Some(Mos6502::isc_absy),
+    Some(Mos6502::nop_absx),
+    None,
+    None,
+    Some(Mos6502::isc_absx),
+];
+
+// NOTE: 65C02, every opcode is decoded. The ones WDC left unused
+// are NOPs of the same length as the instruction they resemble.
+const LOOKUP_CMOS: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
+    Some(Mos6502::brk_imp),
+    Some(Mos6502::ora_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::tsb_zp),
+    Some(Mos6502::ora_zp),
+    Some(Mos6502::asl_zp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::php_imp),
+    Some(Mos6502::ora_imm),
+    Some(Mos6502::asl_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::tsb_abs),
+    Some(Mos6502::ora_abs),
+    Some(Mos6502::asl_abs),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bpl_rel),
+    Some(Mos6502::ora_zpy_ind),
+    Some(Mos6502::ora_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::trb_zp),
+    Some(Mos6502::ora_zpx),
+    Some(Mos6502::asl_zpx),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::clc_imp),
+    Some(Mos6502::ora_absy),
+    Some(Mos6502::inc_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::trb_abs),
+    Some(Mos6502::ora_absx),
+    Some(Mos6502::asl_absx_cmos),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jsr_abs),
+    Some(Mos6502::and_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_zp),
+    Some(Mos6502::and_zp),
+    Some(Mos6502::rol_zp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::plp_imp),
+    Some(Mos6502::and_imm),
+    Some(Mos6502::rol_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_abs),
+    Some(Mos6502::and_abs),
+    Some(Mos6502::rol_abs),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bmi_rel),
+    Some(Mos6502::and_zpy_ind),
+    Some(Mos6502::and_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_zpx),
+    Some(Mos6502::and_zpx),
+    Some(Mos6502::rol_zpx),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sec_imp),
+    Some(Mos6502::and_absy),
+    Some(Mos6502::dec_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_absx),
+    Some(Mos6502::and_absx),
+    Some(Mos6502::rol_absx_cmos),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::rti_imp),
+    Some(Mos6502::eor_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zp),
+    Some(Mos6502::eor_zp),
+    Some(Mos6502::lsr_zp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::pha_imp),
+    Some(Mos6502::eor_imm),
+    Some(Mos6502::lsr_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jmp_abs),
+    Some(Mos6502::eor_abs),
+    Some(Mos6502::lsr_abs),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bvc_rel),
+    Some(Mos6502::eor_zpy_ind),
+    Some(Mos6502::eor_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zpx),
+    Some(Mos6502::eor_zpx),
+    Some(Mos6502::lsr_zpx),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cli_imp),
+    Some(Mos6502::eor_absy),
+    Some(Mos6502::phy_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_abs_5c),
+    Some(Mos6502::eor_absx),
+    Some(Mos6502::lsr_absx_cmos),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::rts_imp),
+    Some(Mos6502::adc_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::stz_zp),
+    Some(Mos6502::adc_zp),
+    Some(Mos6502::ror_zp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::pla_imp),
+    Some(Mos6502::adc_imm),
+    Some(Mos6502::ror_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jmp_abs_ind_cmos),
+    Some(Mos6502::adc_abs),
+    Some(Mos6502::ror_abs),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bvs_rel),
+    Some(Mos6502::adc_zpy_ind),
+    Some(Mos6502::adc_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::stz_zpx),
+    Some(Mos6502::adc_zpx),
+    Some(Mos6502::ror_zpx),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sei_imp),
+    Some(Mos6502::adc_absy),
+    Some(Mos6502::ply_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jmp_absx_ind),
+    Some(Mos6502::adc_absx),
+    Some(Mos6502::ror_absx_cmos),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bra_rel),
+    Some(Mos6502::sta_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sty_zp),
+    Some(Mos6502::sta_zp),
+    Some(Mos6502::stx_zp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::dey_imp),
+    Some(Mos6502::bit_imm),
+    Some(Mos6502::txa_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sty_abs),
+    Some(Mos6502::sta_abs),
+    Some(Mos6502::stx_abs),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bcc_rel),
+    Some(Mos6502::sta_zpy_ind),
+    Some(Mos6502::sta_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sty_zpx),
+    Some(Mos6502::sta_zpx),
+    Some(Mos6502::stx_zpy),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::tya_imp),
+    Some(Mos6502::sta_absy),
+    Some(Mos6502::txs_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::stz_abs),
+    Some(Mos6502::sta_absx),
+    Some(Mos6502::stz_absx),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_imm),
+    Some(Mos6502::lda_zpx_ind),
+    Some(Mos6502::ldx_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_zp),
+    Some(Mos6502::lda_zp),
+    Some(Mos6502::ldx_zp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::tay_imp),
+    Some(Mos6502::lda_imm),
+    Some(Mos6502::tax_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_abs),
+    Some(Mos6502::lda_abs),
+    Some(Mos6502::ldx_abs),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bcs_rel),
+    Some(Mos6502::lda_zpy_ind),
+    Some(Mos6502::lda_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_zpx),
+    Some(Mos6502::lda_zpx),
+    Some(Mos6502::ldx_zpy),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::clv_imp),
+    Some(Mos6502::lda_absy),
+    Some(Mos6502::tsx_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_absx),
+    Some(Mos6502::lda_absx),
+    Some(Mos6502::ldx_absy),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpy_imm),
+    Some(Mos6502::cmp_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpy_zp),
+    Some(Mos6502::cmp_zp),
+    Some(Mos6502::dec_zp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::iny_imp),
+    Some(Mos6502::cmp_imm),
+    Some(Mos6502::dex_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpy_abs),
+    Some(Mos6502::cmp_abs),
+    Some(Mos6502::dec_abs),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bne_rel),
+    Some(Mos6502::cmp_zpy_ind),
+    Some(Mos6502::cmp_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zpx),
+    Some(Mos6502::cmp_zpx),
+    Some(Mos6502::dec_zpx),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cld_imp),
+    Some(Mos6502::cmp_absy),
+    Some(Mos6502::phx_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_abs),
+    Some(Mos6502::cmp_absx),
+    Some(Mos6502::dec_absx),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpx_imm),
+    Some(Mos6502::sbc_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpx_zp),
+    Some(Mos6502::sbc_zp),
+    Some(Mos6502::inc_zp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::inx_imp),
+    Some(Mos6502::sbc_imm),
+    Some(Mos6502::nop_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpx_abs),
+    Some(Mos6502::sbc_abs),
+    Some(Mos6502::inc_abs),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::beq_rel),
+    Some(Mos6502::sbc_zpy_ind),
+    Some(Mos6502::sbc_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zpx),
+    Some(Mos6502::sbc_zpx),
+    Some(Mos6502::inc_zpx),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sed_imp),
+    Some(Mos6502::sbc_absy),
+    Some(Mos6502::plx_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_abs),
+    Some(Mos6502::sbc_absx),
+    Some(Mos6502::inc_absx),
+    Some(Mos6502::nop_imp_1cycle),
+];
+
+// NOTE: Rockwell R65C02, the 65C02 with the bit instructions in
+// columns 7 and F.
+const LOOKUP_R65C02: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
+    Some(Mos6502::brk_imp),
+    Some(Mos6502::ora_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::tsb_zp),
+    Some(Mos6502::ora_zp),
+    Some(Mos6502::asl_zp),
+    Some(Mos6502::rmb0_zp),
+    Some(Mos6502::php_imp),
+    Some(Mos6502::ora_imm),
+    Some(Mos6502::asl_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::tsb_abs),
+    Some(Mos6502::ora_abs),
+    Some(Mos6502::asl_abs),
+    Some(Mos6502::bbr0_zp_rel),
+    Some(Mos6502::bpl_rel),
+    Some(Mos6502::ora_zpy_ind),
+    Some(Mos6502::ora_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::trb_zp),
+    Some(Mos6502::ora_zpx),
+    Some(Mos6502::asl_zpx),
+    Some(Mos6502::rmb1_zp),
+    Some(Mos6502::clc_imp),
+    Some(Mos6502::ora_absy),
+    Some(Mos6502::inc_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::trb_abs),
+    Some(Mos6502::ora_absx),
+    Some(Mos6502::asl_absx_cmos),
+    Some(Mos6502::bbr1_zp_rel),
+    Some(Mos6502::jsr_abs),
+    Some(Mos6502::and_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_zp),
+    Some(Mos6502::and_zp),
+    Some(Mos6502::rol_zp),
+    Some(Mos6502::rmb2_zp),
+    Some(Mos6502::plp_imp),
+    Some(Mos6502::and_imm),
+    Some(Mos6502::rol_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_abs),
+    Some(Mos6502::and_abs),
+    Some(Mos6502::rol_abs),
+    Some(Mos6502::bbr2_zp_rel),
+    Some(Mos6502::bmi_rel),
+    Some(Mos6502::and_zpy_ind),
+    Some(Mos6502::and_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_zpx),
+    Some(Mos6502::and_zpx),
+    Some(Mos6502::rol_zpx),
+    Some(Mos6502::rmb3_zp),
+    Some(Mos6502::sec_imp),
+    Some(Mos6502::and_absy),
+    Some(Mos6502::dec_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_absx),
+    Some(Mos6502::and_absx),
+    Some(Mos6502::rol_absx_cmos),
+    Some(Mos6502::bbr3_zp_rel),
+    Some(Mos6502::rti_imp),
+    Some(Mos6502::eor_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zp),
+    Some(Mos6502::eor_zp),
+    Some(Mos6502::lsr_zp),
+    Some(Mos6502::rmb4_zp),
+    Some(Mos6502::pha_imp),
+    Some(Mos6502::eor_imm),
+    Some(Mos6502::lsr_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jmp_abs),
+    Some(Mos6502::eor_abs),
+    Some(Mos6502::lsr_abs),
+    Some(Mos6502::bbr4_zp_rel),
+    Some(Mos6502::bvc_rel),
+    Some(Mos6502::eor_zpy_ind),
+    Some(Mos6502::eor_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zpx),
+    Some(Mos6502::eor_zpx),
+    Some(Mos6502::lsr_zpx),
+    Some(Mos6502::rmb5_zp),
+    Some(Mos6502::cli_imp),
+    Some(Mos6502::eor_absy),
+    Some(Mos6502::phy_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_abs_5c),
+    Some(Mos6502::eor_absx),
+    Some(Mos6502::lsr_absx_cmos),
+    Some(Mos6502::bbr5_zp_rel),
+    Some(Mos6502::rts_imp),
+    Some(Mos6502::adc_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::stz_zp),
+    Some(Mos6502::adc_zp),
+    Some(Mos6502::ror_zp),
+    Some(Mos6502::rmb6_zp),
+    Some(Mos6502::pla_imp),
+    Some(Mos6502::adc_imm),
+    Some(Mos6502::ror_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jmp_abs_ind_cmos),
+    Some(Mos6502::adc_abs),
+    Some(Mos6502::ror_abs),
+    Some(Mos6502::bbr6_zp_rel),
+    Some(Mos6502::bvs_rel),
+    Some(Mos6502::adc_zpy_ind),
+    Some(Mos6502::adc_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::stz_zpx),
+    Some(Mos6502::adc_zpx),
+    Some(Mos6502::ror_zpx),
+    Some(Mos6502::rmb7_zp),
+    Some(Mos6502::sei_imp),
+    Some(Mos6502::adc_absy),
+    Some(Mos6502::ply_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jmp_absx_ind),
+    Some(Mos6502::adc_absx),
+    Some(Mos6502::ror_absx_cmos),
+    Some(Mos6502::bbr7_zp_rel),
+    Some(Mos6502::bra_rel),
+    Some(Mos6502::sta_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sty_zp),
+    Some(Mos6502::sta_zp),
+    Some(Mos6502::stx_zp),
+    Some(Mos6502::smb0_zp),
+    Some(Mos6502::dey_imp),
+    Some(Mos6502::bit_imm),
+    Some(Mos6502::txa_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sty_abs),
+    Some(Mos6502::sta_abs),
+    Some(Mos6502::stx_abs),
+    Some(Mos6502::bbs0_zp_rel),
+    Some(Mos6502::bcc_rel),
+    Some(Mos6502::sta_zpy_ind),
+    Some(Mos6502::sta_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sty_zpx),
+    Some(Mos6502::sta_zpx),
+    Some(Mos6502::stx_zpy),
+    Some(Mos6502::smb1_zp),
+    Some(Mos6502::tya_imp),
+    Some(Mos6502::sta_absy),
+    Some(Mos6502::txs_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::stz_abs),
+    Some(Mos6502::sta_absx),
+    Some(Mos6502::stz_absx),
+    Some(Mos6502::bbs1_zp_rel),
+    Some(Mos6502::ldy_imm),
+    Some(Mos6502::lda_zpx_ind),
+    Some(Mos6502::ldx_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_zp),
+    Some(Mos6502::lda_zp),
+    Some(Mos6502::ldx_zp),
+    Some(Mos6502::smb2_zp),
+    Some(Mos6502::tay_imp),
+    Some(Mos6502::lda_imm),
+    Some(Mos6502::tax_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_abs),
+    Some(Mos6502::lda_abs),
+    Some(Mos6502::ldx_abs),
+    Some(Mos6502::bbs2_zp_rel),
+    Some(Mos6502::bcs_rel),
+    Some(Mos6502::lda_zpy_ind),
+    Some(Mos6502::lda_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_zpx),
+    Some(Mos6502::lda_zpx),
+    Some(Mos6502::ldx_zpy),
+    Some(Mos6502::smb3_zp),
+    Some(Mos6502::clv_imp),
+    Some(Mos6502::lda_absy),
+    Some(Mos6502::tsx_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_absx),
+    Some(Mos6502::lda_absx),
+    Some(Mos6502::ldx_absy),
+    Some(Mos6502::bbs3_zp_rel),
+    Some(Mos6502::cpy_imm),
+    Some(Mos6502::cmp_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpy_zp),
+    Some(Mos6502::cmp_zp),
+    Some(Mos6502::dec_zp),
+    Some(Mos6502::smb4_zp),
+    Some(Mos6502::iny_imp),
+    Some(Mos6502::cmp_imm),
+    Some(Mos6502::dex_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpy_abs),
+    Some(Mos6502::cmp_abs),
+    Some(Mos6502::dec_abs),
+    Some(Mos6502::bbs4_zp_rel),
+    Some(Mos6502::bne_rel),
+    Some(Mos6502::cmp_zpy_ind),
+    Some(Mos6502::cmp_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zpx),
+    Some(Mos6502::cmp_zpx),
+    Some(Mos6502::dec_zpx),
+    Some(Mos6502::smb5_zp),
+    Some(Mos6502::cld_imp),
+    Some(Mos6502::cmp_absy),
+    Some(Mos6502::phx_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_abs),
+    Some(Mos6502::cmp_absx),
+    Some(Mos6502::dec_absx),
+    Some(Mos6502::bbs5_zp_rel),
+    Some(Mos6502::cpx_imm),
+    Some(Mos6502::sbc_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpx_zp),
+    Some(Mos6502::sbc_zp),
+    Some(Mos6502::inc_zp),
+    Some(Mos6502::smb6_zp),
+    Some(Mos6502::inx_imp),
+    Some(Mos6502::sbc_imm),
+    Some(Mos6502::nop_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpx_abs),
+    Some(Mos6502::sbc_abs),
+    Some(Mos6502::inc_abs),
+    Some(Mos6502::bbs6_zp_rel),
+    Some(Mos6502::beq_rel),
+    Some(Mos6502::sbc_zpy_ind),
+    Some(Mos6502::sbc_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zpx),
+    Some(Mos6502::sbc_zpx),
+    Some(Mos6502::inc_zpx),
+    Some(Mos6502::smb7_zp),
+    Some(Mos6502::sed_imp),
+    Some(Mos6502::sbc_absy),
+    Some(Mos6502::plx_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_abs),
+    Some(Mos6502::sbc_absx),
+    Some(Mos6502::inc_absx),
+    Some(Mos6502::bbs7_zp_rel),
+];
+
+// NOTE: WDC W65C02S, the R65C02 plus WAI ($CB) and STP ($DB).
+const LOOKUP_W65C02S: [Option<fn(&mut Mos6502)>; LOOKUP_TBL_SIZE] = [
+    Some(Mos6502::brk_imp),
+    Some(Mos6502::ora_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::tsb_zp),
+    Some(Mos6502::ora_zp),
+    Some(Mos6502::asl_zp),
+    Some(Mos6502::rmb0_zp),
+    Some(Mos6502::php_imp),
+    Some(Mos6502::ora_imm),
+    Some(Mos6502::asl_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::tsb_abs),
+    Some(Mos6502::ora_abs),
+    Some(Mos6502::asl_abs),
+    Some(Mos6502::bbr0_zp_rel),
+    Some(Mos6502::bpl_rel),
+    Some(Mos6502::ora_zpy_ind),
+    Some(Mos6502::ora_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::trb_zp),
+    Some(Mos6502::ora_zpx),
+    Some(Mos6502::asl_zpx),
+    Some(Mos6502::rmb1_zp),
+    Some(Mos6502::clc_imp),
+    Some(Mos6502::ora_absy),
+    Some(Mos6502::inc_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::trb_abs),
+    Some(Mos6502::ora_absx),
+    Some(Mos6502::asl_absx_cmos),
+    Some(Mos6502::bbr1_zp_rel),
+    Some(Mos6502::jsr_abs),
+    Some(Mos6502::and_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_zp),
+    Some(Mos6502::and_zp),
+    Some(Mos6502::rol_zp),
+    Some(Mos6502::rmb2_zp),
+    Some(Mos6502::plp_imp),
+    Some(Mos6502::and_imm),
+    Some(Mos6502::rol_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_abs),
+    Some(Mos6502::and_abs),
+    Some(Mos6502::rol_abs),
+    Some(Mos6502::bbr2_zp_rel),
+    Some(Mos6502::bmi_rel),
+    Some(Mos6502::and_zpy_ind),
+    Some(Mos6502::and_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_zpx),
+    Some(Mos6502::and_zpx),
+    Some(Mos6502::rol_zpx),
+    Some(Mos6502::rmb3_zp),
+    Some(Mos6502::sec_imp),
+    Some(Mos6502::and_absy),
+    Some(Mos6502::dec_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::bit_absx),
+    Some(Mos6502::and_absx),
+    Some(Mos6502::rol_absx_cmos),
+    Some(Mos6502::bbr3_zp_rel),
+    Some(Mos6502::rti_imp),
+    Some(Mos6502::eor_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zp),
+    Some(Mos6502::eor_zp),
+    Some(Mos6502::lsr_zp),
+    Some(Mos6502::rmb4_zp),
+    Some(Mos6502::pha_imp),
+    Some(Mos6502::eor_imm),
+    Some(Mos6502::lsr_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jmp_abs),
+    Some(Mos6502::eor_abs),
+    Some(Mos6502::lsr_abs),
+    Some(Mos6502::bbr4_zp_rel),
+    Some(Mos6502::bvc_rel),
+    Some(Mos6502::eor_zpy_ind),
+    Some(Mos6502::eor_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zpx),
+    Some(Mos6502::eor_zpx),
+    Some(Mos6502::lsr_zpx),
+    Some(Mos6502::rmb5_zp),
+    Some(Mos6502::cli_imp),
+    Some(Mos6502::eor_absy),
+    Some(Mos6502::phy_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_abs_5c),
+    Some(Mos6502::eor_absx),
+    Some(Mos6502::lsr_absx_cmos),
+    Some(Mos6502::bbr5_zp_rel),
+    Some(Mos6502::rts_imp),
+    Some(Mos6502::adc_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::stz_zp),
+    Some(Mos6502::adc_zp),
+    Some(Mos6502::ror_zp),
+    Some(Mos6502::rmb6_zp),
+    Some(Mos6502::pla_imp),
+    Some(Mos6502::adc_imm),
+    Some(Mos6502::ror_acc),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jmp_abs_ind_cmos),
+    Some(Mos6502::adc_abs),
+    Some(Mos6502::ror_abs),
+    Some(Mos6502::bbr6_zp_rel),
+    Some(Mos6502::bvs_rel),
+    Some(Mos6502::adc_zpy_ind),
+    Some(Mos6502::adc_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::stz_zpx),
+    Some(Mos6502::adc_zpx),
+    Some(Mos6502::ror_zpx),
+    Some(Mos6502::rmb7_zp),
+    Some(Mos6502::sei_imp),
+    Some(Mos6502::adc_absy),
+    Some(Mos6502::ply_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::jmp_absx_ind),
+    Some(Mos6502::adc_absx),
+    Some(Mos6502::ror_absx_cmos),
+    Some(Mos6502::bbr7_zp_rel),
+    Some(Mos6502::bra_rel),
+    Some(Mos6502::sta_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sty_zp),
+    Some(Mos6502::sta_zp),
+    Some(Mos6502::stx_zp),
+    Some(Mos6502::smb0_zp),
+    Some(Mos6502::dey_imp),
+    Some(Mos6502::bit_imm),
+    Some(Mos6502::txa_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sty_abs),
+    Some(Mos6502::sta_abs),
+    Some(Mos6502::stx_abs),
+    Some(Mos6502::bbs0_zp_rel),
+    Some(Mos6502::bcc_rel),
+    Some(Mos6502::sta_zpy_ind),
+    Some(Mos6502::sta_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::sty_zpx),
+    Some(Mos6502::sta_zpx),
+    Some(Mos6502::stx_zpy),
+    Some(Mos6502::smb1_zp),
+    Some(Mos6502::tya_imp),
+    Some(Mos6502::sta_absy),
+    Some(Mos6502::txs_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::stz_abs),
+    Some(Mos6502::sta_absx),
+    Some(Mos6502::stz_absx),
+    Some(Mos6502::bbs1_zp_rel),
+    Some(Mos6502::ldy_imm),
+    Some(Mos6502::lda_zpx_ind),
+    Some(Mos6502::ldx_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_zp),
+    Some(Mos6502::lda_zp),
+    Some(Mos6502::ldx_zp),
+    Some(Mos6502::smb2_zp),
+    Some(Mos6502::tay_imp),
+    Some(Mos6502::lda_imm),
+    Some(Mos6502::tax_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_abs),
+    Some(Mos6502::lda_abs),
+    Some(Mos6502::ldx_abs),
+    Some(Mos6502::bbs2_zp_rel),
+    Some(Mos6502::bcs_rel),
+    Some(Mos6502::lda_zpy_ind),
+    Some(Mos6502::lda_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_zpx),
+    Some(Mos6502::lda_zpx),
+    Some(Mos6502::ldx_zpy),
+    Some(Mos6502::smb3_zp),
+    Some(Mos6502::clv_imp),
+    Some(Mos6502::lda_absy),
+    Some(Mos6502::tsx_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::ldy_absx),
+    Some(Mos6502::lda_absx),
+    Some(Mos6502::ldx_absy),
+    Some(Mos6502::bbs3_zp_rel),
+    Some(Mos6502::cpy_imm),
+    Some(Mos6502::cmp_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpy_zp),
+    Some(Mos6502::cmp_zp),
+    Some(Mos6502::dec_zp),
+    Some(Mos6502::smb4_zp),
+    Some(Mos6502::iny_imp),
+    Some(Mos6502::cmp_imm),
+    Some(Mos6502::dex_imp),
+    Some(Mos6502::wai_imp),
+    Some(Mos6502::cpy_abs),
+    Some(Mos6502::cmp_abs),
+    Some(Mos6502::dec_abs),
+    Some(Mos6502::bbs4_zp_rel),
+    Some(Mos6502::bne_rel),
+    Some(Mos6502::cmp_zpy_ind),
+    Some(Mos6502::cmp_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zpx),
+    Some(Mos6502::cmp_zpx),
+    Some(Mos6502::dec_zpx),
+    Some(Mos6502::smb5_zp),
+    Some(Mos6502::cld_imp),
+    Some(Mos6502::cmp_absy),
+    Some(Mos6502::phx_imp),
+    Some(Mos6502::stp_imp),
+    Some(Mos6502::nop_abs),
+    Some(Mos6502::cmp_absx),
+    Some(Mos6502::dec_absx),
+    Some(Mos6502::bbs5_zp_rel),
+    Some(Mos6502::cpx_imm),
+    Some(Mos6502::sbc_zpx_ind),
+    Some(Mos6502::nop_imm),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpx_zp),
+    Some(Mos6502::sbc_zp),
+    Some(Mos6502::inc_zp),
+    Some(Mos6502::smb6_zp),
+    Some(Mos6502::inx_imp),
+    Some(Mos6502::sbc_imm),
+    Some(Mos6502::nop_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::cpx_abs),
+    Some(Mos6502::sbc_abs),
+    Some(Mos6502::inc_abs),
+    Some(Mos6502::bbs6_zp_rel),
+    Some(Mos6502::beq_rel),
+    Some(Mos6502::sbc_zpy_ind),
+    Some(Mos6502::sbc_zp_ind),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_zpx),
+    Some(Mos6502::sbc_zpx),
+    Some(Mos6502::inc_zpx),
+    Some(Mos6502::smb7_zp),
+    Some(Mos6502::sed_imp),
+    Some(Mos6502::sbc_absy),
+    Some(Mos6502::plx_imp),
+    Some(Mos6502::nop_imp_1cycle),
+    Some(Mos6502::nop_abs),
+    Some(Mos6502::sbc_absx),
+    Some(Mos6502::inc_absx),
+    Some(Mos6502::bbs7_zp_rel),
+];
+
+impl Mos6502 {
+
+    ////////// PUBLIC FUNCTIONS //////////
+
+    pub fn new(mem: Memory) -> Self {
+        Self::with_config(mem, Mos6502Config::default())
+    }
+
+    pub fn with_config(mem: Memory, config: Mos6502Config) -> Self {
+        Self {
+            a: 0x00,
+            x: 0x00,
+            y: 0x00,
+            status: 0x00,
+            cycles: 0,
+            instructions: 0,
+            sp: 0x00,
+            pc: 0x0000,
+            irq_line: false,
+            nmi_pending: false,
+            state: CpuState::Running,
+            effective_addr: None,
+            cycle_debt: 0,
+            in_flight: None,
+            tick_log: Vec::new(),
+            tick_pos: 0,
+            tick_live: 0,
+            line_sample: None,
+            breakpoints: HashSet::new(),
+            halt: Arc::new(AtomicBool::new(false)),
+            io_port: IoPort::new(config.io_port_pullups),
+            config,
+            mem,
+        }
+    }
+
+    // NOTE: Cold start. Registers come up in whatever state the
+    // chip powers on in, zeroed here, before the reset sequence
+    // runs. Memory is left to the caller.
+    pub fn power_on(&mut self) {
+        (self.a, self.x, self.y) = (0x00, 0x00, 0x00);
+        self.sp = 0x00;
+        self.status = 0x00;
+        self.cold_start();
+        self.reset();
+    }
+
+    // NOTE: Warm reset, the 7 cycle sequence the RES line kicks
+    // off. It runs like an interrupt whose three pushes are turned
+    // into reads, so SP still drops by three but nothing is written.
+    // A, X, Y and the other flags are left alone, I is set and `pc`
+    // is loaded from the reset vector. Resets also abort whatever
+    // `tick()` was in the middle of.
+    pub fn reset(&mut self) {
+        self.restart();
+        self.io_port.reset();
+
+        self.cycle();
+        self.cycle();
+        for _ in 0..3 {
+            self.read_byte_at_addr(self.stack_addr());
+            self.sp = self.sp.wrapping_sub(1);
+        }
+        self.interrupt_set_status();
+        self.pc = self.read_word_at_abs_addr(RESET_VECTOR);
+    }
+
+    // NOTE: Runs until `cycle_limit` is reached (or forever when
+    // `None`), the CPU jams, waits or stops, a halt is requested or
+    // `pc` lands on a breakpoint. The clock keeps running while the
+    // CPU is jammed, waiting or stopped, so with a limit the rest of
+    // it is spent idle, the same as ticking through it would.
+    // The instruction at `pc` when called always runs,
+    // so calling again resumes past the breakpoint that stopped us.
+    pub fn exe(&mut self, cycle_limit: Option<u64>) -> Result<StopReason, ExecError> {
+        self.run_until(cycle_limit)
+    }
+
+    // NOTE: Runs for a slice of `cycles` relative to now and returns
+    // how many cycles actually elapsed. The last instruction can run
+    // past the end of the slice, that overshoot is owed and taken
+    // off the next slice so back to back slices don't drift. A slice
+    // cut short by anything but the cycle budget leaves no debt.
+    pub fn run_for(&mut self, cycles: u64) -> Result<(u64, StopReason), ExecError> {
+        self.run_slice(cycles)
+    }
+
+    pub fn cycle_debt(&self) -> u64 {
+        self.cycle_debt
+    }
+
+    // NOTE: Runs exactly one instruction and reports what it did.
+    // A pending interrupt is serviced first, in which case the
+    // record describes the first instruction of the handler and
+    // `cycles` includes the 7 cycles of the interrupt sequence.
+    // Returns `None` when the CPU is jammed, stopped or waiting.
+    pub fn step(&mut self) -> Result<Option<StepRecord>, ExecError> {
+        self.finish_instruction()?;
+        if self.wake().is_some() {
+            return Ok(None);
+        }
+        let start: u64 = self.cycles;
+        self.take_interrupt();
+
+        let before: RegisterState = self.registers();
+        let opcode: Byte = self.peek_byte(self.pc);
+        let (mnemonic, mode) = match self.decode(opcode) {
+            Some(_) => opcode_info(self.config.variant, opcode),
+            None if self.config.unknown_opcode == UnknownOpcodePolicy::Jam => ("JAM", AddressingMode::Implied),
+            None => ("NOP", AddressingMode::Implied),
+        };
+        let mut addr: Word = self.pc;
+        let operand: Vec<Byte> = (0..mode.operand_len())
+            .map(|_| {
+                addr = addr.wrapping_add(1);
+                self.peek_byte(addr)
+            })
+            .collect();
+
+        self.effective_addr = None;
+        self.execute()?;
+
+        Ok(Some(StepRecord {
+            pc: before.pc,
+            opcode,
+            operand,
+            mnemonic,
+            mode,
+            effective_addr: self.effective_addr,
+            cycles: self.cycles - start,
+            before,
+            after: self.registers(),
+        }))
+    }
+
+    // NOTE: Advances the CPU by one clock and returns what was on
+    // the bus. Every tick re-runs the current instruction from its
+    // starting registers, the cycles already spent are replayed from
+    // a log and the ones after the next are dropped, so each read
+    // and write reaches memory on its own tick and sees whatever
+    // other chips put there in between. Registers only change on the
+    // tick that finishes the instruction. IRQ and NMI are sampled on
+    // every tick, the sample taken on the second to last cycle
+    // decides whether an interrupt follows. Internal cycles show up
+    // as reads of `pc`.
+    pub fn tick(&mut self) -> Result<BusCycle, ExecError> {
+        self.clock()
+    }
+
+    pub fn registers(&self) -> RegisterState {
+        RegisterState {
+            a: self.a,
+            x: self.x,
+            y: self.y,
+            sp: self.sp,
+            pc: self.pc,
+            status: self.status,
+        }
+    }
+
+    pub(crate) fn set_registers(&mut self, regs: RegisterState) {
+        (self.a, self.x, self.y) = (regs.a, regs.x, regs.y);
+        (self.sp, self.pc, self.status) = (regs.sp, regs.pc, regs.status);
+    }
+
+    pub fn add_breakpoint(&mut self, addr: Word) {
+        self.breakpoints.insert(addr);
+    }
+
+    pub fn remove_breakpoint(&mut self, addr: Word) {
+        self.breakpoints.remove(&addr);
+    }
+
+    // Makes `exe` return `StopReason::HaltRequested` before the next
+    // instruction. The handle can be handed to other threads.
+    pub fn halt_handle(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.halt)
+    }
+
+    pub fn request_halt(&self) {
+        self.halt.store(true, Ordering::Relaxed);
+    }
+
+    pub fn state(&self) -> CpuState {
+        self.state
+    }
+
+    // NOTE: IRQ is level sensitive, it is taken before every
+    // instruction for as long as the line is held and I is clear.
+    pub fn set_irq_line(&mut self, asserted: bool) {
+        self.irq_line = asserted;
+    }
+
+    // NOTE: NMI is edge triggered, every call is one falling edge.
+    // Edges that arrive before the last one was serviced are lost.
+    pub fn trigger_nmi(&mut self) {
+        self.nmi_pending = true;
+    }
+
+    pub fn config(&self) -> &Mos6502Config {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut Mos6502Config {
+        &mut self.config
+    }
+
+    // NOTE: Only wired up to the bus for `Mos6502Variant::Mos6510`.
+    pub fn io_port(&self) -> &IoPort {
+        &self.io_port
+    }
+
+    pub fn io_port_mut(&mut self) -> &mut IoPort {
+        &mut self.io_port
+    }
+
+    pub fn zero_flag(&self) -> bool {
+        Mos6502Flags::Z.get(self.status)
+    }
+
+    pub fn negative_flag(&self) -> bool {
+        Mos6502Flags::N.get(self.status)
+    }
+
+    pub fn carry_flag(&self) -> bool {
+        Mos6502Flags::C.get(self.status)
+    }
+
+    pub fn interrupts_disable_flag(&self) -> bool {
+        Mos6502Flags::I.get(self.status)
+    }
+
+    pub fn decimal_mode_flag(&self) -> bool {
+        Mos6502Flags::D.get(self.status)
+    }
+
+    pub fn break_flag(&self) -> bool {
+        Mos6502Flags::B.get(self.status)
+    }
+
+    pub fn unused_flag(&self) -> bool {
+        Mos6502Flags::U.get(self.status)
+    }
+
+    pub fn overflow_flag(&self) -> bool {
+        Mos6502Flags::V.get(self.status)
+    }
+
+    pub fn set_xreg(&mut self, data: Byte) {
+        self.x = data;
+    }
+
+    pub fn set_yreg(&mut self, data: Byte) {
+        self.y = data;
+    }
+
+    pub fn set_accumulator(&mut self, data: Byte) {
+        self.a = data;
+    }
+
+    pub fn get_accumulator(&self) -> Byte {
+        self.a
+    }
+
+    pub fn get_yreg(&self) -> Byte {
+        self.y
+    }
+
+    pub fn get_xreg(&self) -> Byte {
+        self.x
+    }
+
+    pub fn get_pc(&self) -> Word {
+        self.pc
+    }
+
+    pub fn set_pc(&mut self, data: Word) {
+        self.pc = data;
+    }
+
+    pub fn get_sp(&self) -> Byte {
+        self.sp
+    }
+
+    pub fn set_sp(&mut self, data: Byte) {
+        self.sp = data;
+    }
+
+    pub fn get_status(&self) -> Byte {
+        self.status
+    }
+
+    pub fn get_cycles(&self) -> u64 {
+        self.cycles
+    }
+
+    // Instructions retired, interrupt sequences don't count.
+    pub fn get_instructions(&self) -> u64 {
+        self.instructions
+    }
+
+    // Zeroes the cycle and instruction counters and nothing else.
+    pub fn reset_counters(&mut self) {
+        self.cycles = 0;
+        self.instructions = 0;
+    }
+
+    pub fn cycles_to_duration(&self, cycles: u64) -> Duration {
+        let hz = u128::from(self.config.clock_hz.max(1));
+        let nanos: u128 = u128::from(cycles) * 1_000_000_000 / hz;
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+
+    // Emulated time covered by `get_cycles()`.
+    pub fn elapsed(&self) -> Duration {
+        self.cycles_to_duration(self.cycles)
+    }
+
+    pub fn set_status_flag(&mut self, flag: Mos6502Flags) {
+        flag.set(&mut self.status);
+    }
+
+    pub fn clear_status_flag(&mut self, flag: Mos6502Flags) {
+        flag.clear(&mut self.status);
+    }
+
+    ////////// HELPER FUNCTIONS //////////
+
+    pub(crate) fn wake(&mut self) -> Option<StopReason> {
+        match self.state {
+            CpuState::Running => None,
+            CpuState::Jammed => Some(StopReason::Jammed),
+            CpuState::Stopped => Some(StopReason::Stopped),
+            // NOTE: WAI wakes up on IRQ even with I set, in which
+            // case execution just carries on after the WAI.
+            CpuState::Waiting if self.irq_line || self.nmi_pending => {
+                self.state = CpuState::Running;
+                None
+            }
+            CpuState::Waiting => Some(StopReason::Waiting),
+        }
+    }
+
+    fn next_tick_op(&mut self, irq_masked: bool) -> TickOp {
+        // NOTE: Outside of `tick()` the lines are looked at as they
+        // are now, there is no earlier cycle to have sampled them on.
+        let (irq, nmi) = self.line_sample.take().unwrap_or((self.irq_line, self.nmi_pending));
+        if self.wake().is_some() {
+            TickOp::Idle
+        } else if nmi && self.nmi_pending {
+            self.nmi_pending = false;
+            TickOp::Interrupt(true)
+        } else if irq && !irq_masked {
+            TickOp::Interrupt(false)
+        } else {
+            TickOp::Instruction
+        }
+    }
+
+    fn execute(&mut self) -> Result<(), ExecError> {
+        let pc: Word = self.pc;
+        let fetch: usize = self.tick_pos;
+        let opcode: Byte = self.fetch_next_byte();
+        self.mark_sync(fetch);
+        self.dispatch(pc, opcode)
+    }
+
+    // NOTE: Runs `opcode`, already fetched from `pc`, and counts it.
+    pub(crate) fn dispatch(&mut self, pc: Word, opcode: Byte) -> Result<(), ExecError> {
+        match (self.decode(opcode), self.config.unknown_opcode) {
+            (Some(instruction), _) => instruction(self),
+            (None, UnknownOpcodePolicy::Error) => {
+                return Err(ExecError { opcode, pc, cycles: self.cycles });
+            }
+            (None, UnknownOpcodePolicy::Nop) => self.nop_imp(),
+            (None, UnknownOpcodePolicy::Jam) => self.jam_imp(),
+        }
+        self.instructions += 1;
+        Ok(())
+    }
+
+    fn decode(&self, opcode: Byte) -> Option<fn(&mut Mos6502)> {
+        let opcode = usize::from(opcode);
+        match self.config.variant {
+            Mos6502Variant::Nmos6502 | Mos6502Variant::Mos6510 => match LOOKUP_NMOS[opcode] {
+                None if self.config.illegal_opcodes => LOOKUP_ILLEGAL[opcode],
+                instruction => instruction,
+            },
+            Mos6502Variant::Ricoh2A03 => LOOKUP_NMOS[opcode].or(LOOKUP_ILLEGAL[opcode]),
+            Mos6502Variant::Cmos65C02 => LOOKUP_CMOS[opcode],
+            Mos6502Variant::Rockwell65C02 => LOOKUP_R65C02[opcode],
+            Mos6502Variant::Wdc65C02S => LOOKUP_W65C02S[opcode],
+        }
+    }
+
+    fn cycle(&mut self) {
+        self.read_byte_at_addr(self.pc);
+    }
+
+    // True for the opcodes the NMOS part documents, the ones a
+    // 65C816 in emulation mode hands to its 6502 core.
+    pub(crate) fn is_documented(opcode: Byte) -> bool {
+        LOOKUP_NMOS[usize::from(opcode)].is_some() && opcode_info(Mos6502Variant::Nmos6502, opcode).0 != "JAM"
+    }
+
+    // NOTE: The part of a power on every core shares, the IRQ line
+    // is released and the counters start over.
+    pub(crate) fn cold_start(&mut self) {
+        self.irq_line = false;
+        self.cycle_debt = 0;
+        self.reset_counters();
+    }
+
+    pub(crate) fn count_instruction(&mut self) {
+        self.instructions += 1;
+    }
+
+    pub(crate) fn set_state(&mut self, state: CpuState) {
+        self.state = state;
+    }
+
+    // NOTE: Drops whatever `tick()` was in the middle of and any
+    // pending NMI, the part of a reset every core shares.
+    pub(crate) fn restart(&mut self) {
+        self.in_flight = None;
+        self.tick_log.clear();
+        self.line_sample = None;
+        self.nmi_pending = false;
+        self.state = CpuState::Running;
+    }
+
+    fn mark_sync(&mut self, fetch: usize) {
+        if let Some(bus) = self.tick_log.get_mut(fetch) {
+            bus.sync = true;
+        }
+    }
+
+    fn bus_cycle(&mut self, bank: Byte, addr: Word, data: Byte, read: bool) -> Access {
+        if self.in_flight.is_none() {
+            self.cycles += 1;
+            return Access::Live;
+        }
+        let pos: usize = self.tick_pos;
+        self.tick_pos += 1;
+        match pos.cmp(&self.tick_live) {
+            std::cmp::Ordering::Less => Access::Replayed(self.tick_log[pos].data),
+            std::cmp::Ordering::Equal => {
+                self.tick_log.push(BusCycle { bank, addr, data, read, sync: false });
+                self.cycles += 1;
+                Access::Live
+            }
+            std::cmp::Ordering::Greater => Access::Ahead,
+        }
+    }
+
+    fn stack_addr(&self) -> Word {
+        STACK_BASE | Word::from(self.sp)
+    }
+
+    fn push_byte(&mut self, data: Byte) {
+        self.write_byte_at_addr(self.stack_addr(), data);
+        self.sp = self.sp.wrapping_sub(1);
+    }
+
+    fn push_word(&mut self, data: Word) {
+        self.push_byte((data >> 8) as Byte);
+        self.push_byte((data & 0xFF) as Byte);
+    }
+
+    fn pull_byte(&mut self) -> Byte {
+        // NOTE: The cycle spent incrementing the stack pointer is
+        // left to the caller, it is shared between both bytes when
+        // pulling a word.
+        self.sp = self.sp.wrapping_add(1);
+        self.read_byte_at_addr(self.stack_addr())
+    }
+
+    fn pull_word(&mut self) -> Word {
+        let lo = Word::from(self.pull_byte());
+        let hi = Word::from(self.pull_byte());
+        (hi << 8) | lo
+    }
+
+    fn status_for_push(&self, brk: bool) -> Byte {
+        // NOTE: B and U only exist on the stack copy of the status
+        // register. U is always pushed as 1, B is 1 for PHP and BRK
+        // and 0 for IRQ and NMI.
+        let mut status = self.status | Mos6502Flags::U as Byte;
+        if brk {
+            Mos6502Flags::B.set(&mut status);
+        } else {
+            Mos6502Flags::B.clear(&mut status);
+        }
+        status
+    }
+
+    fn status_from_pull(&mut self, data: Byte) {
+        let kept = Mos6502Flags::B as Byte | Mos6502Flags::U as Byte;
+        self.status = (data & !kept) | (self.status & kept);
+    }
+
+    fn pc_assign_wcycle(&mut self, data: Word) {
+        self.pc = data;
+        self.cycle();
+    }
+
+    fn acc_assign_wcycle(&mut self, data: Byte) {
+        self.a = data;
+        self.cycle();
+    }
+
+    fn xreg_assign_wcycle(&mut self, data: Byte) {
+        self.x = data;
+        self.cycle();
+    }
+
+    fn yreg_assign_wcycle(&mut self, data: Byte) {
+        self.y = data;
+        self.cycle();
+    }
+
+    fn offset_byte_wcycle(&mut self, target: &mut Byte, offset: Byte) {
+        *target = target.wrapping_add(offset);
+        self.cycle();
+    }
+
+    fn program_counter(&mut self) {
+        self.pc = self.pc.wrapping_add(1);
+    }
+
+    fn read_byte_at_addr(&mut self, addr: Word) -> Byte {
+        // NOTE: This function is needed to retrieve a byte
+        // and not increment the program counter.
+        // NOTE: When dealing with zero page, this function
+        // is still being used even though the address is
+        // a `Word` and zero page address is a `Byte`. Just
+        // cast to a `Word`.
+        let data: Byte = self.peek_byte(addr);
+        match self.bus_cycle(0x00, addr, data, true) {
+            Access::Replayed(logged) => logged,
+            Access::Live | Access::Ahead => data,
+        }
+    }
+
+    fn write_byte_at_addr(&mut self, addr: Word, data: Byte) {
+        // NOTE: Writes to the 6510 port still reach the RAM
+        // underneath, only reads are intercepted.
+        if let Access::Live = self.bus_cycle(0x00, addr, data, false) {
+            if self.io_port_mapped(addr) {
+                self.io_port.write(addr, data);
+            }
+            *self.mem.at(usize::from(addr)) = data;
+        }
+    }
+
+    // NOTE: The 24-bit bus of the 65C816, the bank goes out on the
+    // top byte. No I/O port up there, it is plain memory.
+    pub(crate) fn read_long(&mut self, addr: u32) -> Byte {
+        let data: Byte = self.mem.get_byte(addr as usize);
+        let [_, bank, hi, lo] = addr.to_be_bytes();
+        match self.bus_cycle(bank, Word::from_be_bytes([hi, lo]), data, true) {
+            Access::Replayed(logged) => logged,
+            Access::Live | Access::Ahead => data,
+        }
+    }
+
+    pub(crate) fn write_long(&mut self, addr: u32, data: Byte) {
+        let [_, bank, hi, lo] = addr.to_be_bytes();
+        if let Access::Live = self.bus_cycle(bank, Word::from_be_bytes([hi, lo]), data, false) {
+            *self.mem.at(addr as usize) = data;
+        }
+    }
+
+    pub(crate) fn fetch_opcode_long(&mut self, addr: u32) -> Byte {
+        let fetch: usize = self.tick_pos;
+        let opcode: Byte = self.read_long(addr);
+        self.mark_sync(fetch);
+        opcode
+    }
+
+    fn peek_byte(&self, addr: Word) -> Byte {
+        // NOTE: Same as `read_byte_at_addr` but off the bus, no
+        // cycle is spent.
+        if self.io_port_mapped(addr) {
+            return self.io_port.read(addr);
+        }
+        self.mem.get_byte(usize::from(addr))
+    }
+
+    fn io_port_mapped(&self, addr: Word) -> bool {
+        self.config.variant == Mos6502Variant::Mos6510 && addr <= PORT_ADDR
+    }
+
+    fn read_modify_write(&mut self, addr: Word, op: fn(&mut Self, Byte) -> Byte) {
+        // NOTE: The NMOS 6502 writes the unmodified value back
+        // before writing the result, so both writes hit the bus.
+        if self.config.variant.is_cmos() {
+            self.read_modify_write_cmos(addr, op);
+            return;
+        }
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.write_byte_at_addr(addr, data);
+        let result: Byte = op(self, data);
+        self.write_byte_at_addr(addr, result);
+    }
+
+    fn read_modify_write_cmos(&mut self, addr: Word, op: fn(&mut Self, Byte) -> Byte) {
+        // NOTE: CMOS parts read the operand a second time instead,
+        // only the result is written.
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.read_byte_at_addr(addr);
+        let result: Byte = op(self, data);
+        self.write_byte_at_addr(addr, result);
+    }
+
+    fn fetch_next_byte(&mut self) -> Byte {
+        let b: Byte = self.read_byte_at_addr(self.pc);
+        self.program_counter();
+        b
+    }
+
+    fn fetch_word(&mut self) -> Word {
+        let lo = Word::from(self.fetch_next_byte());
+        let hi = Word::from(self.fetch_next_byte());
+        (hi << 8) | lo
+    }
+
+    fn read_word_at_zp_addr(&mut self, zpaddr: Byte) -> Word {
+        // NOTE: Zero page pointers wrap within the zero page, a
+        // pointer at $FF takes its high byte from $00.
+        let lo = Word::from(self.read_byte_at_addr(Word::from(zpaddr)));
+        let hi = Word::from(self.read_byte_at_addr(Word::from(zpaddr.wrapping_add(1))));
+        (hi << 8) | lo
+    }
+
+    fn read_word_at_abs_addr(&mut self, addr: Word) -> Word {
+        let lo = Word::from(self.read_byte_at_addr(addr));
+        let hi = Word::from(self.read_byte_at_addr(addr.wrapping_add(1)));
+        (hi << 8) | lo
+    }
+
+    ////////// INTERRUPT FUNCTIONS //////////
+
+    pub(crate) fn nmi_hijack(&mut self) -> bool {
+        // NOTE: Takes a pending NMI in the middle of an instruction.
+        // While ticking the answer is kept, so replaying the cycles
+        // before it makes the same choice, and cycles that have not
+        // happened yet are not allowed to take it.
+        let Some(flight) = self.in_flight else {
+            return std::mem::take(&mut self.nmi_pending);
+        };
+        if let Some(taken) = flight.nmi_hijack {
+            return taken;
+        }
+        if self.tick_pos > self.tick_live {
+            return self.nmi_pending;
+        }
+        let taken: bool = std::mem::take(&mut self.nmi_pending);
+        self.in_flight = Some(InFlight { nmi_hijack: Some(taken), ..flight });
+        taken
+    }
+
+    pub(crate) fn interrupt(&mut self, vector: Word) {
+        // NOTE: Same 7 cycles as BRK, the opcode and operand
+        // fetches are replaced with two dummy reads and B is
+        // pushed clear.
+        self.cycle();
+        self.cycle();
+        self.push_word(self.pc);
+        self.push_byte(self.status_for_push(false));
+        self.interrupt_set_status();
+        self.pc = self.read_word_at_abs_addr(vector);
+    }
+
+    fn interrupt_set_status(&mut self) {
+        // NOTE: CMOS parts also leave decimal mode on the way in.
+        Mos6502Flags::I.set(&mut self.status);
+        if self.config.variant.is_cmos() {
+            Mos6502Flags::D.clear(&mut self.status);
+        }
+    }
+
+    fn page_crossed(base: Word, addr: Word) -> bool {
+        base & 0xFF00 != addr & 0xFF00
+    }
+
+    ////////// ADDRESSING MODE FUNCTIONS //////////
+
+    // NOTE: These functions resolve the effective address of an
+    // operand and spend the cycles needed to do so. Indexed modes
+    // that can cross a page take `force_penalty`, which is set by
+    // stores and read-modify-write instructions since they always
+    // spend the extra cycle.
+
+    fn resolved(&mut self, addr: Word) -> Word {
+        self.effective_addr = Some(addr);
+        addr
+    }
+
+    fn addr_zp(&mut self) -> Word {
+        let zpaddr = Word::from(self.fetch_next_byte());
+        self.resolved(zpaddr)
+    }
+
+    fn addr_zpx(&mut self) -> Word {
+        let mut zpaddr: Byte = self.fetch_next_byte();
+        self.offset_byte_wcycle(&mut zpaddr, self.x);
+        self.resolved(Word::from(zpaddr))
+    }
+
+    fn addr_zpy(&mut self) -> Word {
+        let mut zpaddr: Byte = self.fetch_next_byte();
+        self.offset_byte_wcycle(&mut zpaddr, self.y);
+        self.resolved(Word::from(zpaddr))
+    }
+
+    fn addr_abs(&mut self) -> Word {
+        let addr: Word = self.fetch_word();
+        self.resolved(addr)
+    }
+
+    fn addr_absx(&mut self, force_penalty: bool) -> Word {
+        let base: Word = self.fetch_word();
+        let addr: Word = base.wrapping_add(Word::from(self.x));
+        if force_penalty || Self::page_crossed(base, addr) {
+            self.cycle();
+        }
+        self.resolved(addr)
+    }
+
+    fn addr_absy(&mut self, force_penalty: bool) -> Word {
+        let base: Word = self.fetch_word();
+        let addr: Word = base.wrapping_add(Word::from(self.y));
+        if force_penalty || Self::page_crossed(base, addr) {
+            self.cycle();
+        }
+        self.resolved(addr)
+    }
+
+    fn addr_zpx_ind(&mut self) -> Word {
+        let mut zpaddr: Byte = self.fetch_next_byte();
+        self.offset_byte_wcycle(&mut zpaddr, self.x);
+        let addr: Word = self.read_word_at_zp_addr(zpaddr);
+        self.resolved(addr)
+    }
+
+    fn addr_zpy_ind(&mut self, force_penalty: bool) -> Word {
+        let zpaddr: Byte = self.fetch_next_byte();
+        let base: Word = self.read_word_at_zp_addr(zpaddr);
+        let addr: Word = base.wrapping_add(Word::from(self.y));
+        if force_penalty || Self::page_crossed(base, addr) {
+            self.cycle();
+        }
+        self.resolved(addr)
+    }
+
+    fn addr_zp_ind(&mut self) -> Word {
+        let zpaddr: Byte = self.fetch_next_byte();
+        let addr: Word = self.read_word_at_zp_addr(zpaddr);
+        self.resolved(addr)
+    }
+
+    ////////// SET STATUS FUNCTIONS //////////
+
+    fn nz_set_status(&mut self, data: Byte) {
+        Mos6502Flags::Z.assign(&mut self.status, data == 0);
+        Mos6502Flags::N.assign(&mut self.status, data & (1u8 << 7) != 0);
+    }
+
+    fn lda_set_status(&mut self) {
+        self.nz_set_status(self.a);
+    }
+
+    fn ldx_set_status(&mut self) {
+        if self.x == 0 {
+            Mos6502Flags::Z.set(&mut self.status);
+        } else {
+            Mos6502Flags::Z.clear(&mut self.status);
+        }
+        if self.x & (1u8 << 7) == 0 {
+            Mos6502Flags::N.clear(&mut self.status);
+        } else {
+            Mos6502Flags::N.set(&mut self.status);
+        }
+    }
+
+    fn ldy_set_status(&mut self) {
+        if self.y == 0 {
+            Mos6502Flags::Z.set(&mut self.status);
+        } else {
+            Mos6502Flags::Z.clear(&mut self.status);
+        }
+        if self.y & (1u8 << 7) == 0 {
+            Mos6502Flags::N.clear(&mut self.status);
+        } else {
+            Mos6502Flags::N.set(&mut self.status);
+        }
+    }
+
+    ////////// ALU FUNCTIONS //////////
+
+    fn decimal_mode(&self) -> bool {
+        Mos6502Flags::D.get(self.status) && self.config.variant.has_decimal_mode()
+    }
+
+    pub(crate) fn add_with_carry(&mut self, data: Byte) {
+        if self.decimal_mode() {
+            self.add_with_carry_decimal(data);
+            self.decimal_set_status();
+        } else {
+            self.add_with_carry_binary(data);
+        }
+    }
+
+    fn add_with_carry_binary(&mut self, data: Byte) {
+        let carry = Word::from(Mos6502Flags::C.get(self.status));
+        let sum: Word = Word::from(self.a) + Word::from(data) + carry;
+        let result = (sum & 0xFF) as Byte;
+
+        Mos6502Flags::C.assign(&mut self.status, sum > 0xFF);
+        Mos6502Flags::V.assign(&mut self.status, !(self.a ^ data) & (self.a ^ result) & 0x80 != 0);
+        self.a = result;
+        self.lda_set_status();
+    }
+
+    // NOTE: NMOS decimal mode. The accumulator and carry hold the BCD
+    // result, but Z is taken from the binary sum and N/V from the
+    // intermediate value before the high nibble is adjusted.
+    fn add_with_carry_decimal(&mut self, data: Byte) {
+        let (a, m) = (Word::from(self.a), Word::from(data));
+        let carry = Word::from(Mos6502Flags::C.get(self.status));
+
+        let mut lo: Word = (a & 0x0F) + (m & 0x0F) + carry;
+        if lo > 0x09 {
+            lo += 0x06;
+        }
+        let mut sum: Word = (lo & 0x0F) + (a & 0xF0) + (m & 0xF0);
+        if lo > 0x0F {
+            sum += 0x10;
+        }
+
+        Mos6502Flags::Z.assign(&mut self.status, (a + m + carry) % 0x100 == 0);
+        Mos6502Flags::N.assign(&mut self.status, sum & 0x80 != 0);
+        Mos6502Flags::V.assign(&mut self.status, (a ^ sum) & 0x80 != 0 && (a ^ m) & 0x80 == 0);
+
+        if sum & 0x1F0 > 0x90 {
+            sum += 0x60;
+        }
+
+        Mos6502Flags::C.assign(&mut self.status, sum & 0xFF0 > 0xF0);
+        self.a = (sum & 0xFF) as Byte;
+    }
+
+    pub(crate) fn subtract_with_borrow(&mut self, data: Byte) {
+        if self.decimal_mode() {
+            self.subtract_with_borrow_decimal(data);
+            self.decimal_set_status();
+        } else {
+            self.add_with_carry_binary(!data);
+        }
+    }
+
+    // NOTE: NMOS decimal mode. Every flag comes from the binary
+    // subtraction, only the accumulator is BCD adjusted.
+    fn subtract_with_borrow_decimal(&mut self, data: Byte) {
+        let (a, m) = (Word::from(self.a), Word::from(data));
+        let borrow = Word::from(!Mos6502Flags::C.get(self.status));
+
+        let lo: Word = (a & 0x0F).wrapping_sub(m & 0x0F).wrapping_sub(borrow);
+        let mut diff: Word = if lo & 0x10 == 0 {
+            (lo & 0x0F) | (a & 0xF0).wrapping_sub(m & 0xF0)
+        } else {
+            (lo.wrapping_sub(0x06) & 0x0F) | (a & 0xF0).wrapping_sub(m & 0xF0).wrapping_sub(0x10)
+        };
+        if diff & 0x100 != 0 {
+            diff = diff.wrapping_sub(0x60);
+        }
+
+        self.add_with_carry_binary(!data);
+        self.a = (diff & 0xFF) as Byte;
+    }
+
+    fn decimal_set_status(&mut self) {
+        // NOTE: CMOS parts spend an extra cycle in decimal mode to
+        // take N and Z from the BCD result.
+        if self.config.variant.is_cmos() {
+            self.lda_set_status();
+            self.cycle();
+        }
+    }
+
+    fn shift_left(&mut self, data: Byte) -> Byte {
+        let result: Byte = data << 1;
+        Mos6502Flags::C.assign(&mut self.status, data & 0x80 != 0);
+        self.nz_set_status(result);
+        result
+    }
+
+    fn shift_right(&mut self, data: Byte) -> Byte {
+        let result: Byte = data >> 1;
+        Mos6502Flags::C.assign(&mut self.status, data & 0x01 != 0);
+        self.nz_set_status(result);
+        result
+    }
+
+    fn rotate_left(&mut self, data: Byte) -> Byte {
+        let result: Byte = (data << 1) | Byte::from(Mos6502Flags::C.get(self.status));
+        Mos6502Flags::C.assign(&mut self.status, data & 0x80 != 0);
+        self.nz_set_status(result);
+        result
+    }
+
+    fn rotate_right(&mut self, data: Byte) -> Byte {
+        let result: Byte = (data >> 1) | (Byte::from(Mos6502Flags::C.get(self.status)) << 7);
+        Mos6502Flags::C.assign(&mut self.status, data & 0x01 != 0);
+        self.nz_set_status(result);
+        result
+    }
+
+    fn increment(&mut self, data: Byte) -> Byte {
+        let result: Byte = data.wrapping_add(1);
+        self.nz_set_status(result);
+        result
+    }
+
+    fn decrement(&mut self, data: Byte) -> Byte {
+        let result: Byte = data.wrapping_sub(1);
+        self.nz_set_status(result);
+        result
+    }
+
+    fn compare(&mut self, reg: Byte, data: Byte) {
+        Mos6502Flags::C.assign(&mut self.status, reg >= data);
+        self.nz_set_status(reg.wrapping_sub(data));
+    }
+
+    fn bit_test(&mut self, data: Byte) {
+        Mos6502Flags::Z.assign(&mut self.status, self.a & data == 0);
+        Mos6502Flags::N.assign(&mut self.status, data & 0x80 != 0);
+        Mos6502Flags::V.assign(&mut self.status, data & 0x40 != 0);
+    }
+
+    fn test_and_reset_bits(&mut self, data: Byte) -> Byte {
+        Mos6502Flags::Z.assign(&mut self.status, self.a & data == 0);
+        data & !self.a
+    }
+
+    fn test_and_set_bits(&mut self, data: Byte) -> Byte {
+        Mos6502Flags::Z.assign(&mut self.status, self.a & data == 0);
+        data | self.a
+    }
+
+    fn shift_left_or(&mut self, data: Byte) -> Byte {
+        let result: Byte = self.shift_left(data);
+        self.a |= result;
+        self.lda_set_status();
+        result
+    }
+
+    fn rotate_left_and(&mut self, data: Byte) -> Byte {
+        let result: Byte = self.rotate_left(data);
+        self.a &= result;
+        self.lda_set_status();
+        result
+    }
+
+    fn shift_right_eor(&mut self, data: Byte) -> Byte {
+        let result: Byte = self.shift_right(data);
+        self.a ^= result;
+        self.lda_set_status();
+        result
+    }
+
+    fn rotate_right_add(&mut self, data: Byte) -> Byte {
+        let result: Byte = self.rotate_right(data);
+        self.add_with_carry(result);
+        result
+    }
+
+    fn decrement_compare(&mut self, data: Byte) -> Byte {
+        let result: Byte = data.wrapping_sub(1);
+        self.compare(self.a, result);
+        result
+    }
+
+    fn increment_subtract(&mut self, data: Byte) -> Byte {
+        let result: Byte = data.wrapping_add(1);
+        self.subtract_with_borrow(result);
+        result
+    }
+
+    fn and_rotate_right(&mut self, data: Byte) -> Byte {
+        // NOTE: ARR runs the AND through the adder's ROR path, so C
+        // and V are taken from bits 6 and 5 of the result. Decimal
+        // mode applies its own nibble fix-ups on top.
+        let and: Byte = self.a & data;
+        let carry = Byte::from(Mos6502Flags::C.get(self.status));
+        let mut result: Byte = (and >> 1) | (carry << 7);
+
+        if !self.decimal_mode() {
+            self.nz_set_status(result);
+            Mos6502Flags::C.assign(&mut self.status, result & 0x40 != 0);
+            Mos6502Flags::V.assign(&mut self.status, (result ^ (result << 1)) & 0x40 != 0);
+            return result;
+        }
+
+        Mos6502Flags::N.assign(&mut self.status, carry != 0);
+        Mos6502Flags::Z.assign(&mut self.status, result == 0);
+        Mos6502Flags::V.assign(&mut self.status, (and ^ result) & 0x40 != 0);
+
+        let (hi, lo) = (and >> 4, and & 0x0F);
+        if lo + (lo & 0x01) > 0x05 {
+            result = (result & 0xF0) | (result.wrapping_add(0x06) & 0x0F);
+        }
+        let carry_out = hi + (hi & 0x01) > 0x05;
+        if carry_out {
+            result = result.wrapping_add(0x60);
+        }
+        Mos6502Flags::C.assign(&mut self.status, carry_out);
+        result
+    }
+
+    fn branch_if(&mut self, cond: bool) {
+        // NOTE: The offset is relative to the address of the next
+        // instruction. Taking the branch costs a cycle, and landing
+        // on another page costs one more.
+        let offset = i8::from_le_bytes([self.fetch_next_byte()]);
+        let target: Word = self.resolved(self.pc.wrapping_add_signed(i16::from(offset)));
+        if cond {
+            self.cycle();
+            if Self::page_crossed(self.pc, target) {
+                self.cycle();
+            }
+            self.pc = target;
+        }
+    }
+
+    ////////// CPU INSTRUCTION FUNCTIONS //////////
+
+    ///// LDA /////
+
+    fn lda(&mut self, addr: Word) {
+        self.a = self.read_byte_at_addr(addr);
+        self.lda_set_status();
+    }
+
+    fn lda_imm(&mut self) {
+        self.a = self.fetch_next_byte();
+        self.lda_set_status();
+    }
+
+    fn lda_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.lda(addr);
+    }
+
+    fn lda_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.lda(addr);
+    }
+
+    fn lda_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.lda(addr);
+    }
+
+    fn lda_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.lda(addr);
+    }
+
+    fn lda_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.lda(addr);
+    }
+
+    fn lda_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.lda(addr);
+    }
+
+    fn lda_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(false);
+        self.lda(addr);
+    }
+
+    ///// ADC /////
+
+    fn adc(&mut self, addr: Word) {
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.add_with_carry(data);
+    }
+
+    ///// SBC /////
+
+    fn sbc(&mut self, addr: Word) {
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.subtract_with_borrow(data);
+    }
+
+    ///// ASL, LSR, ROL, ROR /////
+
+    fn asl(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::shift_left);
+    }
+
+    fn lsr(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::shift_right);
+    }
+
+    fn rol(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::rotate_left);
+    }
+
+    fn ror(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::rotate_right);
+    }
+
+    ///// CMP, CPX, CPY /////
+
+    fn cmp(&mut self, addr: Word) {
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.compare(self.a, data);
+    }
+
+    fn cpx(&mut self, addr: Word) {
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.compare(self.x, data);
+    }
+
+    fn cpy(&mut self, addr: Word) {
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.compare(self.y, data);
+    }
+
+    ///// BIT /////
+
+    fn bit(&mut self, addr: Word) {
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.bit_test(data);
+    }
+
+    ///// INC, DEC /////
+
+    fn inc(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::increment);
+    }
+
+    fn dec(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::decrement);
+    }
+
+    ///// AND, ORA, EOR /////
+
+    fn and(&mut self, addr: Word) {
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.a &= data;
+        self.lda_set_status();
+    }
+
+    fn ora(&mut self, addr: Word) {
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.a |= data;
+        self.lda_set_status();
+    }
+
+    fn eor(&mut self, addr: Word) {
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.a ^= data;
+        self.lda_set_status();
+    }
+
+    ///// LDX /////
+
+    fn ldx(&mut self, addr: Word) {
+        self.x = self.read_byte_at_addr(addr);
+        self.ldx_set_status();
+    }
+
+    fn ldx_imm(&mut self) {
+        self.x = self.fetch_next_byte();
+        self.ldx_set_status();
+    }
+
+    fn ldx_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.ldx(addr);
+    }
+
+    fn ldx_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.ldx(addr);
+    }
+
+    fn ldx_zpy(&mut self) {
+        let addr: Word = self.addr_zpy();
+        self.ldx(addr);
+    }
+
+    fn ldx_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.ldx(addr);
+    }
+
+    ///// LDY /////
+
+    fn ldy(&mut self, addr: Word) {
+        self.y = self.read_byte_at_addr(addr);
+        self.ldy_set_status();
+    }
+
+    fn ldy_imm(&mut self) {
+        self.y = self.fetch_next_byte();
+        self.ldy_set_status();
+    }
+
+    fn ldy_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.ldy(addr);
+    }
+
+    fn ldy_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.ldy(addr);
+    }
+
+    fn ldy_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.ldy(addr);
+    }
+
+    fn ldy_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.ldy(addr);
+    }
+
+    ///// STA, STX, STY /////
+
+    // NOTE: Indexed stores always spend the page crossing cycle,
+    // a write to the address before the high byte is fixed up
+    // could not be taken back.
+
+    fn sta(&mut self, addr: Word) {
+        self.write_byte_at_addr(addr, self.a);
+    }
+
+    fn stx(&mut self, addr: Word) {
+        self.write_byte_at_addr(addr, self.x);
+    }
+
+    fn sty(&mut self, addr: Word) {
+        self.write_byte_at_addr(addr, self.y);
+    }
+
+    fn asl_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.asl(addr);
+    }
+
+    fn asl_acc(&mut self) {
+        self.a = self.shift_left(self.a);
+        self.cycle();
+    }
+
+    fn asl_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.asl(addr);
+    }
+
+    fn asl_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.asl(addr);
+    }
+
+    fn asl_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.asl(addr);
+    }
+
+    fn and_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.and(addr);
+    }
+
+    fn and_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.and(addr);
+    }
+
+    fn and_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.and(addr);
+    }
+
+    fn and_imm(&mut self) {
+        self.a &= self.fetch_next_byte();
+        self.lda_set_status();
+    }
+
+    fn and_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(false);
+        self.and(addr);
+    }
+
+    fn and_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.and(addr);
+    }
+
+    fn and_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.and(addr);
+    }
+
+    fn and_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.and(addr);
+    }
+
+    fn ora_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.ora(addr);
+    }
+
+    fn ora_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.ora(addr);
+    }
+
+    fn ora_imm(&mut self) {
+        self.a |= self.fetch_next_byte();
+        self.lda_set_status();
+    }
+
+    fn ora_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.ora(addr);
+    }
+
+    fn ora_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(false);
+        self.ora(addr);
+    }
+
+    fn ora_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.ora(addr);
+    }
+
+    fn ora_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.ora(addr);
+    }
+
+    fn ora_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.ora(addr);
+    }
+
+    fn brk_imp(&mut self) {
+        // NOTE: BRK is two bytes long, the byte after the opcode
+        // is skipped. An NMI that arrives before the vector is
+        // fetched hijacks it, the NMI handler runs with B set on
+        // the stack and the BRK handler never runs. Only `tick()`
+        // can get an NMI in that late.
+        self.fetch_next_byte();
+        self.push_word(self.pc);
+        self.push_byte(self.status_for_push(true));
+        self.interrupt_set_status();
+        let vector: Word = if self.nmi_hijack() { NMI_VECTOR } else { IRQ_VECTOR };
+        self.pc = self.read_word_at_abs_addr(vector);
+    }
+
+    fn php_imp(&mut self) {
+        self.cycle();
+        self.push_byte(self.status_for_push(true));
+    }
+
+    fn bpl_rel(&mut self) {
+        self.branch_if(!Mos6502Flags::N.get(self.status));
+    }
+
+    fn clc_imp(&mut self) {
+        Mos6502Flags::C.clear(&mut self.status);
+        self.cycle();
+    }
+
+    fn jsr_abs(&mut self) {
+        // NOTE: The high byte of the target is fetched after the
+        // return address has been pushed, so the pushed address
+        // points at the last byte of the JSR instruction.
+        let lo = Word::from(self.fetch_next_byte());
+        self.cycle();
+        self.push_word(self.pc);
+        let hi = Word::from(self.fetch_next_byte());
+        self.pc = self.resolved((hi << 8) | lo);
+    }
+
+    fn bit_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.bit(addr);
+    }
+
+    fn rol_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.rol(addr);
+    }
+
+    fn plp_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let data: Byte = self.pull_byte();
+        self.status_from_pull(data);
+    }
+
+    fn rol_acc(&mut self) {
+        self.a = self.rotate_left(self.a);
+        self.cycle();
+    }
+
+    fn bit_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.bit(addr);
+    }
+
+    fn rol_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.rol(addr);
+    }
+
+    fn bmi_rel(&mut self) {
+        self.branch_if(Mos6502Flags::N.get(self.status));
+    }
+
+    fn rol_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.rol(addr);
+    }
+
+    fn sec_imp(&mut self) {
+        Mos6502Flags::C.set(&mut self.status);
+        self.cycle();
+    }
+
+    fn rol_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.rol(addr);
+    }
+
+    fn rti_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let data: Byte = self.pull_byte();
+        self.status_from_pull(data);
+        self.pc = self.pull_word();
+    }
+
+    fn eor_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.eor(addr);
+    }
+
+    fn eor_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.eor(addr);
+    }
+
+    fn lsr_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.lsr(addr);
+    }
+
+    fn pha_imp(&mut self) {
+        self.cycle();
+        self.push_byte(self.a);
+    }
+
+    fn eor_imm(&mut self) {
+        self.a ^= self.fetch_next_byte();
+        self.lda_set_status();
+    }
+
+    fn lsr_acc(&mut self) {
+        self.a = self.shift_right(self.a);
+        self.cycle();
+    }
+
+    fn jmp_abs(&mut self) {
+        self.pc = self.addr_abs();
+    }
+
+    fn eor_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.eor(addr);
+    }
+
+    fn lsr_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.lsr(addr);
+    }
+
+    fn bvc_rel(&mut self) {
+        self.branch_if(!Mos6502Flags::V.get(self.status));
+    }
+
+    fn eor_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(false);
+        self.eor(addr);
+    }
+
+    fn eor_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.eor(addr);
+    }
+
+    fn lsr_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.lsr(addr);
+    }
+
+    fn cli_imp(&mut self) {
+        Mos6502Flags::I.clear(&mut self.status);
+        self.cycle();
+    }
+
+    fn eor_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.eor(addr);
+    }
+
+    fn eor_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.eor(addr);
+    }
+
+    fn lsr_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.lsr(addr);
+    }
+
+    fn rts_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let addr: Word = self.pull_word();
+        self.pc_assign_wcycle(addr.wrapping_add(1));
+    }
+
+    fn adc_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.adc(addr);
+    }
 
-    fn fetch_word(&mut self) -> Word {
-        let lo = u16::from(self.mem.get_byte(self.pc as usize));
-        self.program_counter();
+    fn adc_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.adc(addr);
+    }
+
+    fn ror_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.ror(addr);
+    }
+
+    fn pla_imp(&mut self) {
+        self.cycle();
         self.cycle();
+        self.a = self.pull_byte();
+        self.lda_set_status();
+    }
 
-        let hi = u16::from(self.mem.get_byte(self.pc as usize));
-        self.program_counter();
+    fn adc_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        self.add_with_carry(data);
+    }
+
+    fn ror_acc(&mut self) {
+        self.a = self.rotate_right(self.a);
         self.cycle();
+    }
 
-        (hi << 8) | lo
+    fn jmp_abs_ind(&mut self) {
+        let ptr: Word = self.fetch_word();
+        self.pc = if self.config.jmp_indirect_bug {
+            // NOTE: The pointer's low byte is incremented without a
+            // carry into the high byte.
+            let lo = Word::from(self.read_byte_at_addr(ptr));
+            let hi = Word::from(self.read_byte_at_addr((ptr & 0xFF00) | (ptr.wrapping_add(1) & 0x00FF)));
+            (hi << 8) | lo
+        } else {
+            self.read_word_at_abs_addr(ptr)
+        };
+        self.resolved(self.pc);
     }
 
-    fn read_word_at_addr(&mut self, addr: Word) -> Word {
-        let lo = Word::from(self.read_byte_at_addr(addr));
-        let hi = Word::from(self.read_byte_at_addr((addr + 1) % 256));
-        (hi << 8) | lo
+    fn adc_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.adc(addr);
     }
 
-    ////////// SET STATUS FUNCTIONS //////////
+    fn ror_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.ror(addr);
+    }
 
-    fn lda_set_status(&mut self) {
-        if self.a == 0 {
-            Mos6502Flags::Z.set(&mut self.status);
-        } else {
-            Mos6502Flags::Z.clear(&mut self.status);
-        }
-        if self.a & (1u8 << 7) == 0 {
-            Mos6502Flags::N.clear(&mut self.status);
-        } else {
-            Mos6502Flags::N.set(&mut self.status);
-        }
+    fn bvs_rel(&mut self) {
+        self.branch_if(Mos6502Flags::V.get(self.status));
     }
 
-    fn ldx_set_status(&mut self) {
-        if self.x == 0 {
-            Mos6502Flags::Z.set(&mut self.status);
-        } else {
-            Mos6502Flags::Z.clear(&mut self.status);
-        }
-        if self.x & (1u8 << 7) == 0 {
-            Mos6502Flags::N.clear(&mut self.status);
-        } else {
-            Mos6502Flags::N.set(&mut self.status);
-        }
+    fn adc_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(false);
+        self.adc(addr);
     }
 
-    fn ldy_set_status(&mut self) {
-        if self.y == 0 {
-            Mos6502Flags::Z.set(&mut self.status);
-        } else {
-            Mos6502Flags::Z.clear(&mut self.status);
-        }
-        if self.y & (1u8 << 7) == 0 {
-            Mos6502Flags::N.clear(&mut self.status);
-        } else {
-            Mos6502Flags::N.set(&mut self.status);
-        }
+    fn adc_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.adc(addr);
+    }
+
+    fn ror_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.ror(addr);
+    }
+
+    fn sei_imp(&mut self) {
+        Mos6502Flags::I.set(&mut self.status);
+        self.cycle();
+    }
+
+    fn adc_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.adc(addr);
+    }
+
+    fn adc_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.adc(addr);
+    }
+
+    fn ror_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.ror(addr);
+    }
+
+    fn sta_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.sta(addr);
+    }
+
+    fn sty_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.sty(addr);
+    }
+
+    fn sta_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.sta(addr);
+    }
+
+    fn stx_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.stx(addr);
+    }
+
+    fn dey_imp(&mut self) {
+        self.y = self.decrement(self.y);
+        self.cycle();
+    }
+
+    fn txa_imp(&mut self) {
+        self.acc_assign_wcycle(self.x);
+        self.lda_set_status();
+    }
+
+    fn sty_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.sty(addr);
+    }
+
+    fn sta_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.sta(addr);
+    }
+
+    fn stx_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.stx(addr);
+    }
+
+    fn bcc_rel(&mut self) {
+        self.branch_if(!Mos6502Flags::C.get(self.status));
+    }
+
+    fn sta_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(true);
+        self.sta(addr);
+    }
+
+    fn sty_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.sty(addr);
+    }
+
+    fn sta_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.sta(addr);
+    }
+
+    fn stx_zpy(&mut self) {
+        let addr: Word = self.addr_zpy();
+        self.stx(addr);
+    }
+
+    fn tya_imp(&mut self) {
+        self.acc_assign_wcycle(self.y);
+        self.lda_set_status();
+    }
+
+    fn sta_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.sta(addr);
+    }
+
+    fn txs_imp(&mut self) {
+        self.sp = self.x;
+        self.cycle();
+    }
+
+    fn sta_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.sta(addr);
+    }
+
+    fn tay_imp(&mut self) {
+        self.yreg_assign_wcycle(self.a);
+        self.ldy_set_status();
+    }
+
+    fn tax_imp(&mut self) {
+        self.xreg_assign_wcycle(self.a);
+        self.ldx_set_status();
+    }
+
+    fn bcs_rel(&mut self) {
+        self.branch_if(Mos6502Flags::C.get(self.status));
+    }
+
+    fn clv_imp(&mut self) {
+        Mos6502Flags::V.clear(&mut self.status);
+        self.cycle();
+    }
+
+    fn tsx_imp(&mut self) {
+        self.xreg_assign_wcycle(self.sp);
+        self.ldx_set_status();
+    }
+
+    fn cpy_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        self.compare(self.y, data);
+    }
+
+    fn cmp_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.cmp(addr);
+    }
+
+    fn cpy_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.cpy(addr);
+    }
+
+    fn cmp_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.cmp(addr);
+    }
+
+    fn dec_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.dec(addr);
+    }
+
+    fn iny_imp(&mut self) {
+        self.y = self.increment(self.y);
+        self.cycle();
+    }
+
+    fn cmp_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        self.compare(self.a, data);
+    }
+
+    fn dex_imp(&mut self) {
+        self.x = self.decrement(self.x);
+        self.cycle();
+    }
+
+    fn cpy_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.cpy(addr);
+    }
+
+    fn cmp_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.cmp(addr);
+    }
+
+    fn dec_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.dec(addr);
+    }
+
+    fn bne_rel(&mut self) {
+        self.branch_if(!Mos6502Flags::Z.get(self.status));
+    }
+
+    fn cmp_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(false);
+        self.cmp(addr);
+    }
+
+    fn cmp_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.cmp(addr);
+    }
+
+    fn dec_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.dec(addr);
+    }
+
+    fn cld_imp(&mut self) {
+        Mos6502Flags::D.clear(&mut self.status);
+        self.cycle();
+    }
+
+    fn cmp_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.cmp(addr);
+    }
+
+    fn cmp_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.cmp(addr);
+    }
+
+    fn dec_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.dec(addr);
+    }
+
+    fn cpx_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        self.compare(self.x, data);
+    }
+
+    fn sbc_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.sbc(addr);
+    }
+
+    fn cpx_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.cpx(addr);
+    }
+
+    fn sbc_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.sbc(addr);
+    }
+
+    fn inc_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.inc(addr);
+    }
+
+    fn inx_imp(&mut self) {
+        self.x = self.increment(self.x);
+        self.cycle();
+    }
+
+    fn sbc_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        self.subtract_with_borrow(data);
+    }
+
+    fn nop_imp(&mut self) {
+        self.cycle();
+    }
+
+    fn cpx_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.cpx(addr);
+    }
+
+    fn sbc_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.sbc(addr);
+    }
+
+    fn inc_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.inc(addr);
+    }
+
+    fn beq_rel(&mut self) {
+        self.branch_if(Mos6502Flags::Z.get(self.status));
+    }
+
+    fn sbc_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(false);
+        self.sbc(addr);
     }
 
-    ////////// CPU INSTRUCTION FUNCTIONS //////////
+    fn sbc_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.sbc(addr);
+    }
 
-    ///// LDA /////
+    fn inc_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.inc(addr);
+    }
 
-    fn lda_imm(&mut self) {
-        self.a = self.fetch_next_byte();
-        self.lda_set_status();
+    fn sed_imp(&mut self) {
+        Mos6502Flags::D.set(&mut self.status);
+        self.cycle();
     }
 
-    fn lda_zpx_ind(&mut self) {
-        let mut zpaddr: Byte = self.fetch_next_byte();
-        self.offset_byte_wcycle(&mut zpaddr, self.x);
-        let reladdr: Word = self.read_word_at_addr(Word::from(zpaddr));
-        self.a = self.read_byte_at_addr(reladdr);
-        self.lda_set_status();
+    fn sbc_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.sbc(addr);
     }
 
-    fn lda_zp(&mut self) {
-        let zpaddr: Byte = self.fetch_next_byte();
-        self.a = self.read_byte_at_addr(Word::from(zpaddr));
-        self.lda_set_status();
+    fn sbc_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.sbc(addr);
     }
 
-    fn lda_zpx(&mut self) {
-        let mut zpaddr: Byte = self.fetch_next_byte();
-        self.offset_byte_wcycle(&mut zpaddr, self.x);
-        self.a = self.read_byte_at_addr(zpaddr.into());
-        self.lda_set_status();
+    fn inc_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.inc(addr);
     }
 
-    fn lda_absy(&mut self) {
-        let mut abs_addr: Word = self.fetch_word();
+    ////////// UNDOCUMENTED INSTRUCTION FUNCTIONS //////////
 
-        self.offset_word_wocycle(&mut abs_addr, self.y);
-        self.a = self.read_byte_at_addr(abs_addr);
+    fn slo(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::shift_left_or);
+    }
 
-        if (abs_addr % 256) + Word::from(self.y) > 0xFE {
-            self.cycle();
-        }
+    fn rla(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::rotate_left_and);
+    }
 
-        self.lda_set_status();
+    fn sre(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::shift_right_eor);
     }
 
-    fn lda_absx(&mut self) {
-        let mut abs_addr: Word = self.fetch_word();
+    fn rra(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::rotate_right_add);
+    }
 
-        self.offset_word_wocycle(&mut abs_addr, self.x);
-        self.a = self.read_byte_at_addr(abs_addr);
+    fn dcp(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::decrement_compare);
+    }
 
-        if (abs_addr % 256) + Word::from(self.x) > 0xFE {
-            self.cycle();
-        }
+    fn isc(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::increment_subtract);
+    }
 
-        self.lda_set_status();
+    fn sax(&mut self, addr: Word) {
+        self.write_byte_at_addr(addr, self.a & self.x);
     }
 
-    fn lda_abs(&mut self) {
-        let abs_addr: Word = self.fetch_word();
-        self.a = self.read_byte_at_addr(abs_addr);
+    fn lax(&mut self, addr: Word) {
+        self.a = self.read_byte_at_addr(addr);
+        self.x = self.a;
         self.lda_set_status();
     }
 
-    fn lda_zpy_ind(&mut self) {
-        let zpaddr: Byte = self.fetch_next_byte();
-        let mut reladdr: Word = self.read_word_at_addr(Word::from(zpaddr));
+    fn nop(&mut self, addr: Word) {
+        self.read_byte_at_addr(addr);
+    }
 
-        self.offset_word_wocycle(&mut reladdr, self.y);
-        self.a = self.read_byte_at_addr(reladdr);
+    fn store_and_high(&mut self, addr: Word, index: Byte, data: Byte) {
+        // NOTE: The value is ANDed with the high byte of the base
+        // address + 1. If indexing crossed a page the high byte of
+        // the target never gets fixed up, and the value written
+        // ends up on the address bus in its place.
+        let base: Word = addr.wrapping_sub(Word::from(index));
+        let data: Byte = data & ((base >> 8) as Byte).wrapping_add(1);
+        let addr: Word = if Self::page_crossed(base, addr) {
+            (Word::from(data) << 8) | (addr & 0x00FF)
+        } else {
+            addr
+        };
+        self.write_byte_at_addr(addr, data);
+    }
 
-        if (reladdr % 256) + Word::from(self.y) > 0xFE {
-            self.cycle();
-        }
+    fn slo_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.slo(addr);
+    }
 
-        self.lda_set_status();
+    fn slo_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.slo(addr);
     }
 
-    ///// LDX /////
+    fn slo_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.slo(addr);
+    }
 
-    fn ldx_imm(&mut self) {
-        self.x = self.fetch_next_byte();
-        self.ldx_set_status();
+    fn slo_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.slo(addr);
     }
 
-    fn ldx_zp(&mut self) {
-        let zpaddr: Byte = self.fetch_next_byte();
-        self.x = self.read_byte_at_addr(Word::from(zpaddr));
-        self.lda_set_status();
+    fn slo_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.slo(addr);
     }
 
-    fn ldx_abs(&mut self) {
-        let abs_addr: Word = self.fetch_word();
-        self.x = self.read_byte_at_addr(abs_addr);
-        self.ldx_set_status();
+    fn slo_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.slo(addr);
     }
 
-    fn ldx_zpy(&mut self) {
-        todo!()
+    fn slo_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(true);
+        self.slo(addr);
     }
 
-    fn ldx_absy(&mut self) {
-        let mut abs_addr: Word = self.fetch_word();
+    fn rla_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.rla(addr);
+    }
 
-        self.offset_word_wocycle(&mut abs_addr, self.y);
-        self.x = self.read_byte_at_addr(abs_addr);
+    fn rla_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.rla(addr);
+    }
 
-        if (abs_addr % 256) + Word::from(self.y) > 0xFE {
-            self.cycle();
-        }
+    fn rla_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.rla(addr);
+    }
 
-        self.ldx_set_status();
+    fn rla_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.rla(addr);
     }
 
-    ///// LDY /////
+    fn rla_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.rla(addr);
+    }
 
-    fn ldy_imm(&mut self) {
-        todo!()
+    fn rla_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.rla(addr);
     }
 
-    fn ldy_zp(&mut self) {
-        todo!()
+    fn rla_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(true);
+        self.rla(addr);
     }
 
-    fn ldy_abs(&mut self) {
-        todo!()
+    fn sre_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.sre(addr);
     }
 
-    fn ldy_zpx(&mut self) {
-        todo!()
+    fn sre_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.sre(addr);
     }
 
-    fn ldy_absx(&mut self) {
-        todo!()
+    fn sre_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.sre(addr);
     }
 
-    fn asl_zp(&mut self) {
-        todo!()
+    fn sre_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.sre(addr);
     }
 
-    fn asl_acc(&mut self) {
-        todo!()
+    fn sre_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.sre(addr);
     }
 
-    fn asl_abs(&mut self) {
-        todo!()
+    fn sre_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.sre(addr);
     }
 
-    fn asl_zpx(&mut self) {
-        todo!()
+    fn sre_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(true);
+        self.sre(addr);
     }
 
-    fn asl_absx(&mut self) {
-        todo!()
+    fn rra_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.rra(addr);
     }
 
-    fn and_zpx_ind(&mut self) {
-        todo!()
+    fn rra_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.rra(addr);
     }
 
-    fn and_zp(&mut self) {
-        todo!()
+    fn rra_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.rra(addr);
     }
 
-    fn and_abs(&mut self) {
-        todo!()
+    fn rra_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.rra(addr);
     }
 
-    fn and_imm(&mut self) {
-        todo!()
+    fn rra_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.rra(addr);
     }
 
-    fn and_zpy_ind(&mut self) {
-        todo!()
+    fn rra_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.rra(addr);
     }
 
-    fn and_zpx(&mut self) {
-        todo!()
+    fn rra_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(true);
+        self.rra(addr);
     }
 
-    fn and_absy(&mut self) {
-        todo!()
+    fn dcp_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.dcp(addr);
     }
 
-    fn and_absx(&mut self) {
-        todo!()
+    fn dcp_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.dcp(addr);
     }
 
-    fn ora_zpx_ind(&mut self) {
-        todo!()
+    fn dcp_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.dcp(addr);
     }
 
-    fn ora_zp(&mut self) {
-        todo!()
+    fn dcp_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.dcp(addr);
     }
 
-    fn ora_imm(&mut self) {
-        todo!()
+    fn dcp_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.dcp(addr);
     }
 
-    fn ora_abs(&mut self) {
-        todo!()
+    fn dcp_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.dcp(addr);
     }
 
-    fn ora_zpy_ind(&mut self) {
-        todo!()
+    fn dcp_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(true);
+        self.dcp(addr);
     }
 
-    fn ora_zpx(&mut self) {
-        todo!()
+    fn isc_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.isc(addr);
     }
 
-    fn ora_absy(&mut self) {
-        todo!()
+    fn isc_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.isc(addr);
     }
 
-    fn ora_absx(&mut self) {
-        todo!()
+    fn isc_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.isc(addr);
     }
 
-    fn brk_imp(&mut self) {
-        todo!()
+    fn isc_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.isc(addr);
     }
 
-    fn php_imp(&mut self) {
-        todo!()
+    fn isc_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.isc(addr);
     }
 
-    fn bpl_rel(&mut self) {
-        todo!()
+    fn isc_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.isc(addr);
     }
 
-    fn clc_imp(&mut self) {
-        Mos6502Flags::C.clear(&mut self.status);
-        todo!("cycles");
+    fn isc_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(true);
+        self.isc(addr);
     }
 
-    fn jsr_abs(&mut self) {
-        let addr = self.fetch_word();
-        if self.pc == 0x00 {
-            self.push_word(usize::from(self.pc), Word::MAX);
-        } else {
-            self.push_word(usize::from(self.pc), self.pc - 1);
-        }
-        self.pc_assign_wcycle(addr);
+    fn sax_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.sax(addr);
     }
 
-    fn bit_zp(&mut self) {
-        todo!()
+    fn sax_zpy(&mut self) {
+        let addr: Word = self.addr_zpy();
+        self.sax(addr);
     }
 
-    fn rol_zp(&mut self) {
-        todo!()
+    fn sax_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.sax(addr);
     }
 
-    fn plp_imp(&mut self) {
-        todo!()
+    fn sax_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.sax(addr);
     }
 
-    fn rol_acc(&mut self) {
-        todo!()
+    fn lax_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.lax(addr);
     }
 
-    fn bit_abs(&mut self) {
-        todo!()
+    fn lax_zpy(&mut self) {
+        let addr: Word = self.addr_zpy();
+        self.lax(addr);
     }
 
-    fn rol_abs(&mut self) {
-        todo!()
+    fn lax_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.lax(addr);
     }
 
-    fn bmi_rel(&mut self) {
-        todo!()
+    fn lax_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.lax(addr);
     }
 
-    fn rol_zpx(&mut self) {
-        todo!()
+    fn lax_zpx_ind(&mut self) {
+        let addr: Word = self.addr_zpx_ind();
+        self.lax(addr);
     }
 
-    fn sec_imp(&mut self) {
-        Mos6502Flags::C.set(&mut self.status);
-        self.cycle();
+    fn lax_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(false);
+        self.lax(addr);
     }
 
-    fn rol_absx(&mut self) {
-        todo!()
+    fn anc_imm(&mut self) {
+        self.a &= self.fetch_next_byte();
+        self.lda_set_status();
+        let negative = Mos6502Flags::N.get(self.status);
+        Mos6502Flags::C.assign(&mut self.status, negative);
     }
 
-    fn rti_imp(&mut self) {
-        todo!()
+    fn alr_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        self.a = self.shift_right(self.a & data);
     }
 
-    fn eor_zpx_ind(&mut self) {
-        todo!()
+    fn arr_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        self.a = self.and_rotate_right(data);
     }
 
-    fn eor_zp(&mut self) {
-        todo!()
+    fn sbx_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        let and: Byte = self.a & self.x;
+        self.compare(and, data);
+        self.x = and.wrapping_sub(data);
     }
 
-    fn lsr_zp(&mut self) {
-        todo!()
+    fn ane_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        self.a = (self.a | self.config.magic) & self.x & data;
+        self.lda_set_status();
     }
 
-    fn pha_imp(&mut self) {
-        todo!()
+    fn lxa_imm(&mut self) {
+        let data: Byte = self.fetch_next_byte();
+        self.a = (self.a | self.config.magic) & data;
+        self.x = self.a;
+        self.lda_set_status();
     }
 
-    fn eor_imm(&mut self) {
-        todo!()
+    fn sha_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.store_and_high(addr, self.y, self.a & self.x);
     }
 
-    fn lsr_acc(&mut self) {
-        todo!()
+    fn sha_zpy_ind(&mut self) {
+        let addr: Word = self.addr_zpy_ind(true);
+        self.store_and_high(addr, self.y, self.a & self.x);
     }
 
-    fn jmp_abs(&mut self) {
-        todo!()
+    fn shx_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.store_and_high(addr, self.y, self.x);
     }
 
-    fn eor_abs(&mut self) {
-        todo!()
+    fn shy_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.store_and_high(addr, self.x, self.y);
     }
 
-    fn lsr_abs(&mut self) {
-        todo!()
+    fn tas_absy(&mut self) {
+        let addr: Word = self.addr_absy(true);
+        self.sp = self.a & self.x;
+        self.store_and_high(addr, self.y, self.sp);
     }
 
-    fn bvc_rel(&mut self) {
-        todo!()
+    fn las_absy(&mut self) {
+        let addr: Word = self.addr_absy(false);
+        self.a = self.read_byte_at_addr(addr) & self.sp;
+        self.x = self.a;
+        self.sp = self.a;
+        self.lda_set_status();
     }
 
-    fn eor_zpy_ind(&mut self) {
-        todo!()
+    fn jam_imp(&mut self) {
+        // NOTE: The opcode's second fetch never finishes, the
+        // address bus is left stuck and nothing but a reset gets
+        // the CPU going again. Interrupts are not serviced either.
+        self.read_byte_at_addr(self.pc);
+        self.state = CpuState::Jammed;
     }
 
-    fn eor_zpx(&mut self) {
-        todo!()
+    fn nop_imm(&mut self) {
+        self.fetch_next_byte();
     }
 
-    fn lsr_zpx(&mut self) {
-        todo!()
+    fn nop_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.nop(addr);
     }
 
-    fn cli_imp(&mut self) {
-        Mos6502Flags::I.clear(&mut self.status);
-        self.cycle();
+    fn nop_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.nop(addr);
     }
 
-    fn eor_absy(&mut self) {
-        todo!()
+    fn nop_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.nop(addr);
     }
 
-    fn eor_absx(&mut self) {
-        todo!()
+    fn nop_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.nop(addr);
     }
 
-    fn lsr_absx(&mut self) {
-        todo!()
+    ////////// 65C02 INSTRUCTION FUNCTIONS //////////
+
+    fn stz(&mut self, addr: Word) {
+        self.write_byte_at_addr(addr, 0x00);
     }
 
-    fn rts_imp(&mut self) {
-        todo!()
+    fn trb(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::test_and_reset_bits);
     }
 
-    fn adc_zpx_ind(&mut self) {
-        todo!()
+    fn tsb(&mut self, addr: Word) {
+        self.read_modify_write(addr, Self::test_and_set_bits);
     }
 
-    fn adc_zpx_in(&mut self) {
-        todo!()
+    fn ora_zp_ind(&mut self) {
+        let addr: Word = self.addr_zp_ind();
+        self.ora(addr);
     }
 
-    fn adc_zp(&mut self) {
-        todo!()
+    fn and_zp_ind(&mut self) {
+        let addr: Word = self.addr_zp_ind();
+        self.and(addr);
     }
 
-    fn ror_zp(&mut self) {
-        todo!()
+    fn eor_zp_ind(&mut self) {
+        let addr: Word = self.addr_zp_ind();
+        self.eor(addr);
     }
 
-    fn pla_imp(&mut self) {
-        todo!()
+    fn adc_zp_ind(&mut self) {
+        let addr: Word = self.addr_zp_ind();
+        self.adc(addr);
     }
 
-    fn adc_imm(&mut self) {
-        todo!()
+    fn sta_zp_ind(&mut self) {
+        let addr: Word = self.addr_zp_ind();
+        self.sta(addr);
     }
 
-    fn ror_acc(&mut self) {
-        todo!()
+    fn lda_zp_ind(&mut self) {
+        let addr: Word = self.addr_zp_ind();
+        self.lda(addr);
     }
 
-    fn jmp_abs_ind(&mut self) {
-        todo!()
+    fn cmp_zp_ind(&mut self) {
+        let addr: Word = self.addr_zp_ind();
+        self.cmp(addr);
     }
 
-    fn adc_abs(&mut self) {
-        todo!()
+    fn sbc_zp_ind(&mut self) {
+        let addr: Word = self.addr_zp_ind();
+        self.sbc(addr);
     }
 
-    fn ror_abs(&mut self) {
-        todo!()
+    fn stz_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.stz(addr);
     }
 
-    fn bvs_rel(&mut self) {
-        todo!()
+    fn stz_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.stz(addr);
     }
 
-    fn adc_zpy_ind(&mut self) {
-        todo!()
+    fn stz_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.stz(addr);
     }
 
-    fn adc_zpx(&mut self) {
-        todo!()
+    fn stz_absx(&mut self) {
+        let addr: Word = self.addr_absx(true);
+        self.stz(addr);
     }
 
-    fn ror_zpx(&mut self) {
-        todo!()
+    fn trb_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.trb(addr);
     }
 
-    fn sei_imp(&mut self) {
-        Mos6502Flags::I.set(&mut self.status);
-        self.cycle();
+    fn trb_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.trb(addr);
     }
 
-    fn adc_absy(&mut self) {
-        todo!()
+    fn tsb_zp(&mut self) {
+        let addr: Word = self.addr_zp();
+        self.tsb(addr);
     }
 
-    fn adc_absx(&mut self) {
-        todo!()
+    fn tsb_abs(&mut self) {
+        let addr: Word = self.addr_abs();
+        self.tsb(addr);
     }
 
-    fn ror_absx(&mut self) {
-        todo!()
+    fn bit_imm(&mut self) {
+        // NOTE: Only Z is affected, N and V have no memory operand
+        // to come from.
+        let data: Byte = self.fetch_next_byte();
+        Mos6502Flags::Z.assign(&mut self.status, self.a & data == 0);
     }
 
-    fn sta_zpx_ind(&mut self) {
-        todo!()
+    fn bit_zpx(&mut self) {
+        let addr: Word = self.addr_zpx();
+        self.bit(addr);
     }
 
-    fn sty_zp(&mut self) {
-        todo!()
+    fn bit_absx(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.bit(addr);
     }
 
-    fn sta_zp(&mut self) {
-        todo!()
+    fn bra_rel(&mut self) {
+        self.branch_if(true);
     }
 
-    fn stx_zp(&mut self) {
-        todo!()
+    fn phx_imp(&mut self) {
+        self.cycle();
+        self.push_byte(self.x);
     }
 
-    fn dey_imp(&mut self) {
-        todo!()
+    fn phy_imp(&mut self) {
+        self.cycle();
+        self.push_byte(self.y);
     }
 
-    fn txa_imp(&mut self) {
-        todo!()
+    fn plx_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        self.x = self.pull_byte();
+        self.ldx_set_status();
     }
 
-    fn sty_abs(&mut self) {
-        todo!()
+    fn ply_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        self.y = self.pull_byte();
+        self.ldy_set_status();
     }
 
-    fn sta_abs(&mut self) {
-        todo!()
+    fn inc_acc(&mut self) {
+        self.a = self.increment(self.a);
+        self.cycle();
     }
 
-    fn stx_abs(&mut self) {
-        todo!()
+    fn dec_acc(&mut self) {
+        self.a = self.decrement(self.a);
+        self.cycle();
     }
 
-    fn bcc_rel(&mut self) {
-        todo!()
+    // NOTE: The CMOS shifts and rotates only spend the indexing
+    // cycle when a page is crossed, INC and DEC still always do.
+    fn asl_absx_cmos(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.asl(addr);
     }
 
-    fn sta_zpy_ind(&mut self) {
-        todo!()
+    fn rol_absx_cmos(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.rol(addr);
     }
 
-    fn sty_zpx(&mut self) {
-        todo!()
+    fn lsr_absx_cmos(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.lsr(addr);
     }
 
-    fn sta_zpx(&mut self) {
-        todo!()
+    fn ror_absx_cmos(&mut self) {
+        let addr: Word = self.addr_absx(false);
+        self.ror(addr);
     }
 
-    fn stx_zpy(&mut self) {
-        todo!()
+    fn jmp_abs_ind_cmos(&mut self) {
+        // NOTE: Fixed on CMOS parts, the pointer crosses pages
+        // properly at the cost of an extra cycle.
+        let ptr: Word = self.fetch_word();
+        self.cycle();
+        self.pc = self.read_word_at_abs_addr(ptr);
+        self.resolved(self.pc);
     }
 
-    fn tya_imp(&mut self) {
-        todo!()
+    fn jmp_absx_ind(&mut self) {
+        let base: Word = self.fetch_word();
+        self.cycle();
+        self.pc = self.read_word_at_abs_addr(base.wrapping_add(Word::from(self.x)));
+        self.resolved(self.pc);
     }
 
-    fn sta_absy(&mut self) {
-        todo!()
+    // NOTE: The 65C02 unused opcodes in columns 3, 7, B and F
+    // finish in the same cycle as the opcode fetch.
+    #[allow(clippy::unused_self)]
+    fn nop_imp_1cycle(&mut self) {}
+
+    fn nop_abs_5c(&mut self) {
+        // NOTE: $5C reads its operand then idles, 8 cycles in all.
+        let addr: Word = self.addr_abs();
+        self.nop(addr);
+        for _ in 0..4 {
+            self.cycle();
+        }
     }
 
-    fn txs_imp(&mut self) {
-        todo!()
+    ////////// R65C02/W65C02S INSTRUCTION FUNCTIONS //////////
+
+    fn rmb(&mut self, bit: u8) {
+        let addr: Word = self.addr_zp();
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.cycle();
+        self.write_byte_at_addr(addr, data & !(1 << bit));
     }
 
-    fn sta_absx(&mut self) {
-        todo!()
+    fn smb(&mut self, bit: u8) {
+        let addr: Word = self.addr_zp();
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.cycle();
+        self.write_byte_at_addr(addr, data | (1 << bit));
     }
 
-    fn tay_imp(&mut self) {
-        todo!()
+    fn bbr(&mut self, bit: u8) {
+        let addr: Word = self.addr_zp();
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.cycle();
+        self.branch_if(data & (1 << bit) == 0);
+        self.resolved(addr);
     }
 
-    fn tax_imp(&mut self) {
-        todo!()
+    fn bbs(&mut self, bit: u8) {
+        let addr: Word = self.addr_zp();
+        let data: Byte = self.read_byte_at_addr(addr);
+        self.cycle();
+        self.branch_if(data & (1 << bit) != 0);
+        self.resolved(addr);
     }
 
-    fn bcs_rel(&mut self) {
-        todo!()
+    fn rmb0_zp(&mut self) {
+        self.rmb(0);
     }
 
-    fn clv_imp(&mut self) {
-        Mos6502Flags::V.clear(&mut self.status);
-        self.cycle();
+    fn rmb1_zp(&mut self) {
+        self.rmb(1);
     }
 
-    fn tsx_imp(&mut self) {
-        todo!()
+    fn rmb2_zp(&mut self) {
+        self.rmb(2);
     }
 
-    fn cpy_imm(&mut self) {
-        todo!()
+    fn rmb3_zp(&mut self) {
+        self.rmb(3);
     }
 
-    fn cmp_zpx_ind(&mut self) {
-        todo!()
+    fn rmb4_zp(&mut self) {
+        self.rmb(4);
     }
 
-    fn cpy_zp(&mut self) {
-        todo!()
+    fn rmb5_zp(&mut self) {
+        self.rmb(5);
     }
 
-    fn cmp_zp(&mut self) {
-        todo!()
+    fn rmb6_zp(&mut self) {
+        self.rmb(6);
     }
 
-    fn dec_zp(&mut self) {
-        todo!()
+    fn rmb7_zp(&mut self) {
+        self.rmb(7);
     }
 
-    fn iny_imp(&mut self) {
-        todo!()
+    fn smb0_zp(&mut self) {
+        self.smb(0);
     }
 
-    fn cmp_imm(&mut self) {
-        todo!()
+    fn smb1_zp(&mut self) {
+        self.smb(1);
     }
 
-    fn dex_imp(&mut self) {
-        todo!()
+    fn smb2_zp(&mut self) {
+        self.smb(2);
     }
 
-    fn cpy_abs(&mut self) {
-        todo!()
+    fn smb3_zp(&mut self) {
+        self.smb(3);
     }
 
-    fn cmp_abs(&mut self) {
-        todo!()
+    fn smb4_zp(&mut self) {
+        self.smb(4);
     }
 
-    fn dec_abs(&mut self) {
-        todo!()
+    fn smb5_zp(&mut self) {
+        self.smb(5);
     }
 
-    fn bne_rel(&mut self) {
-        todo!()
+    fn smb6_zp(&mut self) {
+        self.smb(6);
     }
 
-    fn cmp_zpy_ind(&mut self) {
-        todo!()
+    fn smb7_zp(&mut self) {
+        self.smb(7);
     }
 
-    fn cmp_zpx(&mut self) {
-        todo!()
+    fn bbr0_zp_rel(&mut self) {
+        self.bbr(0);
     }
 
-    fn dec_zpx(&mut self) {
-        todo!()
+    fn bbr1_zp_rel(&mut self) {
+        self.bbr(1);
     }
 
-    fn cld_imp(&mut self) {
-        Mos6502Flags::D.clear(&mut self.status);
-        self.cycle();
+    fn bbr2_zp_rel(&mut self) {
+        self.bbr(2);
     }
 
-    fn cmp_absy(&mut self) {
-        todo!()
+    fn bbr3_zp_rel(&mut self) {
+        self.bbr(3);
     }
 
-    fn cmp_absx(&mut self) {
-        todo!()
+    fn bbr4_zp_rel(&mut self) {
+        self.bbr(4);
     }
 
-    fn dec_absx(&mut self) {
-        todo!()
+    fn bbr5_zp_rel(&mut self) {
+        self.bbr(5);
     }
 
-    fn cpx_imm(&mut self) {
-        todo!()
+    fn bbr6_zp_rel(&mut self) {
+        self.bbr(6);
     }
 
-    fn sbc_zpx_ind(&mut self) {
-        todo!()
+    fn bbr7_zp_rel(&mut self) {
+        self.bbr(7);
     }
 
-    fn cpx_zp(&mut self) {
-        todo!()
+    fn bbs0_zp_rel(&mut self) {
+        self.bbs(0);
     }
 
-    fn sbc_zp(&mut self) {
-        todo!()
+    fn bbs1_zp_rel(&mut self) {
+        self.bbs(1);
     }
 
-    fn inc_zp(&mut self) {
-        todo!()
+    fn bbs2_zp_rel(&mut self) {
+        self.bbs(2);
     }
 
-    fn inx_imp(&mut self) {
-        todo!()
+    fn bbs3_zp_rel(&mut self) {
+        self.bbs(3);
     }
 
-    fn sbc_imm(&mut self) {
-        todo!()
+    fn bbs4_zp_rel(&mut self) {
+        self.bbs(4);
     }
 
-    fn nop_imp(&mut self) {
-        self.cycle();
+    fn bbs5_zp_rel(&mut self) {
+        self.bbs(5);
     }
 
-    fn cpx_abs(&mut self) {
-        todo!()
+    fn bbs6_zp_rel(&mut self) {
+        self.bbs(6);
     }
 
-    fn sbc_abs(&mut self) {
-        todo!()
+    fn bbs7_zp_rel(&mut self) {
+        self.bbs(7);
     }
 
-    fn inc_abs(&mut self) {
-        todo!()
+    fn wai_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        self.state = CpuState::Waiting;
     }
 
-    fn beq_rel(&mut self) {
-        todo!()
+    fn stp_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        self.state = CpuState::Stopped;
     }
+}
 
-    fn sbc_zpy_ind(&mut self) {
-        todo!()
+impl Core for Mos6502 {
+    fn core(&self) -> &Mos6502 {
+        self
     }
 
-    fn sbc_zpx(&mut self) {
-        todo!()
+    fn core_mut(&mut self) -> &mut Mos6502 {
+        self
     }
 
-    fn inc_zpx(&mut self) {
-        todo!()
+    fn breakpoint(&self) -> Option<StopReason> {
+        self.breakpoints.contains(&self.pc).then_some(StopReason::Breakpoint(self.pc))
     }
 
-    fn sed_imp(&mut self) {
-        Mos6502Flags::D.set(&mut self.status);
-        self.cycle();
+    fn irq_masked(&self) -> bool {
+        Mos6502Flags::I.get(self.status)
     }
 
-    fn sbc_absy(&mut self) {
-        todo!()
+    fn idle(&mut self) {
+        self.cycle();
     }
 
-    fn sbc_absx(&mut self) {
-        todo!()
+    fn service_interrupt(&mut self, nmi: bool) {
+        // NOTE: An IRQ that arrives together with a BRK takes it
+        // over and the BRK is ignored, as the note on `BRK_IMP` says.
+        // The IRQ sequence pushes the address past the BRK and its
+        // signature byte with B clear, so the BRK never runs.
+        if !nmi && self.peek_byte(self.pc) == BRK_IMP {
+            self.pc = self.pc.wrapping_add(2);
+        }
+        self.interrupt(if nmi { NMI_VECTOR } else { IRQ_VECTOR });
     }
 
-    fn inc_absx(&mut self) {
-        todo!()
+    fn run_instruction(&mut self) -> Result<(), ExecError> {
+        self.execute()
     }
 }
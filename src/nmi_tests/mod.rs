@@ -0,0 +1,71 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+
+    const NMI_CCOST: u64 = 7;
+
+    fn nmi_cpu() -> Mos6502 {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::NOP_IMP),
+            (0x0201, instructions::NOP_IMP),
+            (0x3000, instructions::RTI_IMP),
+            (0x4000, instructions::RTI_IMP),
+            (0xFFFA, 0x00),
+            (0xFFFB, 0x30),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+        cpu.set_pc(0x0200);
+        cpu
+    }
+
+    #[test]
+    fn nmi() {
+        let mut cpu = nmi_cpu();
+
+        cpu.set_status_flag(Mos6502Flags::I);
+        cpu.trigger_nmi();
+        cpu.exe(Some(NMI_CCOST)).unwrap();
+
+        // NMI cannot be masked.
+        assert_eq!(cpu.get_pc(), 0x3000);
+        assert_eq!(cpu.mem.get_byte(0x01FB), 0x24);
+        assert_eq!(cpu.get_cycles(), NMI_CCOST);
+    }
+
+    #[test]
+    fn nmi_wedge() {
+        let mut cpu = nmi_cpu();
+
+        cpu.trigger_nmi();
+        cpu.exe(Some(NMI_CCOST + instructions::RTI_IMP_CCOST + instructions::NOP_IMP_CCOST)).unwrap();
+
+        // One edge, one NMI.
+        assert_eq!(cpu.get_pc(), 0x0201);
+        assert_eq!(cpu.get_sp(), 0xFD);
+    }
+
+    #[test]
+    fn nmi_wirq() {
+        let mut cpu = nmi_cpu();
+
+        cpu.set_irq_line(true);
+        cpu.trigger_nmi();
+        cpu.exe(Some(NMI_CCOST)).unwrap();
+
+        // NMI has priority, and sets I so the IRQ waits for RTI.
+        assert_eq!(cpu.get_pc(), 0x3000);
+
+        cpu.exe(Some(cpu.get_cycles() + instructions::RTI_IMP_CCOST + NMI_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4000);
+    }
+}
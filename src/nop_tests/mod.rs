@@ -5,19 +5,120 @@ mod tests {
     use crate::mos6502::{
         Mos6502,
         Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
     };
-    use crate::memory::Memory;
     use crate::instructions;
     use crate::tests_utils;
+    use crate::tests_utils::PC_START;
 
     #[test]
     fn nop() {
         let mut cpu = tests_utils::cpu_mem_set(vec![
             (0xFFFC, instructions::NOP_IMP),
         ]);
-        cpu.exe(Some(instructions::NOP_IMP_CCOST));
+        cpu.exe(Some(instructions::NOP_IMP_CCOST)).unwrap();
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![]);
         assert_eq!(cpu.get_cycles(), instructions::NOP_IMP_CCOST);
     }
 
+    #[test]
+    fn nop_imp_1a() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::NOP_IMP_1A),
+        ]);
+        cpu.exe(Some(instructions::NOP_IMP_1A_CCOST)).unwrap();
+        assert_eq!(cpu.get_pc(), PC_START + 1);
+        assert_eq!(cpu.get_cycles(), instructions::NOP_IMP_1A_CCOST);
+    }
+
+    #[test]
+    fn nop_imm_80() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::NOP_IMM_80),
+            (PC_START + 1, 0xFF),
+        ]);
+        cpu.exe(Some(instructions::NOP_IMM_80_CCOST)).unwrap();
+        assert_eq!(cpu.get_pc(), PC_START + 2);
+        assert_eq!(cpu.get_cycles(), instructions::NOP_IMM_80_CCOST);
+    }
+
+    #[test]
+    fn nop_zpx_14() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::NOP_ZPX_14),
+            (PC_START + 1, 0x42),
+        ]);
+        cpu.set_accumulator(0x37);
+        cpu.exe(Some(instructions::NOP_ZPX_14_CCOST)).unwrap();
+        assert_eq!(cpu.get_pc(), PC_START + 2);
+        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_cycles(), instructions::NOP_ZPX_14_CCOST);
+    }
+
+    #[test]
+    fn nop_abs_0c() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![]);
+        cpu.set_pc(0x0200);
+        *cpu.mem.at(0x0200) = instructions::NOP_ABS_0C;
+        *cpu.mem.at(0x0201) = 0x00;
+        *cpu.mem.at(0x0202) = 0x44;
+        cpu.exe(Some(instructions::NOP_ABS_0C_CCOST)).unwrap();
+        assert_eq!(cpu.get_pc(), 0x0203);
+        assert_eq!(cpu.get_cycles(), instructions::NOP_ABS_0C_CCOST);
+    }
+
+    #[test]
+    fn nop_absx_1c_wpage_boundary() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![]);
+        cpu.set_pc(0x0200);
+        cpu.set_xreg(0x01);
+        *cpu.mem.at(0x0200) = instructions::NOP_ABSX_1C;
+        *cpu.mem.at(0x0201) = 0xFF;
+        *cpu.mem.at(0x0202) = 0x44;
+        cpu.exe(Some(instructions::NOP_ABSX_1C_CCOST + 1)).unwrap();
+        assert_eq!(cpu.get_pc(), 0x0203);
+        assert_eq!(cpu.get_cycles(), instructions::NOP_ABSX_1C_CCOST + 1);
+    }
+
+    #[test]
+    fn nop_imp_1a_wdisabled() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::NOP_IMP_1A),
+        ]);
+        let err = cpu.exe(Some(instructions::NOP_IMP_1A_CCOST)).unwrap_err();
+        assert_eq!(err.opcode, instructions::NOP_IMP_1A);
+    }
+
+    #[test]
+    fn nop_wcmos_unused_opcodes() {
+        // (opcode, bytes, cycles)
+        let cases: [(Byte, Word, u64); 8] = [
+            (0x03, 1, 1),
+            (0xFB, 1, 1),
+            (0x02, 2, 2),
+            (0xE2, 2, 2),
+            (0x44, 2, 3),
+            (0xF4, 2, 4),
+            (0x5C, 3, 8),
+            (0xDC, 3, 4),
+        ];
+        for (opcode, bytes, ccost) in cases {
+            let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+                (0x0200, opcode),
+            ], Mos6502Variant::Cmos65C02);
+            cpu.set_pc(0x0200);
+            cpu.set_accumulator(0x37);
+
+            cpu.exe(Some(ccost)).unwrap();
+
+            assert_eq!(cpu.get_pc(), 0x0200 + bytes, "opcode: {opcode:02x}");
+            assert_eq!(cpu.get_cycles(), ccost, "opcode: {opcode:02x}");
+            assert_eq!(cpu.get_accumulator(), 0x37);
+        }
+    }
 }
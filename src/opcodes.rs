@@ -0,0 +1,589 @@
+#![allow(dead_code)]
+
+use crate::mos6502::Mos6502Variant;
+
+type Byte = u8;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AddressingMode {
+    Implied,
+    Accumulator,
+    Immediate,
+    ZeroPage,
+    ZeroPageX,
+    ZeroPageY,
+    Absolute,
+    AbsoluteX,
+    AbsoluteY,
+    AbsoluteIndirect,
+    AbsoluteXIndirect,
+    ZeroPageXIndirect,
+    ZeroPageIndirectY,
+    ZeroPageIndirect,
+    Relative,
+    ZeroPageRelative,
+}
+
+impl AddressingMode {
+    // Number of operand bytes following the opcode.
+    pub fn operand_len(self) -> usize {
+        match self {
+            AddressingMode::Implied | AddressingMode::Accumulator => 0,
+            AddressingMode::Absolute
+            | AddressingMode::AbsoluteX
+            | AddressingMode::AbsoluteY
+            | AddressingMode::AbsoluteIndirect
+            | AddressingMode::AbsoluteXIndirect
+            | AddressingMode::ZeroPageRelative => 2,
+            _ => 1,
+        }
+    }
+}
+
+const OPCODE_TBL_SIZE: usize = 0x100;
+
+const RMB: [&str; 8] = ["RMB0", "RMB1", "RMB2", "RMB3", "RMB4", "RMB5", "RMB6", "RMB7"];
+const SMB: [&str; 8] = ["SMB0", "SMB1", "SMB2", "SMB3", "SMB4", "SMB5", "SMB6", "SMB7"];
+const BBR: [&str; 8] = ["BBR0", "BBR1", "BBR2", "BBR3", "BBR4", "BBR5", "BBR6", "BBR7"];
+const BBS: [&str; 8] = ["BBS0", "BBS1", "BBS2", "BBS3", "BBS4", "BBS5", "BBS6", "BBS7"];
+
+// NOTE: Documented and undocumented opcodes together, which
+// ones actually run depends on `Mos6502Config::illegal_opcodes`.
+// BRK is listed as immediate for the signature byte it skips.
+const OPCODES_NMOS: [(&str, AddressingMode); OPCODE_TBL_SIZE] = [
+    ("BRK", AddressingMode::Immediate),
+    ("ORA", AddressingMode::ZeroPageXIndirect),
+    ("JAM", AddressingMode::Implied),
+    ("SLO", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::ZeroPage),
+    ("ORA", AddressingMode::ZeroPage),
+    ("ASL", AddressingMode::ZeroPage),
+    ("SLO", AddressingMode::ZeroPage),
+    ("PHP", AddressingMode::Implied),
+    ("ORA", AddressingMode::Immediate),
+    ("ASL", AddressingMode::Accumulator),
+    ("ANC", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Absolute),
+    ("ORA", AddressingMode::Absolute),
+    ("ASL", AddressingMode::Absolute),
+    ("SLO", AddressingMode::Absolute),
+    ("BPL", AddressingMode::Relative),
+    ("ORA", AddressingMode::ZeroPageIndirectY),
+    ("JAM", AddressingMode::Implied),
+    ("SLO", AddressingMode::ZeroPageIndirectY),
+    ("NOP", AddressingMode::ZeroPageX),
+    ("ORA", AddressingMode::ZeroPageX),
+    ("ASL", AddressingMode::ZeroPageX),
+    ("SLO", AddressingMode::ZeroPageX),
+    ("CLC", AddressingMode::Implied),
+    ("ORA", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::Implied),
+    ("SLO", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::AbsoluteX),
+    ("ORA", AddressingMode::AbsoluteX),
+    ("ASL", AddressingMode::AbsoluteX),
+    ("SLO", AddressingMode::AbsoluteX),
+    ("JSR", AddressingMode::Absolute),
+    ("AND", AddressingMode::ZeroPageXIndirect),
+    ("JAM", AddressingMode::Implied),
+    ("RLA", AddressingMode::ZeroPageXIndirect),
+    ("BIT", AddressingMode::ZeroPage),
+    ("AND", AddressingMode::ZeroPage),
+    ("ROL", AddressingMode::ZeroPage),
+    ("RLA", AddressingMode::ZeroPage),
+    ("PLP", AddressingMode::Implied),
+    ("AND", AddressingMode::Immediate),
+    ("ROL", AddressingMode::Accumulator),
+    ("ANC", AddressingMode::Immediate),
+    ("BIT", AddressingMode::Absolute),
+    ("AND", AddressingMode::Absolute),
+    ("ROL", AddressingMode::Absolute),
+    ("RLA", AddressingMode::Absolute),
+    ("BMI", AddressingMode::Relative),
+    ("AND", AddressingMode::ZeroPageIndirectY),
+    ("JAM", AddressingMode::Implied),
+    ("RLA", AddressingMode::ZeroPageIndirectY),
+    ("NOP", AddressingMode::ZeroPageX),
+    ("AND", AddressingMode::ZeroPageX),
+    ("ROL", AddressingMode::ZeroPageX),
+    ("RLA", AddressingMode::ZeroPageX),
+    ("SEC", AddressingMode::Implied),
+    ("AND", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::Implied),
+    ("RLA", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::AbsoluteX),
+    ("AND", AddressingMode::AbsoluteX),
+    ("ROL", AddressingMode::AbsoluteX),
+    ("RLA", AddressingMode::AbsoluteX),
+    ("RTI", AddressingMode::Implied),
+    ("EOR", AddressingMode::ZeroPageXIndirect),
+    ("JAM", AddressingMode::Implied),
+    ("SRE", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::ZeroPage),
+    ("EOR", AddressingMode::ZeroPage),
+    ("LSR", AddressingMode::ZeroPage),
+    ("SRE", AddressingMode::ZeroPage),
+    ("PHA", AddressingMode::Implied),
+    ("EOR", AddressingMode::Immediate),
+    ("LSR", AddressingMode::Accumulator),
+    ("ALR", AddressingMode::Immediate),
+    ("JMP", AddressingMode::Absolute),
+    ("EOR", AddressingMode::Absolute),
+    ("LSR", AddressingMode::Absolute),
+    ("SRE", AddressingMode::Absolute),
+    ("BVC", AddressingMode::Relative),
+    ("EOR", AddressingMode::ZeroPageIndirectY),
+    ("JAM", AddressingMode::Implied),
+    ("SRE", AddressingMode::ZeroPageIndirectY),
+    ("NOP", AddressingMode::ZeroPageX),
+    ("EOR", AddressingMode::ZeroPageX),
+    ("LSR", AddressingMode::ZeroPageX),
+    ("SRE", AddressingMode::ZeroPageX),
+    ("CLI", AddressingMode::Implied),
+    ("EOR", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::Implied),
+    ("SRE", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::AbsoluteX),
+    ("EOR", AddressingMode::AbsoluteX),
+    ("LSR", AddressingMode::AbsoluteX),
+    ("SRE", AddressingMode::AbsoluteX),
+    ("RTS", AddressingMode::Implied),
+    ("ADC", AddressingMode::ZeroPageXIndirect),
+    ("JAM", AddressingMode::Implied),
+    ("RRA", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::ZeroPage),
+    ("ADC", AddressingMode::ZeroPage),
+    ("ROR", AddressingMode::ZeroPage),
+    ("RRA", AddressingMode::ZeroPage),
+    ("PLA", AddressingMode::Implied),
+    ("ADC", AddressingMode::Immediate),
+    ("ROR", AddressingMode::Accumulator),
+    ("ARR", AddressingMode::Immediate),
+    ("JMP", AddressingMode::AbsoluteIndirect),
+    ("ADC", AddressingMode::Absolute),
+    ("ROR", AddressingMode::Absolute),
+    ("RRA", AddressingMode::Absolute),
+    ("BVS", AddressingMode::Relative),
+    ("ADC", AddressingMode::ZeroPageIndirectY),
+    ("JAM", AddressingMode::Implied),
+    ("RRA", AddressingMode::ZeroPageIndirectY),
+    ("NOP", AddressingMode::ZeroPageX),
+    ("ADC", AddressingMode::ZeroPageX),
+    ("ROR", AddressingMode::ZeroPageX),
+    ("RRA", AddressingMode::ZeroPageX),
+    ("SEI", AddressingMode::Implied),
+    ("ADC", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::Implied),
+    ("RRA", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::AbsoluteX),
+    ("ADC", AddressingMode::AbsoluteX),
+    ("ROR", AddressingMode::AbsoluteX),
+    ("RRA", AddressingMode::AbsoluteX),
+    ("NOP", AddressingMode::Immediate),
+    ("STA", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("SAX", AddressingMode::ZeroPageXIndirect),
+    ("STY", AddressingMode::ZeroPage),
+    ("STA", AddressingMode::ZeroPage),
+    ("STX", AddressingMode::ZeroPage),
+    ("SAX", AddressingMode::ZeroPage),
+    ("DEY", AddressingMode::Implied),
+    ("NOP", AddressingMode::Immediate),
+    ("TXA", AddressingMode::Implied),
+    ("ANE", AddressingMode::Immediate),
+    ("STY", AddressingMode::Absolute),
+    ("STA", AddressingMode::Absolute),
+    ("STX", AddressingMode::Absolute),
+    ("SAX", AddressingMode::Absolute),
+    ("BCC", AddressingMode::Relative),
+    ("STA", AddressingMode::ZeroPageIndirectY),
+    ("JAM", AddressingMode::Implied),
+    ("SHA", AddressingMode::ZeroPageIndirectY),
+    ("STY", AddressingMode::ZeroPageX),
+    ("STA", AddressingMode::ZeroPageX),
+    ("STX", AddressingMode::ZeroPageY),
+    ("SAX", AddressingMode::ZeroPageY),
+    ("TYA", AddressingMode::Implied),
+    ("STA", AddressingMode::AbsoluteY),
+    ("TXS", AddressingMode::Implied),
+    ("TAS", AddressingMode::AbsoluteY),
+    ("SHY", AddressingMode::AbsoluteX),
+    ("STA", AddressingMode::AbsoluteX),
+    ("SHX", AddressingMode::AbsoluteY),
+    ("SHA", AddressingMode::AbsoluteY),
+    ("LDY", AddressingMode::Immediate),
+    ("LDA", AddressingMode::ZeroPageXIndirect),
+    ("LDX", AddressingMode::Immediate),
+    ("LAX", AddressingMode::ZeroPageXIndirect),
+    ("LDY", AddressingMode::ZeroPage),
+    ("LDA", AddressingMode::ZeroPage),
+    ("LDX", AddressingMode::ZeroPage),
+    ("LAX", AddressingMode::ZeroPage),
+    ("TAY", AddressingMode::Implied),
+    ("LDA", AddressingMode::Immediate),
+    ("TAX", AddressingMode::Implied),
+    ("LXA", AddressingMode::Immediate),
+    ("LDY", AddressingMode::Absolute),
+    ("LDA", AddressingMode::Absolute),
+    ("LDX", AddressingMode::Absolute),
+    ("LAX", AddressingMode::Absolute),
+    ("BCS", AddressingMode::Relative),
+    ("LDA", AddressingMode::ZeroPageIndirectY),
+    ("JAM", AddressingMode::Implied),
+    ("LAX", AddressingMode::ZeroPageIndirectY),
+    ("LDY", AddressingMode::ZeroPageX),
+    ("LDA", AddressingMode::ZeroPageX),
+    ("LDX", AddressingMode::ZeroPageY),
+    ("LAX", AddressingMode::ZeroPageY),
+    ("CLV", AddressingMode::Implied),
+    ("LDA", AddressingMode::AbsoluteY),
+    ("TSX", AddressingMode::Implied),
+    ("LAS", AddressingMode::AbsoluteY),
+    ("LDY", AddressingMode::AbsoluteX),
+    ("LDA", AddressingMode::AbsoluteX),
+    ("LDX", AddressingMode::AbsoluteY),
+    ("LAX", AddressingMode::AbsoluteY),
+    ("CPY", AddressingMode::Immediate),
+    ("CMP", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("DCP", AddressingMode::ZeroPageXIndirect),
+    ("CPY", AddressingMode::ZeroPage),
+    ("CMP", AddressingMode::ZeroPage),
+    ("DEC", AddressingMode::ZeroPage),
+    ("DCP", AddressingMode::ZeroPage),
+    ("INY", AddressingMode::Implied),
+    ("CMP", AddressingMode::Immediate),
+    ("DEX", AddressingMode::Implied),
+    ("SBX", AddressingMode::Immediate),
+    ("CPY", AddressingMode::Absolute),
+    ("CMP", AddressingMode::Absolute),
+    ("DEC", AddressingMode::Absolute),
+    ("DCP", AddressingMode::Absolute),
+    ("BNE", AddressingMode::Relative),
+    ("CMP", AddressingMode::ZeroPageIndirectY),
+    ("JAM", AddressingMode::Implied),
+    ("DCP", AddressingMode::ZeroPageIndirectY),
+    ("NOP", AddressingMode::ZeroPageX),
+    ("CMP", AddressingMode::ZeroPageX),
+    ("DEC", AddressingMode::ZeroPageX),
+    ("DCP", AddressingMode::ZeroPageX),
+    ("CLD", AddressingMode::Implied),
+    ("CMP", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::Implied),
+    ("DCP", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::AbsoluteX),
+    ("CMP", AddressingMode::AbsoluteX),
+    ("DEC", AddressingMode::AbsoluteX),
+    ("DCP", AddressingMode::AbsoluteX),
+    ("CPX", AddressingMode::Immediate),
+    ("SBC", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("ISC", AddressingMode::ZeroPageXIndirect),
+    ("CPX", AddressingMode::ZeroPage),
+    ("SBC", AddressingMode::ZeroPage),
+    ("INC", AddressingMode::ZeroPage),
+    ("ISC", AddressingMode::ZeroPage),
+    ("INX", AddressingMode::Implied),
+    ("SBC", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("SBC", AddressingMode::Immediate),
+    ("CPX", AddressingMode::Absolute),
+    ("SBC", AddressingMode::Absolute),
+    ("INC", AddressingMode::Absolute),
+    ("ISC", AddressingMode::Absolute),
+    ("BEQ", AddressingMode::Relative),
+    ("SBC", AddressingMode::ZeroPageIndirectY),
+    ("JAM", AddressingMode::Implied),
+    ("ISC", AddressingMode::ZeroPageIndirectY),
+    ("NOP", AddressingMode::ZeroPageX),
+    ("SBC", AddressingMode::ZeroPageX),
+    ("INC", AddressingMode::ZeroPageX),
+    ("ISC", AddressingMode::ZeroPageX),
+    ("SED", AddressingMode::Implied),
+    ("SBC", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::Implied),
+    ("ISC", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::AbsoluteX),
+    ("SBC", AddressingMode::AbsoluteX),
+    ("INC", AddressingMode::AbsoluteX),
+    ("ISC", AddressingMode::AbsoluteX),
+];
+
+const OPCODES_CMOS: [(&str, AddressingMode); OPCODE_TBL_SIZE] = [
+    ("BRK", AddressingMode::Immediate),
+    ("ORA", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("TSB", AddressingMode::ZeroPage),
+    ("ORA", AddressingMode::ZeroPage),
+    ("ASL", AddressingMode::ZeroPage),
+    ("NOP", AddressingMode::Implied),
+    ("PHP", AddressingMode::Implied),
+    ("ORA", AddressingMode::Immediate),
+    ("ASL", AddressingMode::Accumulator),
+    ("NOP", AddressingMode::Implied),
+    ("TSB", AddressingMode::Absolute),
+    ("ORA", AddressingMode::Absolute),
+    ("ASL", AddressingMode::Absolute),
+    ("NOP", AddressingMode::Implied),
+    ("BPL", AddressingMode::Relative),
+    ("ORA", AddressingMode::ZeroPageIndirectY),
+    ("ORA", AddressingMode::ZeroPageIndirect),
+    ("NOP", AddressingMode::Implied),
+    ("TRB", AddressingMode::ZeroPage),
+    ("ORA", AddressingMode::ZeroPageX),
+    ("ASL", AddressingMode::ZeroPageX),
+    ("NOP", AddressingMode::Implied),
+    ("CLC", AddressingMode::Implied),
+    ("ORA", AddressingMode::AbsoluteY),
+    ("INC", AddressingMode::Accumulator),
+    ("NOP", AddressingMode::Implied),
+    ("TRB", AddressingMode::Absolute),
+    ("ORA", AddressingMode::AbsoluteX),
+    ("ASL", AddressingMode::AbsoluteX),
+    ("NOP", AddressingMode::Implied),
+    ("JSR", AddressingMode::Absolute),
+    ("AND", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("BIT", AddressingMode::ZeroPage),
+    ("AND", AddressingMode::ZeroPage),
+    ("ROL", AddressingMode::ZeroPage),
+    ("NOP", AddressingMode::Implied),
+    ("PLP", AddressingMode::Implied),
+    ("AND", AddressingMode::Immediate),
+    ("ROL", AddressingMode::Accumulator),
+    ("NOP", AddressingMode::Implied),
+    ("BIT", AddressingMode::Absolute),
+    ("AND", AddressingMode::Absolute),
+    ("ROL", AddressingMode::Absolute),
+    ("NOP", AddressingMode::Implied),
+    ("BMI", AddressingMode::Relative),
+    ("AND", AddressingMode::ZeroPageIndirectY),
+    ("AND", AddressingMode::ZeroPageIndirect),
+    ("NOP", AddressingMode::Implied),
+    ("BIT", AddressingMode::ZeroPageX),
+    ("AND", AddressingMode::ZeroPageX),
+    ("ROL", AddressingMode::ZeroPageX),
+    ("NOP", AddressingMode::Implied),
+    ("SEC", AddressingMode::Implied),
+    ("AND", AddressingMode::AbsoluteY),
+    ("DEC", AddressingMode::Accumulator),
+    ("NOP", AddressingMode::Implied),
+    ("BIT", AddressingMode::AbsoluteX),
+    ("AND", AddressingMode::AbsoluteX),
+    ("ROL", AddressingMode::AbsoluteX),
+    ("NOP", AddressingMode::Implied),
+    ("RTI", AddressingMode::Implied),
+    ("EOR", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("NOP", AddressingMode::ZeroPage),
+    ("EOR", AddressingMode::ZeroPage),
+    ("LSR", AddressingMode::ZeroPage),
+    ("NOP", AddressingMode::Implied),
+    ("PHA", AddressingMode::Implied),
+    ("EOR", AddressingMode::Immediate),
+    ("LSR", AddressingMode::Accumulator),
+    ("NOP", AddressingMode::Implied),
+    ("JMP", AddressingMode::Absolute),
+    ("EOR", AddressingMode::Absolute),
+    ("LSR", AddressingMode::Absolute),
+    ("NOP", AddressingMode::Implied),
+    ("BVC", AddressingMode::Relative),
+    ("EOR", AddressingMode::ZeroPageIndirectY),
+    ("EOR", AddressingMode::ZeroPageIndirect),
+    ("NOP", AddressingMode::Implied),
+    ("NOP", AddressingMode::ZeroPageX),
+    ("EOR", AddressingMode::ZeroPageX),
+    ("LSR", AddressingMode::ZeroPageX),
+    ("NOP", AddressingMode::Implied),
+    ("CLI", AddressingMode::Implied),
+    ("EOR", AddressingMode::AbsoluteY),
+    ("PHY", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("NOP", AddressingMode::Absolute),
+    ("EOR", AddressingMode::AbsoluteX),
+    ("LSR", AddressingMode::AbsoluteX),
+    ("NOP", AddressingMode::Implied),
+    ("RTS", AddressingMode::Implied),
+    ("ADC", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("STZ", AddressingMode::ZeroPage),
+    ("ADC", AddressingMode::ZeroPage),
+    ("ROR", AddressingMode::ZeroPage),
+    ("NOP", AddressingMode::Implied),
+    ("PLA", AddressingMode::Implied),
+    ("ADC", AddressingMode::Immediate),
+    ("ROR", AddressingMode::Accumulator),
+    ("NOP", AddressingMode::Implied),
+    ("JMP", AddressingMode::AbsoluteIndirect),
+    ("ADC", AddressingMode::Absolute),
+    ("ROR", AddressingMode::Absolute),
+    ("NOP", AddressingMode::Implied),
+    ("BVS", AddressingMode::Relative),
+    ("ADC", AddressingMode::ZeroPageIndirectY),
+    ("ADC", AddressingMode::ZeroPageIndirect),
+    ("NOP", AddressingMode::Implied),
+    ("STZ", AddressingMode::ZeroPageX),
+    ("ADC", AddressingMode::ZeroPageX),
+    ("ROR", AddressingMode::ZeroPageX),
+    ("NOP", AddressingMode::Implied),
+    ("SEI", AddressingMode::Implied),
+    ("ADC", AddressingMode::AbsoluteY),
+    ("PLY", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("JMP", AddressingMode::AbsoluteXIndirect),
+    ("ADC", AddressingMode::AbsoluteX),
+    ("ROR", AddressingMode::AbsoluteX),
+    ("NOP", AddressingMode::Implied),
+    ("BRA", AddressingMode::Relative),
+    ("STA", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("STY", AddressingMode::ZeroPage),
+    ("STA", AddressingMode::ZeroPage),
+    ("STX", AddressingMode::ZeroPage),
+    ("NOP", AddressingMode::Implied),
+    ("DEY", AddressingMode::Implied),
+    ("BIT", AddressingMode::Immediate),
+    ("TXA", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("STY", AddressingMode::Absolute),
+    ("STA", AddressingMode::Absolute),
+    ("STX", AddressingMode::Absolute),
+    ("NOP", AddressingMode::Implied),
+    ("BCC", AddressingMode::Relative),
+    ("STA", AddressingMode::ZeroPageIndirectY),
+    ("STA", AddressingMode::ZeroPageIndirect),
+    ("NOP", AddressingMode::Implied),
+    ("STY", AddressingMode::ZeroPageX),
+    ("STA", AddressingMode::ZeroPageX),
+    ("STX", AddressingMode::ZeroPageY),
+    ("NOP", AddressingMode::Implied),
+    ("TYA", AddressingMode::Implied),
+    ("STA", AddressingMode::AbsoluteY),
+    ("TXS", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("STZ", AddressingMode::Absolute),
+    ("STA", AddressingMode::AbsoluteX),
+    ("STZ", AddressingMode::AbsoluteX),
+    ("NOP", AddressingMode::Implied),
+    ("LDY", AddressingMode::Immediate),
+    ("LDA", AddressingMode::ZeroPageXIndirect),
+    ("LDX", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("LDY", AddressingMode::ZeroPage),
+    ("LDA", AddressingMode::ZeroPage),
+    ("LDX", AddressingMode::ZeroPage),
+    ("NOP", AddressingMode::Implied),
+    ("TAY", AddressingMode::Implied),
+    ("LDA", AddressingMode::Immediate),
+    ("TAX", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("LDY", AddressingMode::Absolute),
+    ("LDA", AddressingMode::Absolute),
+    ("LDX", AddressingMode::Absolute),
+    ("NOP", AddressingMode::Implied),
+    ("BCS", AddressingMode::Relative),
+    ("LDA", AddressingMode::ZeroPageIndirectY),
+    ("LDA", AddressingMode::ZeroPageIndirect),
+    ("NOP", AddressingMode::Implied),
+    ("LDY", AddressingMode::ZeroPageX),
+    ("LDA", AddressingMode::ZeroPageX),
+    ("LDX", AddressingMode::ZeroPageY),
+    ("NOP", AddressingMode::Implied),
+    ("CLV", AddressingMode::Implied),
+    ("LDA", AddressingMode::AbsoluteY),
+    ("TSX", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("LDY", AddressingMode::AbsoluteX),
+    ("LDA", AddressingMode::AbsoluteX),
+    ("LDX", AddressingMode::AbsoluteY),
+    ("NOP", AddressingMode::Implied),
+    ("CPY", AddressingMode::Immediate),
+    ("CMP", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("CPY", AddressingMode::ZeroPage),
+    ("CMP", AddressingMode::ZeroPage),
+    ("DEC", AddressingMode::ZeroPage),
+    ("NOP", AddressingMode::Implied),
+    ("INY", AddressingMode::Implied),
+    ("CMP", AddressingMode::Immediate),
+    ("DEX", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("CPY", AddressingMode::Absolute),
+    ("CMP", AddressingMode::Absolute),
+    ("DEC", AddressingMode::Absolute),
+    ("NOP", AddressingMode::Implied),
+    ("BNE", AddressingMode::Relative),
+    ("CMP", AddressingMode::ZeroPageIndirectY),
+    ("CMP", AddressingMode::ZeroPageIndirect),
+    ("NOP", AddressingMode::Implied),
+    ("NOP", AddressingMode::ZeroPageX),
+    ("CMP", AddressingMode::ZeroPageX),
+    ("DEC", AddressingMode::ZeroPageX),
+    ("NOP", AddressingMode::Implied),
+    ("CLD", AddressingMode::Implied),
+    ("CMP", AddressingMode::AbsoluteY),
+    ("PHX", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("NOP", AddressingMode::Absolute),
+    ("CMP", AddressingMode::AbsoluteX),
+    ("DEC", AddressingMode::AbsoluteX),
+    ("NOP", AddressingMode::Implied),
+    ("CPX", AddressingMode::Immediate),
+    ("SBC", AddressingMode::ZeroPageXIndirect),
+    ("NOP", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("CPX", AddressingMode::ZeroPage),
+    ("SBC", AddressingMode::ZeroPage),
+    ("INC", AddressingMode::ZeroPage),
+    ("NOP", AddressingMode::Implied),
+    ("INX", AddressingMode::Implied),
+    ("SBC", AddressingMode::Immediate),
+    ("NOP", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("CPX", AddressingMode::Absolute),
+    ("SBC", AddressingMode::Absolute),
+    ("INC", AddressingMode::Absolute),
+    ("NOP", AddressingMode::Implied),
+    ("BEQ", AddressingMode::Relative),
+    ("SBC", AddressingMode::ZeroPageIndirectY),
+    ("SBC", AddressingMode::ZeroPageIndirect),
+    ("NOP", AddressingMode::Implied),
+    ("NOP", AddressingMode::ZeroPageX),
+    ("SBC", AddressingMode::ZeroPageX),
+    ("INC", AddressingMode::ZeroPageX),
+    ("NOP", AddressingMode::Implied),
+    ("SED", AddressingMode::Implied),
+    ("SBC", AddressingMode::AbsoluteY),
+    ("PLX", AddressingMode::Implied),
+    ("NOP", AddressingMode::Implied),
+    ("NOP", AddressingMode::Absolute),
+    ("SBC", AddressingMode::AbsoluteX),
+    ("INC", AddressingMode::AbsoluteX),
+    ("NOP", AddressingMode::Implied),
+];
+
+// Mnemonic and addressing mode of `opcode` as decoded by `variant`.
+pub fn opcode_info(variant: Mos6502Variant, opcode: Byte) -> (&'static str, AddressingMode) {
+    let bit = usize::from(opcode >> 4) & 0x07;
+    let set = opcode & 0x80 != 0;
+    match (variant, opcode & 0x0F) {
+        (Mos6502Variant::Nmos6502 | Mos6502Variant::Mos6510 | Mos6502Variant::Ricoh2A03, _) => {
+            OPCODES_NMOS[usize::from(opcode)]
+        }
+        (Mos6502Variant::Rockwell65C02 | Mos6502Variant::Wdc65C02S, 0x07) => {
+            (if set { SMB[bit] } else { RMB[bit] }, AddressingMode::ZeroPage)
+        }
+        (Mos6502Variant::Rockwell65C02 | Mos6502Variant::Wdc65C02S, 0x0F) => {
+            (if set { BBS[bit] } else { BBR[bit] }, AddressingMode::ZeroPageRelative)
+        }
+        (Mos6502Variant::Wdc65C02S, _) if opcode == 0xCB => ("WAI", AddressingMode::Implied),
+        (Mos6502Variant::Wdc65C02S, _) if opcode == 0xDB => ("STP", AddressingMode::Implied),
+        _ => OPCODES_CMOS[usize::from(opcode)],
+    }
+}
@@ -0,0 +1,64 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn pha_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PHA_IMP),
+        ]);
+
+        cpu.set_accumulator(0x37);
+        cpu.exe(Some(instructions::PHA_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(0x01FD), 0x37);
+        assert_eq!(cpu.get_sp(), 0xFC);
+        assert_eq!(cpu.get_cycles(), instructions::PHA_IMP_CCOST);
+    }
+
+    #[test]
+    fn pha_imp_wwrap() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PHA_IMP),
+        ]);
+
+        cpu.set_accumulator(0x37);
+        cpu.set_sp(0x00);
+        cpu.exe(Some(instructions::PHA_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(0x0100), 0x37);
+        assert_eq!(cpu.get_sp(), 0xFF);
+        assert_eq!(cpu.get_cycles(), instructions::PHA_IMP_CCOST);
+    }
+
+    #[test]
+    fn pha_imp_wpla_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PHA_IMP),
+            (PC_START + 1, instructions::LDA_IMM),
+            (PC_START + 2, 0x00),
+            (PC_START + 3, instructions::PLA_IMP),
+        ]);
+
+        cpu.set_accumulator(0x84);
+        cpu.exe(Some(
+            instructions::PHA_IMP_CCOST
+            + instructions::LDA_IMM_CCOST
+            + instructions::PLA_IMP_CCOST
+        )).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x84);
+        assert_eq!(cpu.get_sp(), 0xFD);
+        assert!(cpu.negative_flag());
+        assert!(!cpu.zero_flag());
+    }
+}
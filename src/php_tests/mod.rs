@@ -0,0 +1,52 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn php_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PHP_IMP),
+        ]);
+
+        cpu.set_status_flag(Mos6502Flags::C);
+        cpu.set_status_flag(Mos6502Flags::N);
+        cpu.exe(Some(instructions::PHP_IMP_CCOST)).unwrap();
+
+        // B and U are always set in the pushed copy.
+        assert_eq!(cpu.mem.get_byte(0x01FD), 0xB1);
+        assert_eq!(cpu.get_sp(), 0xFC);
+        assert_eq!(cpu.get_cycles(), instructions::PHP_IMP_CCOST);
+        assert!(!cpu.break_flag());
+    }
+
+    #[test]
+    fn php_imp_wplp_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PHP_IMP),
+            (PC_START + 1, instructions::CLV_IMP),
+            (PC_START + 2, instructions::PLP_IMP),
+        ]);
+
+        cpu.set_status_flag(Mos6502Flags::V);
+        cpu.set_status_flag(Mos6502Flags::Z);
+        cpu.exe(Some(
+            instructions::PHP_IMP_CCOST
+            + instructions::CLV_IMP_CCOST
+            + instructions::PLP_IMP_CCOST
+        )).unwrap();
+
+        assert!(cpu.overflow_flag());
+        assert!(cpu.zero_flag());
+        assert!(!cpu.break_flag());
+        assert_eq!(cpu.get_sp(), 0xFD);
+    }
+}
@@ -0,0 +1,33 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn phx_imp() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::PHX_IMP),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_xreg(0x42);
+
+        cpu.exe(Some(instructions::PHX_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(0x01FD), 0x42);
+        assert_eq!(cpu.get_sp(), 0xFC);
+        assert_eq!(cpu.get_cycles(), instructions::PHX_IMP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
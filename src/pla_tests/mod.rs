@@ -0,0 +1,58 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn pla_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PLA_IMP),
+            (0x01FE, 0x84),
+        ]);
+
+        cpu.exe(Some(instructions::PLA_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x84);
+        assert_eq!(cpu.get_sp(), 0xFE);
+        assert_eq!(cpu.get_cycles(), instructions::PLA_IMP_CCOST);
+        assert!(cpu.negative_flag());
+    }
+
+    #[test]
+    fn pla_imp_wzero() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PLA_IMP),
+            (0x01FE, 0x00),
+        ]);
+
+        cpu.set_accumulator(0x37);
+        cpu.exe(Some(instructions::PLA_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert!(cpu.zero_flag());
+        assert!(!cpu.negative_flag());
+    }
+
+    #[test]
+    fn pla_imp_wwrap() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PLA_IMP),
+            (0x0100, 0x37),
+        ]);
+
+        cpu.set_sp(0xFF);
+        cpu.exe(Some(instructions::PLA_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x37);
+        assert_eq!(cpu.get_sp(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::PLA_IMP_CCOST);
+    }
+}
@@ -0,0 +1,42 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn plp_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PLP_IMP),
+            (0x01FE, 0xFF),
+        ]);
+
+        cpu.exe(Some(instructions::PLP_IMP_CCOST)).unwrap();
+
+        // B and U are not part of the status register.
+        assert_eq!(cpu.get_status(), 0xCF);
+        assert_eq!(cpu.get_sp(), 0xFE);
+        assert_eq!(cpu.get_cycles(), instructions::PLP_IMP_CCOST);
+    }
+
+    #[test]
+    fn plp_imp_wwrap() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::PLP_IMP),
+            (0x0100, 0x01),
+        ]);
+
+        cpu.set_sp(0xFF);
+        cpu.exe(Some(instructions::PLP_IMP_CCOST)).unwrap();
+
+        assert!(cpu.carry_flag());
+        assert_eq!(cpu.get_sp(), 0x00);
+    }
+}
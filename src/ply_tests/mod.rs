@@ -0,0 +1,50 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn ply_imp() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::PLY_IMP),
+            (0x01FD, 0x80),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_sp(0xFC);
+
+        cpu.exe(Some(instructions::PLY_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_yreg(), 0x80);
+        assert_eq!(cpu.get_sp(), 0xFD);
+        assert_eq!(cpu.get_cycles(), instructions::PLY_IMP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn ply_imp_wzero() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::PLY_IMP),
+            (0x01FD, 0x00),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_sp(0xFC);
+        cpu.set_yreg(0x42);
+
+        cpu.exe(Some(instructions::PLY_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_yreg(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::PLY_IMP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z]).is_ok());
+    }
+}
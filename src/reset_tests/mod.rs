@@ -0,0 +1,167 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+        CpuState,
+        StopReason,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    const RESET_CCOST: u64 = 7;
+
+    fn vector_cpu() -> Mos6502 {
+        tests_utils::cpu_mem_set(vec![
+            (0xFFFC, 0x00),
+            (0xFFFD, 0x40),
+            (0x4000, instructions::LDA_IMM),
+            (0x4001, 0x84),
+        ])
+    }
+
+    #[test]
+    fn power_on() {
+        let mut cpu = vector_cpu();
+        cpu.set_accumulator(0x42);
+        cpu.set_status_flag(Mos6502Flags::C);
+
+        cpu.power_on();
+
+        assert_eq!(cpu.get_pc(), 0x4000);
+        assert_eq!(cpu.get_sp(), 0xFD);
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_cycles(), RESET_CCOST);
+        assert_eq!(cpu.get_instructions(), 0);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::I]).is_ok());
+    }
+
+    #[test]
+    fn reset() {
+        let mut cpu = vector_cpu();
+
+        cpu.reset();
+
+        assert_eq!(cpu.get_pc(), 0x4000);
+        assert_eq!(cpu.get_cycles(), RESET_CCOST);
+        assert!(cpu.interrupts_disable_flag());
+
+        cpu.exe(Some(RESET_CCOST + instructions::LDA_IMM_CCOST)).unwrap();
+        assert_eq!(cpu.get_accumulator(), 0x84);
+    }
+
+    #[test]
+    fn reset_wsuppressed_pushes() {
+        let mut cpu = vector_cpu();
+        cpu.set_sp(0x50);
+        *cpu.mem.at(0x0150) = 0x11;
+        *cpu.mem.at(0x014F) = 0x22;
+        *cpu.mem.at(0x014E) = 0x33;
+
+        cpu.reset();
+
+        // SP moves as if three bytes were pushed but nothing is written.
+        assert_eq!(cpu.get_sp(), 0x4D);
+        assert_eq!(cpu.mem.get_byte(0x0150), 0x11);
+        assert_eq!(cpu.mem.get_byte(0x014F), 0x22);
+        assert_eq!(cpu.mem.get_byte(0x014E), 0x33);
+    }
+
+    #[test]
+    fn reset_wsp_wrap() {
+        let mut cpu = vector_cpu();
+        cpu.set_sp(0x01);
+
+        cpu.reset();
+
+        assert_eq!(cpu.get_sp(), 0xFE);
+    }
+
+    #[test]
+    fn reset_wwarm() {
+        let mut cpu = vector_cpu();
+        cpu.set_accumulator(0x42);
+        cpu.set_xreg(0x43);
+        cpu.set_yreg(0x44);
+        cpu.set_status_flag(Mos6502Flags::C);
+        cpu.set_status_flag(Mos6502Flags::D);
+        *cpu.mem.at(0x0300) = 0x55;
+
+        cpu.reset();
+
+        assert_eq!(cpu.get_accumulator(), 0x42);
+        assert_eq!(cpu.get_xreg(), 0x43);
+        assert_eq!(cpu.get_yreg(), 0x44);
+        assert_eq!(cpu.mem.get_byte(0x0300), 0x55);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[
+            Mos6502Flags::C,
+            Mos6502Flags::D,
+            Mos6502Flags::I,
+        ]).is_ok());
+    }
+
+    #[test]
+    fn reset_wcmos() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![(0xFFFC, 0x00), (0xFFFD, 0x40)], Mos6502Variant::Cmos65C02);
+        cpu.set_status_flag(Mos6502Flags::D);
+
+        cpu.reset();
+
+        // CMOS parts also clear D on reset.
+        assert!(!cpu.decimal_mode_flag());
+        assert!(cpu.interrupts_disable_flag());
+    }
+
+    #[test]
+    fn reset_wnmi_pending() {
+        let mut cpu = vector_cpu();
+        cpu.trigger_nmi();
+
+        cpu.reset();
+        cpu.exe(Some(RESET_CCOST + instructions::LDA_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x4002);
+    }
+
+    #[test]
+    fn reset_wjammed() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JAM_IMP_02),
+            (0x0200, instructions::NOP_IMP),
+        ]);
+        cpu.exe(None).unwrap();
+        *cpu.mem.at(0xFFFC) = 0x00;
+        *cpu.mem.at(0xFFFD) = 0x02;
+
+        cpu.reset();
+
+        assert_eq!(cpu.state(), CpuState::Running);
+        assert_eq!(cpu.get_pc(), 0x0200);
+    }
+
+    #[test]
+    fn reset_wtick() {
+        let mut cpu = vector_cpu();
+        *cpu.mem.at(0x4002) = instructions::STA_ZP;
+        *cpu.mem.at(0x4003) = 0x42;
+        cpu.set_pc(0x4002);
+        cpu.set_accumulator(0x37);
+        cpu.tick().unwrap();
+
+        // The write STA had coming never happens.
+        cpu.reset();
+        cpu.exe(Some(cpu.get_cycles() + instructions::LDA_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(0x0042), 0x00);
+        assert_eq!(cpu.get_accumulator(), 0x84);
+    }
+}
@@ -0,0 +1,77 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn rla_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::RLA_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0x40),
+        ]);
+        cpu.set_accumulator(0xF0);
+        cpu.set_status_flag(Mos6502Flags::C);
+
+        cpu.exe(Some(instructions::RLA_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x81);
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::RLA_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn rla_absx_wpage_boundary() {
+        let (lo, hi, xreg) = (0xFF, 0x44, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::RLA_ABSX),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4500, 0x80),
+        ]);
+        cpu.set_xreg(xreg);
+        cpu.set_accumulator(0xFF);
+
+        cpu.exe(Some(instructions::RLA_ABSX_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4500_u16)), 0x00);
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::RLA_ABSX_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::Z]).is_ok());
+    }
+
+    #[test]
+    fn rla_zpy_ind() {
+        let (addr, yreg) = (0x20, 0x10);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::RLA_ZPY_IND),
+            (PC_START + 1, addr),
+            (0x0020, 0x00),
+            (0x0021, 0x44),
+            (0x4410, 0x0F),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_accumulator(0x3C);
+
+        cpu.exe(Some(instructions::RLA_ZPY_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4410_u16)), 0x1E);
+        assert_eq!(cpu.get_accumulator(), 0x1C);
+        assert_eq!(cpu.get_cycles(), instructions::RLA_ZPY_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
@@ -0,0 +1,53 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn rmb3_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::RMB3_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0xFF),
+        ], Mos6502Variant::Rockwell65C02);
+
+        cpu.exe(Some(instructions::RMB3_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0xF7);
+        assert_eq!(cpu.get_cycles(), instructions::RMB3_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn rmb_zp_wall_bits() {
+        let opcodes = [
+            instructions::RMB0_ZP, instructions::RMB1_ZP, instructions::RMB2_ZP, instructions::RMB3_ZP,
+            instructions::RMB4_ZP, instructions::RMB5_ZP, instructions::RMB6_ZP, instructions::RMB7_ZP,
+        ];
+        for (bit, opcode) in opcodes.into_iter().enumerate() {
+            let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+                (PC_START, opcode),
+                (PC_START + 1, 0x42),
+                (0x0042, 0xFF),
+            ], Mos6502Variant::Wdc65C02S);
+
+            cpu.exe(Some(instructions::RMB0_ZP_CCOST)).unwrap();
+
+            assert_eq!(cpu.mem.get_byte(0x0042), !(1 << bit), "bit: {bit}");
+        }
+    }
+}
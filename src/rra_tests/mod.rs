@@ -0,0 +1,74 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn rra_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::RRA_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0x02),
+        ]);
+        cpu.set_accumulator(0x10);
+
+        cpu.exe(Some(instructions::RRA_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x01);
+        assert_eq!(cpu.get_accumulator(), 0x11);
+        assert_eq!(cpu.get_cycles(), instructions::RRA_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn rra_abs_wcarry() {
+        let (lo, hi) = (0x00, 0x44);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::RRA_ABS),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4400, 0x03),
+        ]);
+        cpu.set_accumulator(0x7F);
+
+        cpu.exe(Some(instructions::RRA_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0x01);
+        assert_eq!(cpu.get_accumulator(), 0x81);
+        assert_eq!(cpu.get_cycles(), instructions::RRA_ABS_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::V, Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn rra_zpx() {
+        let (addr, xreg) = (0x40, 0x02);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::RRA_ZPX),
+            (PC_START + 1, addr),
+            (0x0042, 0x01),
+        ]);
+        cpu.set_xreg(xreg);
+        cpu.set_accumulator(0xFF);
+        cpu.set_status_flag(Mos6502Flags::C);
+
+        cpu.exe(Some(instructions::RRA_ZPX_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x0042_u16)), 0x80);
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::RRA_ZPX_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::N]).is_ok());
+    }
+}
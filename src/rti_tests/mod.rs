@@ -0,0 +1,32 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::Memory;
+    use crate::instructions;
+    use crate::tests_utils;
+
+    #[test]
+    fn rti_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::RTI_IMP),
+            (0x01FB, 0xFF),
+            (0x01FC, 0x37),
+            (0x01FD, 0x42),
+        ]);
+
+        cpu.set_pc(0x0200);
+        cpu.set_sp(0xFA);
+        cpu.exe(Some(instructions::RTI_IMP_CCOST)).unwrap();
+
+        // Unlike RTS, the pulled address is not incremented.
+        assert_eq!(cpu.get_pc(), 0x4237);
+        assert_eq!(cpu.get_sp(), 0xFD);
+        assert_eq!(cpu.get_status(), 0xCF);
+        assert_eq!(cpu.get_cycles(), instructions::RTI_IMP_CCOST);
+    }
+}
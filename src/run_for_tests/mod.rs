@@ -0,0 +1,121 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+        StopReason,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+
+    fn nop_cpu() -> Mos6502 {
+        let mut cpu = tests_utils::cpu_mem_set((0x0200..0x0300).map(|addr| (addr, instructions::NOP_IMP)).collect());
+        cpu.set_pc(0x0200);
+        cpu
+    }
+
+    #[test]
+    fn run_for_exact() {
+        let mut cpu = nop_cpu();
+
+        assert_eq!(cpu.run_for(4).unwrap(), (4, StopReason::CycleLimit));
+        assert_eq!(cpu.get_pc(), 0x0202);
+        assert_eq!(cpu.cycle_debt(), 0);
+    }
+
+    #[test]
+    fn run_for_relative() {
+        let mut cpu = nop_cpu();
+        cpu.exe(Some(10)).unwrap();
+
+        assert_eq!(cpu.run_for(4).unwrap(), (4, StopReason::CycleLimit));
+        assert_eq!(cpu.get_cycles(), 14);
+    }
+
+    #[test]
+    fn run_for_woverrun() {
+        let mut cpu = nop_cpu();
+
+        assert_eq!(cpu.run_for(3).unwrap(), (4, StopReason::CycleLimit));
+        assert_eq!(cpu.cycle_debt(), 1);
+
+        // The cycle owed comes off the next slice.
+        assert_eq!(cpu.run_for(3).unwrap(), (2, StopReason::CycleLimit));
+        assert_eq!(cpu.cycle_debt(), 0);
+        assert_eq!(cpu.get_cycles(), 6);
+    }
+
+    #[test]
+    fn run_for_wno_drift() {
+        let mut cpu = nop_cpu();
+
+        let elapsed: u64 = (0..21).map(|_| cpu.run_for(3).unwrap().0).sum();
+
+        assert_eq!(elapsed, cpu.get_cycles());
+        assert_eq!(cpu.get_cycles() - cpu.cycle_debt(), 63);
+    }
+
+    #[test]
+    fn run_for_wdebt_over_slice() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::JSR_ABS),
+            (0x0201, 0x00),
+            (0x0202, 0x03),
+            (0x0300, instructions::NOP_IMP),
+        ]);
+        cpu.set_pc(0x0200);
+
+        assert_eq!(cpu.run_for(1).unwrap(), (instructions::JSR_ABS_CCOST, StopReason::CycleLimit));
+        assert_eq!(cpu.cycle_debt(), instructions::JSR_ABS_CCOST - 1);
+
+        assert_eq!(cpu.run_for(2).unwrap(), (0, StopReason::CycleLimit));
+        assert_eq!(cpu.run_for(3).unwrap(), (0, StopReason::CycleLimit));
+        assert_eq!(cpu.cycle_debt(), 0);
+        assert_eq!(cpu.get_pc(), 0x0300);
+
+        assert_eq!(cpu.run_for(2).unwrap(), (instructions::NOP_IMP_CCOST, StopReason::CycleLimit));
+    }
+
+    #[test]
+    fn run_for_wjam() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x0200, instructions::NOP_IMP),
+            (0x0201, instructions::JAM_IMP_02),
+        ]);
+        cpu.set_pc(0x0200);
+
+        // The rest of the slice is spent jammed.
+        assert_eq!(cpu.run_for(100).unwrap(), (100, StopReason::Jammed));
+        assert_eq!(cpu.cycle_debt(), 0);
+        assert_eq!(cpu.run_for(100).unwrap(), (100, StopReason::Jammed));
+    }
+
+    #[test]
+    fn run_for_wwai() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x0200, instructions::WAI_IMP),
+            (0x0201, instructions::LDA_IMM),
+            (0x0202, 0x84),
+        ], Mos6502Variant::Wdc65C02S);
+        cpu.set_pc(0x0200);
+
+        assert_eq!(cpu.run_for(100).unwrap(), (100, StopReason::Waiting));
+        assert_eq!(cpu.run_for(100).unwrap(), (100, StopReason::Waiting));
+        assert_eq!(cpu.get_cycles(), 200);
+        assert_eq!(cpu.get_pc(), 0x0201);
+
+        // Woken up with I set, execution carries on after the WAI.
+        cpu.set_status_flag(Mos6502Flags::I);
+        cpu.set_irq_line(true);
+        assert_eq!(cpu.run_for(instructions::LDA_IMM_CCOST).unwrap(), (instructions::LDA_IMM_CCOST, StopReason::CycleLimit));
+        assert_eq!(cpu.get_accumulator(), 0x84);
+    }
+}
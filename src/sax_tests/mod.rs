@@ -0,0 +1,91 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn sax_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SAX_ZP),
+            (PC_START + 1, addr),
+        ]);
+        cpu.set_accumulator(0xF0);
+        cpu.set_xreg(0x3C);
+
+        cpu.exe(Some(instructions::SAX_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x30);
+        assert_eq!(cpu.get_cycles(), instructions::SAX_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn sax_zpy_wwrap() {
+        let (addr, yreg) = (0x80, 0xFF);
+        let dest_addr = tests_utils::word_from_byte_addition(addr, yreg);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SAX_ZPY),
+            (PC_START + 1, addr),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_accumulator(0x80);
+        cpu.set_xreg(0xFF);
+
+        cpu.exe(Some(instructions::SAX_ZPY_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(dest_addr)), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::SAX_ZPY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn sax_abs() {
+        let (lo, hi) = (0x00, 0x44);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SAX_ABS),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4400, 0xFF),
+        ]);
+        cpu.set_accumulator(0x0F);
+        cpu.set_xreg(0xF0);
+
+        cpu.exe(Some(instructions::SAX_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::SAX_ABS_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn sax_zpx_ind() {
+        let (addr, xreg) = (0x20, 0x04);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SAX_ZPX_IND),
+            (PC_START + 1, addr),
+            (0x0024, 0x00),
+            (0x0025, 0x44),
+        ]);
+        cpu.set_xreg(xreg);
+        cpu.set_accumulator(0xFF);
+
+        cpu.exe(Some(instructions::SAX_ZPX_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0x04);
+        assert_eq!(cpu.get_cycles(), instructions::SAX_ZPX_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
@@ -0,0 +1,252 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn sbc_imm_binary_matrix() {
+        tests_utils::arithmetic_matrix(
+            instructions::SBC_IMM,
+            instructions::SBC_IMM_CCOST,
+            false,
+            tests_utils::reference_sbc,
+        );
+    }
+
+    #[test]
+    fn sbc_imm_decimal_matrix() {
+        tests_utils::arithmetic_matrix(
+            instructions::SBC_IMM,
+            instructions::SBC_IMM_CCOST,
+            true,
+            tests_utils::reference_sbc,
+        );
+    }
+
+    #[test]
+    fn sbc_zp() {
+        let addr = 0x42;
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::SBC_ZP),
+                (PC_START + 1, addr),
+                (Word::from(addr), 0xB0),
+            ],
+            0xA0,
+            instructions::SBC_ZP_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::V, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x50); cpu.set_status_flag(Mos6502Flags::C); })
+        );
+    }
+
+    #[test]
+    fn sbc_zpx_wwrap() {
+        let (addr, xreg) = (0x80, 0xFF);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::SBC_ZPX),
+                (PC_START + 1, addr),
+                (tests_utils::word_from_byte_addition(addr, xreg), 0xB0),
+            ],
+            0xA0,
+            instructions::SBC_ZPX_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::V, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x50); cpu.set_status_flag(Mos6502Flags::C); cpu.set_xreg(xreg); })
+        );
+    }
+
+    #[test]
+    fn sbc_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::SBC_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo), 0xB0),
+            ],
+            0xA0,
+            instructions::SBC_ABS_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::V, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x50); cpu.set_status_flag(Mos6502Flags::C); })
+        );
+    }
+
+    #[test]
+    fn sbc_absx_wopage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x80, 1);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::SBC_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(xreg), 0xB0),
+            ],
+            0xA0,
+            instructions::SBC_ABSX_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::V, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x50); cpu.set_status_flag(Mos6502Flags::C); cpu.set_xreg(xreg); })
+        );
+    }
+
+    #[test]
+    fn sbc_absx_wpage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x02, 0xFF);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::SBC_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(xreg), 0xB0),
+            ],
+            0xA0,
+            instructions::SBC_ABSX_CCOST + 1,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::V, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x50); cpu.set_status_flag(Mos6502Flags::C); cpu.set_xreg(xreg); })
+        );
+    }
+
+    #[test]
+    fn sbc_absy_wpage_boundary() {
+        let (hi, lo, yreg) = (0x44, 0x02, 0xFF);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::SBC_ABSY),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0xB0),
+            ],
+            0xA0,
+            instructions::SBC_ABSY_CCOST + 1,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::V, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x50); cpu.set_status_flag(Mos6502Flags::C); cpu.set_yreg(yreg); })
+        );
+    }
+
+    #[test]
+    fn sbc_zpx_ind() {
+        let (hi, lo, xreg, arb_addr) = (0x80, 0x00, 0x04, 0x02);
+        let dest_addr = tests_utils::word_from_byte_addition(arb_addr, xreg);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::SBC_ZPX_IND),
+                (PC_START + 1, arb_addr),
+                (dest_addr, lo),
+                (dest_addr + 1, hi),
+                (tests_utils::word_from_bytes(hi, lo), 0xB0),
+            ],
+            0xA0,
+            instructions::SBC_ZPX_IND_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::V, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x50); cpu.set_status_flag(Mos6502Flags::C); cpu.set_xreg(xreg); })
+        );
+    }
+
+    #[test]
+    fn sbc_zpy_ind_wpage_boundary() {
+        let (hi, lo, yreg, arb_addr) = (0x80, 0x02, 0xFF, 0x02);
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::SBC_ZPY_IND),
+                (PC_START + 1, arb_addr),
+                (Word::from(arb_addr), lo),
+                (Word::from(arb_addr) + 1, hi),
+                (tests_utils::word_from_bytes(hi, lo) + Word::from(yreg), 0xB0),
+            ],
+            0xA0,
+            instructions::SBC_ZPY_IND_CCOST + 1,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::V, Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x50); cpu.set_status_flag(Mos6502Flags::C); cpu.set_yreg(yreg); })
+        );
+    }
+
+    #[test]
+    fn usbc_imm() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::USBC_IMM),
+            (PC_START + 1, 0x01),
+        ]);
+        cpu.set_accumulator(0x10);
+        cpu.set_status_flag(Mos6502Flags::C);
+
+        cpu.exe(Some(instructions::USBC_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0F);
+        assert_eq!(cpu.get_cycles(), instructions::USBC_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C]).is_ok());
+    }
+
+    #[test]
+    fn sbc_zp_ind() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::SBC_ZP_IND),
+            (PC_START + 1, 0x20),
+            (0x0020, 0x00),
+            (0x0021, 0x44),
+            (0x4400, 0x01),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x10);
+        cpu.set_status_flag(Mos6502Flags::C);
+
+        cpu.exe(Some(instructions::SBC_ZP_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0F);
+        assert_eq!(cpu.get_cycles(), instructions::SBC_ZP_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C]).is_ok());
+    }
+
+    #[test]
+    fn sbc_imm_wdecimal_cmos() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::SBC_IMM),
+            (PC_START + 1, 0x01),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x00);
+        cpu.set_status_flag(Mos6502Flags::C);
+        cpu.set_status_flag(Mos6502Flags::D);
+
+        cpu.exe(Some(instructions::SBC_IMM_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x99);
+        assert_eq!(cpu.get_cycles(), instructions::SBC_IMM_CCOST + 1);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N, Mos6502Flags::D]).is_ok());
+    }
+
+    #[test]
+    fn sbc_imm_wricoh_ignores_decimal() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::SBC_IMM),
+            (PC_START + 1, 0x01),
+        ], Mos6502Variant::Ricoh2A03);
+        cpu.set_accumulator(0x10);
+        cpu.set_status_flag(Mos6502Flags::C);
+        cpu.set_status_flag(Mos6502Flags::D);
+
+        cpu.exe(Some(instructions::SBC_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0F);
+        assert_eq!(cpu.get_cycles(), instructions::SBC_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::D]).is_ok());
+    }
+}
@@ -0,0 +1,51 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn sbx_imm() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SBX_IMM),
+            (PC_START + 1, 0x05),
+        ]);
+        cpu.set_accumulator(0xFF);
+        cpu.set_xreg(0x0F);
+
+        cpu.exe(Some(instructions::SBX_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_xreg(), 0x0A);
+        assert_eq!(cpu.get_accumulator(), 0xFF);
+        assert_eq!(cpu.get_cycles(), instructions::SBX_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C]).is_ok());
+    }
+
+    #[test]
+    fn sbx_imm_wborrow() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SBX_IMM),
+            (PC_START + 1, 0x10),
+        ]);
+        cpu.set_accumulator(0x0F);
+        cpu.set_xreg(0xFF);
+        cpu.set_status_flag(Mos6502Flags::D);
+
+        cpu.exe(Some(instructions::SBX_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_xreg(), 0xFF);
+        assert_eq!(cpu.get_cycles(), instructions::SBX_IMM_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::D, Mos6502Flags::N]).is_ok());
+    }
+}
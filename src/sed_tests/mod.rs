@@ -1,10 +1,11 @@
-#[allow(unused_imports)]
+#![allow(unused_imports)]
 
 #[cfg(test)]
 mod tests {
     use crate::mos6502::{
         Mos6502,
         Mos6502Flags,
+        Mos6502Variant,
     };
     use crate::memory::Memory;
     use crate::instructions;
@@ -15,9 +16,19 @@ mod tests {
         let mut cpu = tests_utils::cpu_mem_set(vec![
             (0xFFFC, instructions::SED_IMP),
         ]);
-        cpu.exe(Some(instructions::SED_IMP_CCOST));
+        cpu.exe(Some(instructions::SED_IMP_CCOST)).unwrap();
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![Mos6502Flags::D]);
         assert!(cpu.decimal_mode_flag());
         assert_eq!(cpu.get_cycles(), instructions::SED_IMP_CCOST);
     }
+
+    #[test]
+    fn sed_imp_wricoh() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0xFFFC, instructions::SED_IMP),
+        ], Mos6502Variant::Ricoh2A03);
+        cpu.exe(Some(instructions::SED_IMP_CCOST)).unwrap();
+        assert!(cpu.decimal_mode_flag());
+        assert_eq!(cpu.get_cycles(), instructions::SED_IMP_CCOST);
+    }
 }
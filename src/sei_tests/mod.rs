@@ -15,7 +15,7 @@ mod tests {
         let mut cpu = tests_utils::cpu_mem_set(vec![
             (0xFFFC, instructions::SEI_IMP),
         ]);
-        cpu.exe(Some(instructions::SEI_IMP_CCOST));
+        cpu.exe(Some(instructions::SEI_IMP_CCOST)).unwrap();
         // tests_utils::assert_all_status_flags_false_except(&cpu, vec![Mos6502Flags::I]);
         assert!(cpu.interrupts_disable_flag());
         assert_eq!(cpu.get_cycles(), instructions::SEI_IMP_CCOST);
@@ -0,0 +1,76 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn sha_absy() {
+        let (lo, hi, yreg) = (0x00, 0x44, 0x10);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SHA_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_accumulator(0xFF);
+        cpu.set_xreg(0xF7);
+
+        cpu.exe(Some(instructions::SHA_ABSY_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4410_u16)), 0x45);
+        assert_eq!(cpu.get_cycles(), instructions::SHA_ABSY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn sha_absy_wpage_boundary() {
+        let (lo, hi, yreg) = (0xFF, 0x44, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SHA_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_accumulator(0x0F);
+        cpu.set_xreg(0xFF);
+
+        cpu.exe(Some(instructions::SHA_ABSY_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x0500_u16)), 0x05);
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4500_u16)), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::SHA_ABSY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn sha_zpy_ind() {
+        let (addr, yreg) = (0x20, 0x02);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SHA_ZPY_IND),
+            (PC_START + 1, addr),
+            (0x0020, 0x00),
+            (0x0021, 0x44),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_accumulator(0xFF);
+        cpu.set_xreg(0xFF);
+
+        cpu.exe(Some(instructions::SHA_ZPY_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4402_u16)), 0x45);
+        assert_eq!(cpu.get_cycles(), instructions::SHA_ZPY_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
@@ -0,0 +1,54 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn shx_absy() {
+        let (lo, hi, yreg) = (0x00, 0x44, 0x10);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SHX_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_xreg(0xFF);
+
+        cpu.exe(Some(instructions::SHX_ABSY_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4410_u16)), 0x45);
+        assert_eq!(cpu.get_cycles(), instructions::SHX_ABSY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn shx_absy_wpage_boundary() {
+        let (lo, hi, yreg) = (0xF0, 0x44, 0x20);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SHX_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_xreg(0x03);
+
+        cpu.exe(Some(instructions::SHX_ABSY_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x0110_u16)), 0x01);
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4510_u16)), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::SHX_ABSY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
@@ -0,0 +1,53 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn shy_absx() {
+        let (lo, hi, xreg) = (0x00, 0x44, 0x10);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SHY_ABSX),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+        ]);
+        cpu.set_xreg(xreg);
+        cpu.set_yreg(0x0F);
+
+        cpu.exe(Some(instructions::SHY_ABSX_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4410_u16)), 0x05);
+        assert_eq!(cpu.get_cycles(), instructions::SHY_ABSX_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn shy_absx_wpage_boundary() {
+        let (lo, hi, xreg) = (0xFF, 0x44, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SHY_ABSX),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+        ]);
+        cpu.set_xreg(xreg);
+        cpu.set_yreg(0xFF);
+
+        cpu.exe(Some(instructions::SHY_ABSX_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4500_u16)), 0x45);
+        assert_eq!(cpu.get_cycles(), instructions::SHY_ABSX_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
@@ -0,0 +1,94 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn slo_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SLO_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0xC1),
+        ]);
+        cpu.set_accumulator(0x01);
+
+        cpu.exe(Some(instructions::SLO_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x82);
+        assert_eq!(cpu.get_accumulator(), 0x83);
+        assert_eq!(cpu.get_cycles(), instructions::SLO_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn slo_zpx_wwrap() {
+        let (addr, xreg) = (0x80, 0xFF);
+        let dest_addr = tests_utils::word_from_byte_addition(addr, xreg);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SLO_ZPX),
+            (PC_START + 1, addr),
+            (dest_addr, 0x01),
+        ]);
+        cpu.set_xreg(xreg);
+        cpu.set_accumulator(0x10);
+
+        cpu.exe(Some(instructions::SLO_ZPX_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(dest_addr)), 0x02);
+        assert_eq!(cpu.get_accumulator(), 0x12);
+        assert_eq!(cpu.get_cycles(), instructions::SLO_ZPX_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn slo_absy_wpage_boundary() {
+        let (lo, hi, yreg) = (0xFF, 0x44, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SLO_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4500, 0x40),
+        ]);
+        cpu.set_yreg(yreg);
+
+        cpu.exe(Some(instructions::SLO_ABSY_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4500_u16)), 0x80);
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::SLO_ABSY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::N]).is_ok());
+    }
+
+    #[test]
+    fn slo_zpx_ind() {
+        let (addr, xreg) = (0x20, 0x04);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SLO_ZPX_IND),
+            (PC_START + 1, addr),
+            (0x0024, 0x00),
+            (0x0025, 0x44),
+            (0x4400, 0x80),
+        ]);
+        cpu.set_xreg(xreg);
+
+        cpu.exe(Some(instructions::SLO_ZPX_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0x00);
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::SLO_ZPX_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::Z]).is_ok());
+    }
+}
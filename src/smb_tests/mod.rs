@@ -0,0 +1,52 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn smb7_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::SMB7_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0x01),
+        ], Mos6502Variant::Rockwell65C02);
+
+        cpu.exe(Some(instructions::SMB7_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x81);
+        assert_eq!(cpu.get_cycles(), instructions::SMB7_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn smb_zp_wall_bits() {
+        let opcodes = [
+            instructions::SMB0_ZP, instructions::SMB1_ZP, instructions::SMB2_ZP, instructions::SMB3_ZP,
+            instructions::SMB4_ZP, instructions::SMB5_ZP, instructions::SMB6_ZP, instructions::SMB7_ZP,
+        ];
+        for (bit, opcode) in opcodes.into_iter().enumerate() {
+            let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+                (PC_START, opcode),
+                (PC_START + 1, 0x42),
+            ], Mos6502Variant::Wdc65C02S);
+
+            cpu.exe(Some(instructions::SMB0_ZP_CCOST)).unwrap();
+
+            assert_eq!(cpu.mem.get_byte(0x0042), 1 << bit, "bit: {bit}");
+        }
+    }
+}
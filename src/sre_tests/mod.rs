@@ -0,0 +1,74 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn sre_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SRE_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0x03),
+        ]);
+        cpu.set_accumulator(0x01);
+
+        cpu.exe(Some(instructions::SRE_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x01);
+        assert_eq!(cpu.get_accumulator(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::SRE_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::Z]).is_ok());
+    }
+
+    #[test]
+    fn sre_abs() {
+        let (lo, hi) = (0x00, 0x44);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SRE_ABS),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4400, 0x80),
+        ]);
+        cpu.set_accumulator(0x0F);
+
+        cpu.exe(Some(instructions::SRE_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0x40);
+        assert_eq!(cpu.get_accumulator(), 0x4F);
+        assert_eq!(cpu.get_cycles(), instructions::SRE_ABS_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn sre_absy() {
+        let (lo, hi, yreg) = (0x00, 0x44, 0x02);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::SRE_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4402, 0x01),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_accumulator(0x80);
+
+        cpu.exe(Some(instructions::SRE_ABSY_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4402_u16)), 0x00);
+        assert_eq!(cpu.get_accumulator(), 0x80);
+        assert_eq!(cpu.get_cycles(), instructions::SRE_ABSY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::C, Mos6502Flags::N]).is_ok());
+    }
+}
@@ -0,0 +1,217 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn sta_zp() {
+        let addr = 0x42;
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ZP),
+                (PC_START + 1, addr),
+            ],
+            Word::from(addr),
+            0x84,
+            instructions::STA_ZP_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_zpx_wwrap() {
+        let (addr, xreg) = (0x80, 0xFF);
+        let dest_addr = tests_utils::word_from_byte_addition(addr, xreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ZPX),
+                (PC_START + 1, addr),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STA_ZPX_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STA_ABS_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_absx_wopage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x80, 1);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(xreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STA_ABSX_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_absx_wpage_boundary() {
+        let (hi, lo, xreg) = (0x44, 0x02, 0xFF);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(xreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ABSX),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STA_ABSX_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_absy_wopage_boundary() {
+        let (hi, lo, yreg) = (0x44, 0x80, 1);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(yreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ABSY),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STA_ABSY_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_absy_wpage_boundary() {
+        let (hi, lo, yreg) = (0x44, 0x02, 0xFF);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(yreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ABSY),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STA_ABSY_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_zpx_ind() {
+        let (hi, lo, xreg, arb_addr) = (0x80, 0x00, 0x04, 0x02);
+        let ptr_addr = tests_utils::word_from_byte_addition(arb_addr, xreg);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ZPX_IND),
+                (PC_START + 1, arb_addr),
+                (ptr_addr, lo),
+                (ptr_addr + 1, hi),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STA_ZPX_IND_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(xreg); cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_zpy_ind_wopage_boundary() {
+        let (hi, lo, yreg, arb_addr) = (0x80, 0x00, 0x04, 0x02);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(yreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ZPY_IND),
+                (PC_START + 1, arb_addr),
+                (Word::from(arb_addr), lo),
+                (Word::from(arb_addr) + 1, hi),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STA_ZPY_IND_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_zpy_ind_wpage_boundary() {
+        let (hi, lo, yreg, arb_addr) = (0x80, 0x02, 0xFF, 0x02);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo) + Word::from(yreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STA_ZPY_IND),
+                (PC_START + 1, arb_addr),
+                (Word::from(arb_addr), lo),
+                (Word::from(arb_addr) + 1, hi),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STA_ZPY_IND_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn sta_zp_ind() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::STA_ZP_IND),
+            (PC_START + 1, 0x20),
+            (0x0020, 0x00),
+            (0x0021, 0x44),
+            (0x4400, 0x00),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x37);
+
+        cpu.exe(Some(instructions::STA_ZP_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0x37);
+        assert_eq!(cpu.get_cycles(), instructions::STA_ZP_IND_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
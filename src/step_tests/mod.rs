@@ -0,0 +1,258 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Config,
+        Mos6502Flags,
+        Mos6502Variant,
+        UnknownOpcodePolicy,
+    };
+    use crate::opcodes::AddressingMode;
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn step_lda_imm() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::LDA_IMM),
+            (PC_START + 1, 0x84),
+        ]);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.pc, PC_START);
+        assert_eq!(record.opcode, instructions::LDA_IMM);
+        assert_eq!(record.operand, vec![0x84]);
+        assert_eq!(record.mnemonic, "LDA");
+        assert_eq!(record.mode, AddressingMode::Immediate);
+        assert_eq!(record.effective_addr, None);
+        assert_eq!(record.cycles, instructions::LDA_IMM_CCOST);
+        assert_eq!(record.before.a, 0x00);
+        assert_eq!(record.after.a, 0x84);
+        assert_eq!(record.after.pc, PC_START + 2);
+        assert!(Mos6502Flags::N.get(record.after.status));
+        assert_eq!(cpu.get_cycles(), instructions::LDA_IMM_CCOST);
+    }
+
+    #[test]
+    fn step_sta_absx() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::STA_ABSX),
+            (PC_START + 1, 0x00),
+            (PC_START + 2, 0x44),
+        ]);
+        cpu.set_xreg(0x01);
+        cpu.set_accumulator(0x42);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.operand, vec![0x00, 0x44]);
+        assert_eq!(record.mnemonic, "STA");
+        assert_eq!(record.mode, AddressingMode::AbsoluteX);
+        assert_eq!(record.effective_addr, Some(0x4401));
+        assert_eq!(record.cycles, instructions::STA_ABSX_CCOST);
+        assert_eq!(cpu.mem.get_byte(0x4401), 0x42);
+    }
+
+    #[test]
+    fn step_lda_zpy_ind_wpage_boundary() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::LDA_ZPY_IND),
+            (PC_START + 1, 0x20),
+            (0x0020, 0xFF),
+            (0x0021, 0x44),
+            (0x4500, 0x37),
+        ]);
+        cpu.set_yreg(0x01);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.mode, AddressingMode::ZeroPageIndirectY);
+        assert_eq!(record.effective_addr, Some(0x4500));
+        assert_eq!(record.cycles, instructions::LDA_ZPY_IND_CCOST + 1);
+        assert_eq!(record.after.a, 0x37);
+    }
+
+    #[test]
+    fn step_beq_rel_wnot_taken() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x4400, instructions::BEQ_REL),
+            (0x4401, 0x10),
+        ]);
+        cpu.set_pc(0x4400);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.mode, AddressingMode::Relative);
+        assert_eq!(record.effective_addr, Some(0x4412));
+        assert_eq!(record.cycles, instructions::BEQ_REL_CCOST);
+        assert_eq!(record.after.pc, 0x4402);
+    }
+
+    #[test]
+    fn step_jsr_abs() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JSR_ABS),
+            (PC_START + 1, 0x42),
+            (PC_START + 2, 0x44),
+        ]);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.mnemonic, "JSR");
+        assert_eq!(record.effective_addr, Some(0x4442));
+        assert_eq!(record.cycles, instructions::JSR_ABS_CCOST);
+        assert_eq!(record.before.sp, 0xFD);
+        assert_eq!(record.after.sp, 0xFB);
+        assert_eq!(record.after.pc, 0x4442);
+    }
+
+    #[test]
+    fn step_jmp_abs_ind() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JMP_ABS_IND),
+            (PC_START + 1, 0x20),
+            (PC_START + 2, 0x01),
+            (0x0120, 0xFC),
+            (0x0121, 0xBA),
+        ]);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.mode, AddressingMode::AbsoluteIndirect);
+        assert_eq!(record.effective_addr, Some(0xBAFC));
+        assert_eq!(record.cycles, instructions::JMP_ABS_IND_CCOST);
+    }
+
+    #[test]
+    fn step_wirq() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::NOP_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+            (0x4000, instructions::LDA_IMM),
+            (0x4001, 0x01),
+        ]);
+        cpu.set_irq_line(true);
+
+        // The interrupt sequence is folded into the handler's
+        // first instruction.
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.pc, 0x4000);
+        assert_eq!(record.mnemonic, "LDA");
+        assert_eq!(record.cycles, 7 + instructions::LDA_IMM_CCOST);
+        assert!(Mos6502Flags::I.get(record.before.status));
+    }
+
+    #[test]
+    fn step_wjammed() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![(PC_START, instructions::JAM_IMP_02)]);
+
+        let record = cpu.step().unwrap().unwrap();
+        assert_eq!(record.mnemonic, "JAM");
+
+        let cycles = cpu.get_cycles();
+        assert!(cpu.step().unwrap().is_none());
+        assert_eq!(cpu.get_cycles(), cycles);
+    }
+
+    #[test]
+    fn step_wunknown_opcode() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![(PC_START, 0x03)]);
+
+        let err = cpu.step().unwrap_err();
+
+        assert_eq!(err.opcode, 0x03);
+        assert_eq!(err.pc, PC_START);
+    }
+
+    #[test]
+    fn step_wunknown_opcode_wnop() {
+        let mut cpu = tests_utils::cpu_mem_set_wconfig(vec![(PC_START, 0x03)], Mos6502Config {
+            unknown_opcode: UnknownOpcodePolicy::Nop,
+            ..Mos6502Config::default()
+        });
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.mnemonic, "NOP");
+        assert_eq!(record.mode, AddressingMode::Implied);
+        assert!(record.operand.is_empty());
+    }
+
+    #[test]
+    fn step_lax_zp() {
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::LAX_ZP),
+            (PC_START + 1, 0x42),
+            (0x0042, 0x37),
+        ]);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.mnemonic, "LAX");
+        assert_eq!(record.mode, AddressingMode::ZeroPage);
+        assert_eq!(record.effective_addr, Some(0x0042));
+        assert_eq!((record.after.a, record.after.x), (0x37, 0x37));
+    }
+
+    #[test]
+    fn step_bbr0_zp_rel() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x0200, instructions::BBR0_ZP_REL),
+            (0x0201, 0x42),
+            (0x0202, 0x10),
+        ], Mos6502Variant::Rockwell65C02);
+        cpu.set_pc(0x0200);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.mnemonic, "BBR0");
+        assert_eq!(record.mode, AddressingMode::ZeroPageRelative);
+        assert_eq!(record.operand, vec![0x42, 0x10]);
+        assert_eq!(record.effective_addr, Some(0x0042));
+        assert_eq!(record.after.pc, 0x0213);
+    }
+
+    #[test]
+    fn step_wcmos_unused_opcode() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![(PC_START, 0x07)], Mos6502Variant::Cmos65C02);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!(record.mnemonic, "NOP");
+        assert_eq!(record.cycles, 1);
+    }
+
+    #[test]
+    fn step_brk_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::BRK_IMP),
+            (PC_START + 1, 0x42),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        // The signature byte is the operand, so the return address
+        // BRK pushes is the address after it.
+        assert_eq!(record.mnemonic, "BRK");
+        assert_eq!(record.mode, AddressingMode::Immediate);
+        assert_eq!(record.operand, vec![0x42]);
+        assert_eq!(record.effective_addr, None);
+        assert_eq!(record.cycles, instructions::BRK_IMP_CCOST);
+        assert_eq!(record.after.pc, 0x4000);
+        let pushed = Word::from_le_bytes([cpu.mem.get_byte(0x01FC), cpu.mem.get_byte(0x01FD)]);
+        assert_eq!(usize::from(pushed - PC_START), 1 + record.operand.len());
+    }
+}
@@ -0,0 +1,61 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+        CpuState,
+        StopReason,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    fn stp_cpu() -> Mos6502 {
+        tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::STP_IMP),
+        ], Mos6502Variant::Wdc65C02S)
+    }
+
+    #[test]
+    fn stp_imp() {
+        let mut cpu = stp_cpu();
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Stopped);
+        assert_eq!(cpu.state(), CpuState::Stopped);
+        assert_eq!(cpu.get_pc(), PC_START + 1);
+        assert_eq!(cpu.get_cycles(), instructions::STP_IMP_CCOST);
+    }
+
+    #[test]
+    fn stp_imp_wignores_interrupts() {
+        let mut cpu = stp_cpu();
+        cpu.exe(None).unwrap();
+
+        cpu.set_irq_line(true);
+        cpu.trigger_nmi();
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Stopped);
+        assert_eq!(cpu.get_pc(), PC_START + 1);
+    }
+
+    #[test]
+    fn stp_imp_wreset() {
+        let mut cpu = stp_cpu();
+        cpu.exe(None).unwrap();
+
+        cpu.reset();
+        cpu.set_pc(PC_START);
+        *cpu.mem.at(usize::from(PC_START)) = instructions::NOP_IMP;
+
+        assert_eq!(cpu.state(), CpuState::Running);
+        assert_eq!(cpu.exe(Some(cpu.get_cycles() + instructions::NOP_IMP_CCOST)).unwrap(), StopReason::CycleLimit);
+    }
+}
@@ -0,0 +1,68 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn stx_zp() {
+        let addr = 0x42;
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STX_ZP),
+                (PC_START + 1, addr),
+            ],
+            Word::from(addr),
+            0x84,
+            instructions::STX_ZP_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0x84); })
+        );
+    }
+
+    #[test]
+    fn stx_abs() {
+        let (hi, lo) = (0x44, 0x80);
+        let dest_addr = tests_utils::word_from_bytes(hi, lo);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STX_ABS),
+                (PC_START + 1, lo),
+                (PC_START + 2, hi),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STX_ABS_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0x84); })
+        );
+    }
+
+    #[test]
+    fn stx_zpy_wwrap() {
+        let (addr, yreg) = (0x80, 0xFF);
+        let dest_addr = tests_utils::word_from_byte_addition(addr, yreg);
+        tests_utils::write_into_mem(
+            vec![
+                (PC_START, instructions::STX_ZPY),
+                (PC_START + 1, addr),
+            ],
+            dest_addr,
+            0x84,
+            instructions::STX_ZPY_CCOST,
+            vec![],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(yreg); cpu.set_xreg(0x84); })
+        );
+    }
+}
@@ -0,0 +1,87 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn stz_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::STZ_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0xFF),
+        ], Mos6502Variant::Cmos65C02);
+
+        cpu.exe(Some(instructions::STZ_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::STZ_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn stz_zpx_wwrap() {
+        let (addr, xreg) = (0x80, 0xFF);
+        let dest_addr = tests_utils::word_from_byte_addition(addr, xreg);
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::STZ_ZPX),
+            (PC_START + 1, addr),
+            (dest_addr, 0xFF),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_xreg(xreg);
+
+        cpu.exe(Some(instructions::STZ_ZPX_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(dest_addr)), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::STZ_ZPX_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn stz_abs() {
+        let (lo, hi) = (0x00, 0x44);
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::STZ_ABS),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4400, 0xFF),
+        ], Mos6502Variant::Cmos65C02);
+
+        cpu.exe(Some(instructions::STZ_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::STZ_ABS_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn stz_absx_wpage_boundary() {
+        let (lo, hi, xreg) = (0xFF, 0x44, 0x01);
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::STZ_ABSX),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4500, 0xFF),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_xreg(xreg);
+
+        cpu.exe(Some(instructions::STZ_ABSX_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4500_u16)), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::STZ_ABSX_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
@@ -0,0 +1,37 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn tas_absy() {
+        let (lo, hi, yreg) = (0x00, 0x44, 0x10);
+        let mut cpu = tests_utils::cpu_mem_set_willegal(vec![
+            (PC_START, instructions::TAS_ABSY),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+        ]);
+        cpu.set_yreg(yreg);
+        cpu.set_accumulator(0xF3);
+        cpu.set_xreg(0x3F);
+
+        cpu.exe(Some(instructions::TAS_ABSY_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_sp(), 0x33);
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4410_u16)), 0x01);
+        assert_eq!(cpu.get_cycles(), instructions::TAS_ABSY_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
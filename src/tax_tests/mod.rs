@@ -0,0 +1,45 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn tax_imp() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::TAX_IMP),
+            ],
+            0x84,
+            instructions::TAX_IMP_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x84); })
+        );
+    }
+
+    #[test]
+    fn tax_imp_wzero() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::TAX_IMP),
+            ],
+            0x00,
+            instructions::TAX_IMP_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::Z],
+            Some(|cpu: &mut Mos6502| { cpu.set_xreg(0x37); })
+        );
+    }
+}
@@ -2,7 +2,9 @@
 
 use crate::mos6502::{
     Mos6502,
+    Mos6502Config,
     Mos6502Flags,
+    Mos6502Variant,
 };
 
 use crate::memory::{
@@ -49,30 +51,49 @@ impl std::error::Error for FlagAssertionError {}
 pub const PC_START: Word = 0xFFFC;
 
 pub fn cpu_mem_set(instrs: Vec<(u16, u8)>) -> Mos6502 {
+    cpu_mem_set_wconfig(instrs, Mos6502Config::default())
+}
+
+pub fn cpu_mem_set_willegal(instrs: Vec<(u16, u8)>) -> Mos6502 {
+    cpu_mem_set_wconfig(instrs, Mos6502Config { illegal_opcodes: true, ..Mos6502Config::default() })
+}
+
+pub fn cpu_mem_set_wvariant(instrs: Vec<(u16, u8)>, variant: Mos6502Variant) -> Mos6502 {
+    cpu_mem_set_wconfig(instrs, Mos6502Config { variant, ..Mos6502Config::default() })
+}
+
+pub fn cpu_mem_set_wconfig(instrs: Vec<(u16, u8)>, config: Mos6502Config) -> Mos6502 {
     let mut mem = Memory::new();
     for (addr, opcode) in instrs {
         *mem.at(addr as usize) = opcode;
     }
-    let mut cpu = Mos6502::new(mem);
-    cpu.reset(false);
+    let mut cpu = Mos6502::with_config(mem, config);
+    restart(&mut cpu);
     cpu
 }
 
-pub fn assert_all_status_flags_false_except(cpu: &Mos6502, excluded_flags: Vec<Mos6502Flags>) -> Result<(), FlagAssertionError> {
+// Powers the CPU back on and parks it at `PC_START` with clear
+// flags and counters, the state every test starts from.
+pub fn restart(cpu: &mut Mos6502) {
+    cpu.power_on();
+    cpu.set_pc(PC_START);
+    cpu.clear_status_flag(Mos6502Flags::I);
+    cpu.reset_counters();
+}
+
+pub fn assert_all_status_flags_false_except(cpu: &Mos6502, excluded_flags: &[Mos6502Flags]) -> Result<(), FlagAssertionError> {
     let assert_flag_state = |flag: bool, current_flag: Mos6502Flags| {
-        if !excluded_flags.contains(&current_flag) {
-            if flag {
-                Err(match current_flag {
-                    Mos6502Flags::C => FlagAssertionError::Carry,
-                    Mos6502Flags::Z => FlagAssertionError::Zero,
-                    Mos6502Flags::I => FlagAssertionError::InterruptsDisable,
-                    Mos6502Flags::D => FlagAssertionError::DecimalMode,
-                    Mos6502Flags::B => FlagAssertionError::Break,
-                    Mos6502Flags::U => FlagAssertionError::Unused,
-                    Mos6502Flags::V => FlagAssertionError::Overflow,
-                    Mos6502Flags::N => FlagAssertionError::Negative,
-                })?;
-            }
+        if flag && !excluded_flags.contains(&current_flag) {
+            Err(match current_flag {
+                Mos6502Flags::C => FlagAssertionError::Carry,
+                Mos6502Flags::Z => FlagAssertionError::Zero,
+                Mos6502Flags::I => FlagAssertionError::InterruptsDisable,
+                Mos6502Flags::D => FlagAssertionError::DecimalMode,
+                Mos6502Flags::B => FlagAssertionError::Break,
+                Mos6502Flags::U => FlagAssertionError::Unused,
+                Mos6502Flags::V => FlagAssertionError::Overflow,
+                Mos6502Flags::N => FlagAssertionError::Negative,
+            })?;
         }
         Ok(())
     };
@@ -89,24 +110,22 @@ pub fn assert_all_status_flags_false_except(cpu: &Mos6502, excluded_flags: Vec<M
     Ok(())
 }
 
-fn assert_true_flags(cpu: &Mos6502, tflags: &Vec<Mos6502Flags>) {
-    tflags
-        .iter()
-        .for_each(|flag| {
-            match flag {
-                Mos6502Flags::C => assert!(cpu.carry_flag()),
-                Mos6502Flags::Z => assert!(cpu.zero_flag()),
-                Mos6502Flags::I => assert!(cpu.interrupts_disable_flag()),
-                Mos6502Flags::D => assert!(cpu.decimal_mode_flag()),
-                Mos6502Flags::B => assert!(cpu.break_flag()),
-                Mos6502Flags::U => assert!(cpu.unused_flag()),
-                Mos6502Flags::V => assert!(cpu.overflow_flag()),
-                Mos6502Flags::N => assert!(cpu.negative_flag()),
-            }
-        });
+fn assert_true_flags(cpu: &Mos6502, tflags: &[Mos6502Flags]) {
+    for flag in tflags {
+        match flag {
+            Mos6502Flags::C => assert!(cpu.carry_flag()),
+            Mos6502Flags::Z => assert!(cpu.zero_flag()),
+            Mos6502Flags::I => assert!(cpu.interrupts_disable_flag()),
+            Mos6502Flags::D => assert!(cpu.decimal_mode_flag()),
+            Mos6502Flags::B => assert!(cpu.break_flag()),
+            Mos6502Flags::U => assert!(cpu.unused_flag()),
+            Mos6502Flags::V => assert!(cpu.overflow_flag()),
+            Mos6502Flags::N => assert!(cpu.negative_flag()),
+        }
+    }
 }
 
-fn assert_register_has_value(cpu: &Mos6502, register: Registers, val: Byte) {
+fn assert_register_has_value(cpu: &Mos6502, register: &Registers, val: Byte) {
     match register {
         Registers::A => assert_eq!(cpu.get_accumulator(), val),
         Registers::X => assert_eq!(cpu.get_xreg(), val),
@@ -116,18 +135,16 @@ fn assert_register_has_value(cpu: &Mos6502, register: Registers, val: Byte) {
 
 fn perform_ld_asserts(
     cpu: &Mos6502,
-    test_register: Registers,
-    ccost: u32,
+    test_register: &Registers,
+    ccost: u64,
     val: Byte,
-    tflags: Vec<Mos6502Flags>)
+    tflags: &[Mos6502Flags])
 {
-    assert_register_has_value(&cpu, test_register, val);
+    assert_register_has_value(cpu, test_register, val);
     assert_eq!(cpu.get_cycles(), ccost);
-    assert_true_flags(&cpu, &tflags);
-    match assert_all_status_flags_false_except(&cpu, tflags) {
-        Ok(()) => println!("Flag assertion success"),
-        Err(err) => eprintln!("Flag assertion error: {err}"),
-    }
+    assert_true_flags(cpu, tflags);
+    let flags = assert_all_status_flags_false_except(cpu, tflags);
+    assert!(flags.is_ok(), "{flags:?}");
 }
 
 pub fn word_from_bytes(hi: Byte, lo: Byte) -> Word {
@@ -135,13 +152,14 @@ pub fn word_from_bytes(hi: Byte, lo: Byte) -> Word {
 }
 
 pub fn word_from_byte_addition(b1: Byte, b2: Byte) -> Word {
-    (b1 as Word + b2 as Word) % 256
+    (Word::from(b1) + Word::from(b2)) % 256
 }
 
+#[allow(clippy::needless_pass_by_value)]
 pub fn ld_into_reg<F>(
     mem: Vec<(Word, Byte)>,
     val: Byte,
-    ccost: u32,
+    ccost: u64,
     test_register: Registers,
     tflags: Vec<Mos6502Flags>,
     mod_before_exe: Option<F>)
@@ -153,6 +171,168 @@ where F: FnOnce(&mut Mos6502)
         f(&mut cpu);
     }
 
-    cpu.exe(Some(ccost));
-    perform_ld_asserts(&cpu, test_register, ccost, val, tflags);
+    cpu.exe(Some(ccost)).unwrap();
+    perform_ld_asserts(&cpu, &test_register, ccost, val, &tflags);
+}
+
+#[allow(clippy::struct_excessive_bools)]
+pub struct ArithmeticResult {
+    pub a: Byte,
+    pub c: bool,
+    pub z: bool,
+    pub v: bool,
+    pub n: bool,
+}
+
+// Reference model for ADC/SBC on an NMOS 6502, following the
+// sequences in Bruce Clark's "Decimal Mode" tutorial (6502.org).
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+pub fn reference_adc(a: Byte, m: Byte, carry: bool, decimal: bool) -> ArithmeticResult {
+    let c = i32::from(carry);
+    let bin = i32::from(a) + i32::from(m) + c;
+    let sbin = i32::from(a as i8) + i32::from(m as i8) + c;
+
+    let mut res = ArithmeticResult {
+        a: (bin & 0xFF) as Byte,
+        c: bin > 0xFF,
+        z: bin % 0x100 == 0,
+        v: !(-128..=127).contains(&sbin),
+        n: bin & 0x80 != 0,
+    };
+
+    if decimal {
+        // Seq. 1: accumulator and carry.
+        let mut al = i32::from(a & 0x0F) + i32::from(m & 0x0F) + c;
+        if al >= 0x0A {
+            al = ((al + 0x06) & 0x0F) + 0x10;
+        }
+        let mut sum = i32::from(a & 0xF0) + i32::from(m & 0xF0) + al;
+        if sum >= 0xA0 {
+            sum += 0x60;
+        }
+        res.a = (sum & 0xFF) as Byte;
+        res.c = sum >= 0x100;
+
+        // Seq. 2: N and V from the signed intermediate.
+        let signed = i32::from((a & 0xF0) as i8) + i32::from((m & 0xF0) as i8) + al;
+        res.n = signed & 0x80 != 0;
+        res.v = !(-128..=127).contains(&signed);
+    }
+
+    res
+}
+
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+pub fn reference_sbc(a: Byte, m: Byte, carry: bool, decimal: bool) -> ArithmeticResult {
+    let mut res = reference_adc(a, !m, carry, false);
+
+    if decimal {
+        // Seq. 3: only the accumulator differs from binary mode.
+        let b = 1 - i32::from(carry);
+        let mut al = i32::from(a & 0x0F) - i32::from(m & 0x0F) - b;
+        if al < 0 {
+            al = ((al - 0x06) & 0x0F) - 0x10;
+        }
+        let mut diff = i32::from(a & 0xF0) - i32::from(m & 0xF0) + al;
+        if diff < 0 {
+            diff -= 0x60;
+        }
+        res.a = (diff & 0xFF) as Byte;
+    }
+
+    res
+}
+
+// Runs a single two byte instruction once for every accumulator,
+// operand and carry combination and compares it against `reference`.
+pub fn arithmetic_matrix<F>(opcode: Byte, ccost: u64, decimal: bool, reference: F)
+where F: Fn(Byte, Byte, bool, bool) -> ArithmeticResult
+{
+    let mut cpu = cpu_mem_set(vec![(PC_START, opcode)]);
+
+    for a in 0..=Byte::MAX {
+        for m in 0..=Byte::MAX {
+            for carry in [false, true] {
+                restart(&mut cpu);
+                *cpu.mem.at(usize::from(PC_START) + 1) = m;
+                cpu.set_accumulator(a);
+                if carry {
+                    cpu.set_status_flag(Mos6502Flags::C);
+                }
+                if decimal {
+                    cpu.set_status_flag(Mos6502Flags::D);
+                }
+
+                let start = cpu.get_cycles();
+                cpu.exe(Some(start + ccost)).unwrap();
+
+                let expected = reference(a, m, carry, decimal);
+                let ctx = format!("a: {a:02x}, m: {m:02x}, c: {carry}, d: {decimal}");
+                assert_eq!(cpu.get_accumulator(), expected.a, "A ({ctx})");
+                assert_eq!(cpu.carry_flag(), expected.c, "C ({ctx})");
+                assert_eq!(cpu.zero_flag(), expected.z, "Z ({ctx})");
+                assert_eq!(cpu.overflow_flag(), expected.v, "V ({ctx})");
+                assert_eq!(cpu.negative_flag(), expected.n, "N ({ctx})");
+                assert_eq!(cpu.get_cycles() - start, ccost, "cycles ({ctx})");
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn write_into_mem<F>(
+    mem: Vec<(Word, Byte)>,
+    addr: Word,
+    val: Byte,
+    ccost: u64,
+    tflags: Vec<Mos6502Flags>,
+    mod_before_exe: Option<F>)
+where F: FnOnce(&mut Mos6502)
+{
+    let mut cpu = cpu_mem_set(mem);
+
+    if let Some(f) = mod_before_exe {
+        f(&mut cpu);
+    }
+
+    cpu.exe(Some(ccost)).unwrap();
+
+    assert_eq!(cpu.mem.get_byte(usize::from(addr)), val);
+    assert_eq!(cpu.get_cycles(), ccost);
+    assert_true_flags(&cpu, &tflags);
+    assert!(assert_all_status_flags_false_except(&cpu, &tflags).is_ok());
+}
+
+// (pc of the branch, offset, destination, cycles when taken)
+pub const BRANCH_CASES: [(Word, Byte, Word, u64); 5] = [
+    (0x4400, 0x10, 0x4412, 1), // forward
+    (0x44F0, 0x20, 0x4512, 2), // forward, page crossed
+    (0x4450, 0xF0, 0x4442, 1), // backward
+    (0x4400, 0xF0, 0x43F2, 2), // backward, page crossed
+    (0x44FD, 0x01, 0x4500, 2), // forward, page crossed by one byte
+];
+
+fn branch_once(cpu: &mut Mos6502, pc: Word, offset: Byte, set_flags: fn(&mut Mos6502), ccost: u64) {
+    restart(cpu);
+    cpu.set_pc(pc);
+    *cpu.mem.at(usize::from(pc) + 1) = offset;
+    set_flags(cpu);
+
+    let start = cpu.get_cycles();
+    cpu.exe(Some(start + ccost)).unwrap();
+    assert_eq!(cpu.get_cycles() - start, ccost, "pc: {pc:04x}, offset: {offset:02x}");
+}
+
+pub fn branch_matrix(opcode: Byte, ccost: u64, taken: fn(&mut Mos6502), not_taken: fn(&mut Mos6502)) {
+    let mut cpu = cpu_mem_set(vec![]);
+
+    for (pc, offset, dest, penalty) in BRANCH_CASES {
+        *cpu.mem.at(usize::from(pc)) = opcode;
+
+        branch_once(&mut cpu, pc, offset, taken, ccost + penalty);
+        assert_eq!(cpu.get_pc(), dest);
+
+        branch_once(&mut cpu, pc, offset, not_taken, ccost);
+        assert_eq!(cpu.get_pc(), pc.wrapping_add(2));
+    }
 }
@@ -0,0 +1,278 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+        BusCycle,
+        StopReason,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    fn ticks(cpu: &mut Mos6502, n: u64) -> Vec<BusCycle> {
+        (0..n).map(|_| cpu.tick().unwrap()).collect()
+    }
+
+    #[test]
+    fn tick_lda_imm() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::LDA_IMM),
+            (PC_START + 1, 0x84),
+        ]);
+
+        let fetch = cpu.tick().unwrap();
+        assert_eq!(fetch, BusCycle { bank: 0x00, addr: PC_START, data: instructions::LDA_IMM, read: true, sync: true });
+        assert_eq!(cpu.get_cycles(), 1);
+
+        let operand = cpu.tick().unwrap();
+        assert_eq!(operand, BusCycle { bank: 0x00, addr: PC_START + 1, data: 0x84, read: true, sync: false });
+        assert_eq!(cpu.get_cycles(), instructions::LDA_IMM_CCOST);
+        assert_eq!(cpu.get_accumulator(), 0x84);
+    }
+
+    #[test]
+    fn tick_sta_zp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::STA_ZP),
+            (PC_START + 1, 0x42),
+        ]);
+        cpu.set_accumulator(0x37);
+
+        ticks(&mut cpu, instructions::STA_ZP_CCOST - 1);
+        assert_eq!(cpu.mem.get_byte(0x0042), 0x00);
+
+        let write = cpu.tick().unwrap();
+        assert_eq!(write, BusCycle { bank: 0x00, addr: 0x0042, data: 0x37, read: false, sync: false });
+        assert_eq!(cpu.mem.get_byte(0x0042), 0x37);
+    }
+
+    #[test]
+    fn tick_inc_zp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::INC_ZP),
+            (PC_START + 1, 0x42),
+            (0x0042, 0x10),
+        ]);
+
+        let bus = ticks(&mut cpu, instructions::INC_ZP_CCOST);
+
+        // The NMOS part writes the unmodified value back first.
+        let rw: Vec<bool> = bus.iter().map(|b| b.read).collect();
+        assert_eq!(rw, vec![true, true, true, false, false]);
+        assert_eq!((bus[3].addr, bus[3].data), (0x0042, 0x10));
+        assert_eq!((bus[4].addr, bus[4].data), (0x0042, 0x11));
+        assert_eq!(cpu.mem.get_byte(0x0042), 0x11);
+    }
+
+    #[test]
+    fn tick_inc_zp_wcmos() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::INC_ZP),
+            (PC_START + 1, 0x42),
+            (0x0042, 0x10),
+        ], Mos6502Variant::Cmos65C02);
+
+        let bus = ticks(&mut cpu, instructions::INC_ZP_CCOST);
+
+        // The CMOS part reads the operand twice and writes once.
+        let rw: Vec<bool> = bus.iter().map(|b| b.read).collect();
+        assert_eq!(rw, vec![true, true, true, true, false]);
+        assert_eq!((bus[3].addr, bus[3].data), (0x0042, 0x10));
+        assert_eq!((bus[4].addr, bus[4].data), (0x0042, 0x11));
+        assert_eq!(cpu.mem.get_byte(0x0042), 0x11);
+    }
+
+    #[test]
+    fn tick_jsr_abs() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::JSR_ABS),
+            (PC_START + 1, 0x42),
+            (PC_START + 2, 0x44),
+        ]);
+
+        let bus = ticks(&mut cpu, instructions::JSR_ABS_CCOST);
+
+        let rw: Vec<bool> = bus.iter().map(|b| b.read).collect();
+        assert_eq!(rw, vec![true, true, true, false, false, true]);
+        assert_eq!((bus[3].addr, bus[3].data), (0x01FD, 0xFF));
+        assert_eq!((bus[4].addr, bus[4].data), (0x01FC, 0xFE));
+        assert_eq!(bus.iter().filter(|b| b.sync).count(), 1);
+        assert_eq!(cpu.get_pc(), 0x4442);
+    }
+
+    #[test]
+    fn tick_wirq() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::NOP_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+        cpu.set_irq_line(true);
+
+        let bus = ticks(&mut cpu, 7);
+
+        assert_eq!(bus.iter().filter(|b| !b.read).count(), 3);
+        assert_eq!((bus[5].addr, bus[6].addr), (0xFFFE, 0xFFFF));
+        assert_eq!(cpu.get_pc(), 0x4000);
+        assert_eq!(cpu.get_cycles(), 7);
+    }
+
+    #[test]
+    fn tick_wjammed() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![(PC_START, instructions::JAM_IMP_02)]);
+        cpu.exe(None).unwrap();
+        let cycles = cpu.get_cycles();
+
+        // The clock keeps running even though nothing executes.
+        let bus = ticks(&mut cpu, 3);
+
+        assert!(bus.iter().all(|b| b.read && !b.sync));
+        assert_eq!(cpu.get_cycles(), cycles + 3);
+    }
+
+    #[test]
+    fn tick_wexe() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::STA_ZP),
+            (PC_START + 1, 0x42),
+        ]);
+        cpu.set_accumulator(0x37);
+
+        cpu.tick().unwrap();
+        assert_eq!(cpu.exe(Some(instructions::STA_ZP_CCOST)).unwrap(), StopReason::CycleLimit);
+
+        // `exe` finishes the instruction `tick` started.
+        assert_eq!(cpu.mem.get_byte(0x0042), 0x37);
+        assert_eq!(cpu.get_cycles(), instructions::STA_ZP_CCOST);
+    }
+
+    #[test]
+    fn tick_wunknown_opcode() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![(PC_START, 0x03)]);
+
+        let err = cpu.tick().unwrap_err();
+
+        assert_eq!((err.opcode, err.pc), (0x03, PC_START));
+    }
+
+    #[test]
+    fn tick_wmem_changed_between_ticks() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::LDA_ABS),
+            (PC_START + 1, 0x00),
+            (PC_START + 2, 0x44),
+            (0x4400, 0x11),
+        ]);
+
+        cpu.tick().unwrap();
+        // Another chip on the bus writes between our clocks.
+        *cpu.mem.at(0x4400) = 0x22;
+        ticks(&mut cpu, 2);
+        assert_eq!(cpu.get_accumulator(), 0x00);
+
+        let read = cpu.tick().unwrap();
+
+        assert_eq!(read, BusCycle { bank: 0x00, addr: 0x4400, data: 0x22, read: true, sync: false });
+        assert_eq!(cpu.get_accumulator(), 0x22);
+        assert_eq!(cpu.get_cycles(), instructions::LDA_ABS_CCOST);
+    }
+
+    #[test]
+    fn tick_wmem_changed_before_last_tick() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::LDA_ABS),
+            (PC_START + 1, 0x00),
+            (PC_START + 2, 0x44),
+            (0x4400, 0x11),
+        ]);
+
+        ticks(&mut cpu, instructions::LDA_ABS_CCOST - 1);
+        *cpu.mem.at(0x4400) = 0x22;
+        cpu.tick().unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x22);
+    }
+
+    #[test]
+    fn tick_wirq_before_last_cycle() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::LDA_ABS),
+            (PC_START + 1, 0x00),
+            (PC_START + 2, 0x44),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+
+        ticks(&mut cpu, 2);
+        cpu.set_irq_line(true);
+        ticks(&mut cpu, instructions::LDA_ABS_CCOST - 2);
+
+        // Seen on the second to last cycle, taken right after.
+        let bus = ticks(&mut cpu, 7);
+        assert!(bus.iter().all(|b| !b.sync));
+        assert_eq!(cpu.get_pc(), 0x4000);
+    }
+
+    #[test]
+    fn tick_wirq_on_last_cycle() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x4400, instructions::NOP_IMP),
+            (0x4401, instructions::NOP_IMP),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+        cpu.set_pc(0x4400);
+
+        cpu.tick().unwrap();
+        cpu.set_irq_line(true);
+        cpu.tick().unwrap();
+
+        // Too late for the first NOP, the second one still runs.
+        let fetch = cpu.tick().unwrap();
+        assert_eq!(fetch, BusCycle { bank: 0x00, addr: 0x4401, data: instructions::NOP_IMP, read: true, sync: true });
+        ticks(&mut cpu, instructions::NOP_IMP_CCOST - 1 + 7);
+        assert_eq!(cpu.get_pc(), 0x4000);
+    }
+
+    #[test]
+    fn tick_wnmi_mid_instruction() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (0x4400, instructions::LDA_ZP),
+            (0x4401, 0x42),
+            (0xFFFA, 0x00),
+            (0xFFFB, 0x50),
+        ]);
+        cpu.set_pc(0x4400);
+
+        cpu.tick().unwrap();
+        cpu.trigger_nmi();
+
+        let bus = ticks(&mut cpu, instructions::LDA_ZP_CCOST - 1 + 7);
+        assert_eq!((bus[7].addr, bus[8].addr), (0xFFFA, 0xFFFB));
+        assert_eq!(cpu.get_pc(), 0x5000);
+    }
+
+    #[test]
+    fn tick_wio_port_write() {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::STA_ZP),
+            (PC_START + 1, 0x00),
+        ], Mos6502Variant::Mos6510);
+        cpu.set_accumulator(0x2F);
+
+        ticks(&mut cpu, instructions::STA_ZP_CCOST - 1);
+        assert_eq!(cpu.io_port().ddr(), 0x00);
+
+        cpu.tick().unwrap();
+        assert_eq!(cpu.io_port().ddr(), 0x2F);
+    }
+}
@@ -0,0 +1,54 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn trb_zp() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::TRB_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0xFF),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x0F);
+
+        cpu.exe(Some(instructions::TRB_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0xF0);
+        assert_eq!(cpu.get_accumulator(), 0x0F);
+        assert_eq!(cpu.get_cycles(), instructions::TRB_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+
+    #[test]
+    fn trb_abs_wzero() {
+        let (lo, hi) = (0x00, 0x44);
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::TRB_ABS),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4400, 0xF0),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x0F);
+
+        cpu.exe(Some(instructions::TRB_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0xF0);
+        assert_eq!(cpu.get_cycles(), instructions::TRB_ABS_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z]).is_ok());
+    }
+}
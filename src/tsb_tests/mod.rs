@@ -0,0 +1,54 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn tsb_zp_wzero() {
+        let addr = 0x42;
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::TSB_ZP),
+            (PC_START + 1, addr),
+            (Word::from(addr), 0xF0),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x0F);
+
+        cpu.exe(Some(instructions::TSB_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(Word::from(addr))), 0xFF);
+        assert_eq!(cpu.get_accumulator(), 0x0F);
+        assert_eq!(cpu.get_cycles(), instructions::TSB_ZP_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[Mos6502Flags::Z]).is_ok());
+    }
+
+    #[test]
+    fn tsb_abs() {
+        let (lo, hi) = (0x00, 0x44);
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (PC_START, instructions::TSB_ABS),
+            (PC_START + 1, lo),
+            (PC_START + 2, hi),
+            (0x4400, 0x01),
+        ], Mos6502Variant::Cmos65C02);
+        cpu.set_accumulator(0x81);
+
+        cpu.exe(Some(instructions::TSB_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.mem.get_byte(usize::from(0x4400_u16)), 0x81);
+        assert_eq!(cpu.get_cycles(), instructions::TSB_ABS_CCOST);
+        assert!(tests_utils::assert_all_status_flags_false_except(&cpu, &[]).is_ok());
+    }
+}
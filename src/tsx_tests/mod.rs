@@ -0,0 +1,45 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn tsx_imp() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::TSX_IMP),
+            ],
+            0xfd,
+            instructions::TSX_IMP_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::N],
+            None::<fn(&mut Mos6502)>
+        );
+    }
+
+    #[test]
+    fn tsx_imp_wzero() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::TSX_IMP),
+            ],
+            0x00,
+            instructions::TSX_IMP_CCOST,
+            tests_utils::Registers::X,
+            vec![Mos6502Flags::Z],
+            Some(|cpu: &mut Mos6502| { cpu.set_sp(0x00); })
+        );
+    }
+}
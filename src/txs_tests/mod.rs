@@ -0,0 +1,45 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn txs_imp() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::TXS_IMP),
+        ]);
+
+        cpu.set_xreg(0x00);
+        cpu.exe(Some(instructions::TXS_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_sp(), 0x00);
+        assert_eq!(cpu.get_cycles(), instructions::TXS_IMP_CCOST);
+        // TXS does not touch the flags, even when moving zero.
+        assert!(!cpu.zero_flag());
+    }
+
+    #[test]
+    fn txs_imp_wnegative() {
+        let mut cpu = tests_utils::cpu_mem_set(vec![
+            (PC_START, instructions::TXS_IMP),
+        ]);
+
+        cpu.set_xreg(0x84);
+        cpu.exe(Some(instructions::TXS_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_sp(), 0x84);
+        assert!(!cpu.negative_flag());
+    }
+}
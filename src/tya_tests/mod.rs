@@ -0,0 +1,45 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    #[test]
+    fn tya_imp() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::TYA_IMP),
+            ],
+            0x84,
+            instructions::TYA_IMP_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::N],
+            Some(|cpu: &mut Mos6502| { cpu.set_yreg(0x84); })
+        );
+    }
+
+    #[test]
+    fn tya_imp_wzero() {
+        tests_utils::ld_into_reg(
+            vec![
+                (PC_START, instructions::TYA_IMP),
+            ],
+            0x00,
+            instructions::TYA_IMP_CCOST,
+            tests_utils::Registers::A,
+            vec![Mos6502Flags::Z],
+            Some(|cpu: &mut Mos6502| { cpu.set_accumulator(0x37); })
+        );
+    }
+}
@@ -0,0 +1,2809 @@
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use crate::memory::{Memory, LONG_MEM_MAX};
+use crate::instructions::BRK_IMP;
+use crate::mos6502::{BusCycle, Core, CpuState, ExecError, Mos6502, Mos6502Config, RegisterState, StopReason};
+
+type Byte = u8;
+type Word = u16;
+type Addr = u32;
+
+#[derive(PartialEq)]
+pub enum W65c816Flags {
+    C = 1 << 0, // Carry
+    Z = 1 << 1, // Zero
+    I = 1 << 2, // Interrupts disable
+    D = 1 << 3, // Decimal mode
+    X = 1 << 4, // Index register width (B on the stack in emulation mode)
+    M = 1 << 5, // Accumulator/memory width
+    V = 1 << 6, // Overflow
+    N = 1 << 7, // Negative
+}
+
+impl W65c816Flags {
+    fn set(self, status: &mut Byte) {
+        *status |= self as Byte;
+    }
+
+    fn clear(self, status: &mut Byte) {
+        *status &= !(self as Byte);
+    }
+
+    fn assign(self, status: &mut Byte, state: bool) {
+        if state {
+            self.set(status);
+        } else {
+            self.clear(status);
+        }
+    }
+
+    pub fn get(self, status: Byte) -> bool {
+        status & self as Byte != 0
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct W65c816RegisterState {
+    pub a: Word,
+    pub x: Word,
+    pub y: Word,
+    pub sp: Word,
+    pub dp: Word,
+    pub dbr: Byte,
+    pub pbr: Byte,
+    pub pc: Word,
+    pub status: Byte,
+    pub e: bool,
+}
+
+// What `step()` ran. `pc` is the full 24-bit address of the opcode.
+#[derive(Clone, Debug)]
+pub struct W65c816StepRecord {
+    pub pc: Addr,
+    pub opcode: Byte,
+    pub cycles: u64,
+    pub before: W65c816RegisterState,
+    pub after: W65c816RegisterState,
+}
+
+// NOTE: The W65C816S. Starts out in emulation mode (E set), where
+// it runs 6502 code with 8-bit registers, the stack in page one and
+// the 6502 vectors. XCE switches to native mode, which unlocks the
+// 16-bit registers (M and X clear), the movable direct page, the
+// data and program bank registers and the rest of the opcodes.
+//
+// Built around a `Mos6502` core, which owns the memory, the
+// clock, the bus and the run loop. In emulation mode with the
+// direct page and both banks at zero the 6502's documented opcodes
+// run on the core itself. Otherwise the 65C816 handlers run, and
+// in emulation mode they follow the NMOS part as well: the
+// JMP ($xxFF) page wrap, the double write of read-modify-write
+// instructions, NMOS decimal flags, a BRK taken over by an IRQ or
+// NMI, and D left alone by interrupts and reset. What still differs
+// with DP, DBR or PBR away from zero is what only they can do: code
+// and data live in the banks PBR and DBR pick, indexing can carry
+// into the next bank, a direct page off a page boundary costs a
+// cycle per access and doesn't wrap within the page, and dummy
+// cycles read PBR:PC instead of the addresses the 6502 puts out.
+// The opcodes the 6502 doesn't document always run as 65C816
+// instructions, whatever DP, DBR and PBR hold.
+pub struct W65c816 {
+    // NOTE: The full 16-bit C accumulator. A is the low byte and
+    // B the high byte, B is left alone while M is set.
+    a: Word,
+    x: Word,
+    y: Word,
+    status: Byte,
+    e: bool,
+    sp: Word,
+    dp: Word,
+    dbr: Byte,
+    pbr: Byte,
+    pc: Word,
+    tick_regs: W65c816RegisterState,
+    breakpoints: HashSet<Addr>,
+    core: Mos6502,
+}
+
+impl std::fmt::Display for W65c816 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a: {:x}\nx: {:x}\ny: {:x}\nstatus: {:x}\ne: {}\nsp: {:x}\ndp: {:x}\ndbr: {:x}\npbr: {:x}\npc: {:x}\ncycles: {:x}",
+               self.a, self.x, self.y, self.status, self.e, self.sp, self.dp, self.dbr, self.pbr, self.pc, self.core.get_cycles())
+    }
+}
+
+const LOOKUP_TBL_SIZE: usize = 0x100;
+const ADDR_MASK: Addr = 0x00FF_FFFF;
+
+// Emulation mode vectors, the same as the 6502 plus COP.
+const EMU_COP_VECTOR: Word = 0xFFF4;
+const EMU_NMI_VECTOR: Word = 0xFFFA;
+const EMU_RESET_VECTOR: Word = 0xFFFC;
+const EMU_IRQ_VECTOR: Word = 0xFFFE;
+
+// Native mode vectors, BRK gets its own.
+const COP_VECTOR: Word = 0xFFE4;
+const BRK_VECTOR: Word = 0xFFE6;
+const NMI_VECTOR: Word = 0xFFEA;
+const IRQ_VECTOR: Word = 0xFFEE;
+
+const LOOKUP: [fn(&mut W65c816); LOOKUP_TBL_SIZE] = [
+    W65c816::brk_imp,
+    W65c816::ora_dpx_ind,
+    W65c816::cop_imp,
+    W65c816::ora_sr,
+    W65c816::tsb_dp,
+    W65c816::ora_dp,
+    W65c816::asl_dp,
+    W65c816::ora_dp_ind_long,
+    W65c816::php_imp,
+    W65c816::ora_imm,
+    W65c816::asl_acc,
+    W65c816::phd_imp,
+    W65c816::tsb_abs,
+    W65c816::ora_abs,
+    W65c816::asl_abs,
+    W65c816::ora_long,
+    W65c816::bpl_rel,
+    W65c816::ora_dpy_ind,
+    W65c816::ora_dp_ind,
+    W65c816::ora_sry_ind,
+    W65c816::trb_dp,
+    W65c816::ora_dpx,
+    W65c816::asl_dpx,
+    W65c816::ora_dpy_ind_long,
+    W65c816::clc_imp,
+    W65c816::ora_absy,
+    W65c816::inc_acc,
+    W65c816::tcs_imp,
+    W65c816::trb_abs,
+    W65c816::ora_absx,
+    W65c816::asl_absx,
+    W65c816::ora_longx,
+    W65c816::jsr_abs,
+    W65c816::and_dpx_ind,
+    W65c816::jsl_long,
+    W65c816::and_sr,
+    W65c816::bit_dp,
+    W65c816::and_dp,
+    W65c816::rol_dp,
+    W65c816::and_dp_ind_long,
+    W65c816::plp_imp,
+    W65c816::and_imm,
+    W65c816::rol_acc,
+    W65c816::pld_imp,
+    W65c816::bit_abs,
+    W65c816::and_abs,
+    W65c816::rol_abs,
+    W65c816::and_long,
+    W65c816::bmi_rel,
+    W65c816::and_dpy_ind,
+    W65c816::and_dp_ind,
+    W65c816::and_sry_ind,
+    W65c816::bit_dpx,
+    W65c816::and_dpx,
+    W65c816::rol_dpx,
+    W65c816::and_dpy_ind_long,
+    W65c816::sec_imp,
+    W65c816::and_absy,
+    W65c816::dec_acc,
+    W65c816::tsc_imp,
+    W65c816::bit_absx,
+    W65c816::and_absx,
+    W65c816::rol_absx,
+    W65c816::and_longx,
+    W65c816::rti_imp,
+    W65c816::eor_dpx_ind,
+    W65c816::wdm_imp,
+    W65c816::eor_sr,
+    W65c816::mvp_blk,
+    W65c816::eor_dp,
+    W65c816::lsr_dp,
+    W65c816::eor_dp_ind_long,
+    W65c816::pha_imp,
+    W65c816::eor_imm,
+    W65c816::lsr_acc,
+    W65c816::phk_imp,
+    W65c816::jmp_abs,
+    W65c816::eor_abs,
+    W65c816::lsr_abs,
+    W65c816::eor_long,
+    W65c816::bvc_rel,
+    W65c816::eor_dpy_ind,
+    W65c816::eor_dp_ind,
+    W65c816::eor_sry_ind,
+    W65c816::mvn_blk,
+    W65c816::eor_dpx,
+    W65c816::lsr_dpx,
+    W65c816::eor_dpy_ind_long,
+    W65c816::cli_imp,
+    W65c816::eor_absy,
+    W65c816::phy_imp,
+    W65c816::tcd_imp,
+    W65c816::jml_long,
+    W65c816::eor_absx,
+    W65c816::lsr_absx,
+    W65c816::eor_longx,
+    W65c816::rts_imp,
+    W65c816::adc_dpx_ind,
+    W65c816::per_rel_long,
+    W65c816::adc_sr,
+    W65c816::stz_dp,
+    W65c816::adc_dp,
+    W65c816::ror_dp,
+    W65c816::adc_dp_ind_long,
+    W65c816::pla_imp,
+    W65c816::adc_imm,
+    W65c816::ror_acc,
+    W65c816::rtl_imp,
+    W65c816::jmp_abs_ind,
+    W65c816::adc_abs,
+    W65c816::ror_abs,
+    W65c816::adc_long,
+    W65c816::bvs_rel,
+    W65c816::adc_dpy_ind,
+    W65c816::adc_dp_ind,
+    W65c816::adc_sry_ind,
+    W65c816::stz_dpx,
+    W65c816::adc_dpx,
+    W65c816::ror_dpx,
+    W65c816::adc_dpy_ind_long,
+    W65c816::sei_imp,
+    W65c816::adc_absy,
+    W65c816::ply_imp,
+    W65c816::tdc_imp,
+    W65c816::jmp_absx_ind,
+    W65c816::adc_absx,
+    W65c816::ror_absx,
+    W65c816::adc_longx,
+    W65c816::bra_rel,
+    W65c816::sta_dpx_ind,
+    W65c816::brl_rel_long,
+    W65c816::sta_sr,
+    W65c816::sty_dp,
+    W65c816::sta_dp,
+    W65c816::stx_dp,
+    W65c816::sta_dp_ind_long,
+    W65c816::dey_imp,
+    W65c816::bit_imm,
+    W65c816::txa_imp,
+    W65c816::phb_imp,
+    W65c816::sty_abs,
+    W65c816::sta_abs,
+    W65c816::stx_abs,
+    W65c816::sta_long,
+    W65c816::bcc_rel,
+    W65c816::sta_dpy_ind,
+    W65c816::sta_dp_ind,
+    W65c816::sta_sry_ind,
+    W65c816::sty_dpx,
+    W65c816::sta_dpx,
+    W65c816::stx_dpy,
+    W65c816::sta_dpy_ind_long,
+    W65c816::tya_imp,
+    W65c816::sta_absy,
+    W65c816::txs_imp,
+    W65c816::txy_imp,
+    W65c816::stz_abs,
+    W65c816::sta_absx,
+    W65c816::stz_absx,
+    W65c816::sta_longx,
+    W65c816::ldy_imm,
+    W65c816::lda_dpx_ind,
+    W65c816::ldx_imm,
+    W65c816::lda_sr,
+    W65c816::ldy_dp,
+    W65c816::lda_dp,
+    W65c816::ldx_dp,
+    W65c816::lda_dp_ind_long,
+    W65c816::tay_imp,
+    W65c816::lda_imm,
+    W65c816::tax_imp,
+    W65c816::plb_imp,
+    W65c816::ldy_abs,
+    W65c816::lda_abs,
+    W65c816::ldx_abs,
+    W65c816::lda_long,
+    W65c816::bcs_rel,
+    W65c816::lda_dpy_ind,
+    W65c816::lda_dp_ind,
+    W65c816::lda_sry_ind,
+    W65c816::ldy_dpx,
+    W65c816::lda_dpx,
+    W65c816::ldx_dpy,
+    W65c816::lda_dpy_ind_long,
+    W65c816::clv_imp,
+    W65c816::lda_absy,
+    W65c816::tsx_imp,
+    W65c816::tyx_imp,
+    W65c816::ldy_absx,
+    W65c816::lda_absx,
+    W65c816::ldx_absy,
+    W65c816::lda_longx,
+    W65c816::cpy_imm,
+    W65c816::cmp_dpx_ind,
+    W65c816::rep_imm,
+    W65c816::cmp_sr,
+    W65c816::cpy_dp,
+    W65c816::cmp_dp,
+    W65c816::dec_dp,
+    W65c816::cmp_dp_ind_long,
+    W65c816::iny_imp,
+    W65c816::cmp_imm,
+    W65c816::dex_imp,
+    W65c816::wai_imp,
+    W65c816::cpy_abs,
+    W65c816::cmp_abs,
+    W65c816::dec_abs,
+    W65c816::cmp_long,
+    W65c816::bne_rel,
+    W65c816::cmp_dpy_ind,
+    W65c816::cmp_dp_ind,
+    W65c816::cmp_sry_ind,
+    W65c816::pei_dp_ind,
+    W65c816::cmp_dpx,
+    W65c816::dec_dpx,
+    W65c816::cmp_dpy_ind_long,
+    W65c816::cld_imp,
+    W65c816::cmp_absy,
+    W65c816::phx_imp,
+    W65c816::stp_imp,
+    W65c816::jml_abs_ind_long,
+    W65c816::cmp_absx,
+    W65c816::dec_absx,
+    W65c816::cmp_longx,
+    W65c816::cpx_imm,
+    W65c816::sbc_dpx_ind,
+    W65c816::sep_imm,
+    W65c816::sbc_sr,
+    W65c816::cpx_dp,
+    W65c816::sbc_dp,
+    W65c816::inc_dp,
+    W65c816::sbc_dp_ind_long,
+    W65c816::inx_imp,
+    W65c816::sbc_imm,
+    W65c816::nop_imp,
+    W65c816::xba_imp,
+    W65c816::cpx_abs,
+    W65c816::sbc_abs,
+    W65c816::inc_abs,
+    W65c816::sbc_long,
+    W65c816::beq_rel,
+    W65c816::sbc_dpy_ind,
+    W65c816::sbc_dp_ind,
+    W65c816::sbc_sry_ind,
+    W65c816::pea_abs,
+    W65c816::sbc_dpx,
+    W65c816::inc_dpx,
+    W65c816::sbc_dpy_ind_long,
+    W65c816::sed_imp,
+    W65c816::sbc_absy,
+    W65c816::plx_imp,
+    W65c816::xce_imp,
+    W65c816::jsr_absx_ind,
+    W65c816::sbc_absx,
+    W65c816::inc_absx,
+    W65c816::sbc_longx,
+];
+
+impl W65c816 {
+    pub fn new(mem: Memory) -> Self {
+        Self::with_config(mem, Mos6502Config::default())
+    }
+
+    // NOTE: The config goes to the `Mos6502` core. `clock_hz` sets
+    // the emulated time and `jmp_indirect_bug` the JMP ($xxFF) page
+    // wrap in emulation mode.
+    pub fn with_config(mem: Memory, config: Mos6502Config) -> Self {
+        Self {
+            a: 0x0000,
+            x: 0x0000,
+            y: 0x0000,
+            status: 0x00,
+            e: true,
+            sp: 0x0100,
+            dp: 0x0000,
+            dbr: 0x00,
+            pbr: 0x00,
+            pc: 0x0000,
+            tick_regs: W65c816RegisterState::default(),
+            breakpoints: HashSet::new(),
+            core: Mos6502::with_config(mem, config),
+        }
+    }
+
+    pub fn with_long_memory() -> Self {
+        Self::new(Memory::with_size(LONG_MEM_MAX))
+    }
+
+    // NOTE: Cold start, registers are zeroed before the reset
+    // sequence runs. Memory is left to the caller.
+    pub fn power_on(&mut self) {
+        (self.a, self.x, self.y) = (0x0000, 0x0000, 0x0000);
+        self.sp = 0x0000;
+        self.status = 0x00;
+        self.core.cold_start();
+        self.reset();
+    }
+
+    // NOTE: Warm reset, the same 7 cycle sequence as the 6502: two
+    // internal cycles, three stack reads in place of the pushes and
+    // the reset vector fetch. Reset always drops back into emulation
+    // mode with the direct page and both banks at zero and M, X and
+    // I set. D is left alone like on the NMOS part. The stack is
+    // forced into page one and the high bytes of X and Y are cleared,
+    // the low bytes, A, B and the other flags are left alone. Resets
+    // also abort whatever `tick()` was in the middle of.
+    pub fn reset(&mut self) {
+        self.core.restart();
+        self.e = true;
+        (self.dp, self.dbr, self.pbr) = (0x0000, 0x00, 0x00);
+        self.status |= W65c816Flags::M as Byte | W65c816Flags::X as Byte;
+        self.widths_changed();
+
+        self.cycle();
+        self.cycle();
+        for _ in 0..3 {
+            self.read_byte_at_addr(Addr::from(self.sp));
+            self.sp = 0x0100 | (self.sp.wrapping_sub(1) & 0x00FF);
+        }
+        W65c816Flags::I.set(&mut self.status);
+        self.pc = self.read_data(Addr::from(EMU_RESET_VECTOR), true);
+    }
+
+    // NOTE: Same contract as `Mos6502::exe`, the run loop is the
+    // core's.
+    pub fn exe(&mut self, cycle_limit: Option<u64>) -> Result<StopReason, ExecError> {
+        self.run_until(cycle_limit)
+    }
+
+    // NOTE: Same contract as `Mos6502::run_for`.
+    pub fn run_for(&mut self, cycles: u64) -> Result<(u64, StopReason), ExecError> {
+        self.run_slice(cycles)
+    }
+
+    pub fn cycle_debt(&self) -> u64 {
+        self.core.cycle_debt()
+    }
+
+    // NOTE: Same contract as `Mos6502::step`.
+    pub fn step(&mut self) -> Result<Option<W65c816StepRecord>, ExecError> {
+        self.finish_instruction()?;
+        if self.core.wake().is_some() {
+            return Ok(None);
+        }
+        let start: u64 = self.core.get_cycles();
+        self.take_interrupt();
+
+        let before: W65c816RegisterState = self.registers();
+        let pc: Addr = Self::long_addr(self.pbr, self.pc);
+        let opcode: Byte = self.core.mem.get_byte(pc as usize);
+        self.run_instruction()?;
+
+        Ok(Some(W65c816StepRecord {
+            pc,
+            opcode,
+            cycles: self.core.get_cycles() - start,
+            before,
+            after: self.registers(),
+        }))
+    }
+
+    // NOTE: Same contract as `Mos6502::tick`, `bank` on the bus
+    // carries the top byte of the address.
+    pub fn tick(&mut self) -> Result<BusCycle, ExecError> {
+        self.clock()
+    }
+
+    pub fn registers(&self) -> W65c816RegisterState {
+        W65c816RegisterState {
+            a: self.a,
+            x: self.x,
+            y: self.y,
+            sp: self.sp,
+            dp: self.dp,
+            dbr: self.dbr,
+            pbr: self.pbr,
+            pc: self.pc,
+            status: self.status,
+            e: self.e,
+        }
+    }
+
+    fn set_registers(&mut self, regs: W65c816RegisterState) {
+        (self.a, self.x, self.y) = (regs.a, regs.x, regs.y);
+        (self.sp, self.dp, self.dbr, self.pbr) = (regs.sp, regs.dp, regs.dbr, regs.pbr);
+        (self.pc, self.status, self.e) = (regs.pc, regs.status, regs.e);
+    }
+
+    // Breakpoints are full 24-bit addresses, bank included.
+    pub fn add_breakpoint(&mut self, addr: Addr) {
+        self.breakpoints.insert(addr & ADDR_MASK);
+    }
+
+    pub fn remove_breakpoint(&mut self, addr: Addr) {
+        self.breakpoints.remove(&(addr & ADDR_MASK));
+    }
+
+    pub fn halt_handle(&self) -> Arc<AtomicBool> {
+        self.core.halt_handle()
+    }
+
+    pub fn request_halt(&self) {
+        self.core.request_halt();
+    }
+
+    pub fn mem(&self) -> &Memory {
+        &self.core.mem
+    }
+
+    pub fn mem_mut(&mut self) -> &mut Memory {
+        &mut self.core.mem
+    }
+
+    pub fn set_irq_line(&mut self, asserted: bool) {
+        self.core.set_irq_line(asserted);
+    }
+
+    pub fn trigger_nmi(&mut self) {
+        self.core.trigger_nmi();
+    }
+
+    pub fn config(&self) -> &Mos6502Config {
+        self.core.config()
+    }
+
+    pub fn config_mut(&mut self) -> &mut Mos6502Config {
+        self.core.config_mut()
+    }
+
+    pub fn state(&self) -> CpuState {
+        self.core.state()
+    }
+
+    pub fn emulation_flag(&self) -> bool {
+        self.e
+    }
+
+    pub fn carry_flag(&self) -> bool {
+        W65c816Flags::C.get(self.status)
+    }
+
+    pub fn zero_flag(&self) -> bool {
+        W65c816Flags::Z.get(self.status)
+    }
+
+    pub fn interrupts_disable_flag(&self) -> bool {
+        W65c816Flags::I.get(self.status)
+    }
+
+    pub fn decimal_mode_flag(&self) -> bool {
+        W65c816Flags::D.get(self.status)
+    }
+
+    pub fn index_width_flag(&self) -> bool {
+        W65c816Flags::X.get(self.status)
+    }
+
+    pub fn memory_width_flag(&self) -> bool {
+        W65c816Flags::M.get(self.status)
+    }
+
+    pub fn overflow_flag(&self) -> bool {
+        W65c816Flags::V.get(self.status)
+    }
+
+    pub fn negative_flag(&self) -> bool {
+        W65c816Flags::N.get(self.status)
+    }
+
+    pub fn set_status_flag(&mut self, flag: W65c816Flags) {
+        flag.set(&mut self.status);
+        self.widths_changed();
+    }
+
+    pub fn clear_status_flag(&mut self, flag: W65c816Flags) {
+        flag.clear(&mut self.status);
+        self.widths_changed();
+    }
+
+    pub fn get_accumulator(&self) -> Word {
+        self.a
+    }
+
+    pub fn set_accumulator(&mut self, data: Word) {
+        self.a = data;
+    }
+
+    pub fn get_xreg(&self) -> Word {
+        self.x
+    }
+
+    pub fn set_xreg(&mut self, data: Word) {
+        self.x = data;
+        self.widths_changed();
+    }
+
+    pub fn get_yreg(&self) -> Word {
+        self.y
+    }
+
+    pub fn set_yreg(&mut self, data: Word) {
+        self.y = data;
+        self.widths_changed();
+    }
+
+    pub fn get_sp(&self) -> Word {
+        self.sp
+    }
+
+    pub fn set_sp(&mut self, data: Word) {
+        self.sp = data;
+        self.widths_changed();
+    }
+
+    pub fn get_dp(&self) -> Word {
+        self.dp
+    }
+
+    pub fn set_dp(&mut self, data: Word) {
+        self.dp = data;
+    }
+
+    pub fn get_dbr(&self) -> Byte {
+        self.dbr
+    }
+
+    pub fn set_dbr(&mut self, data: Byte) {
+        self.dbr = data;
+    }
+
+    pub fn get_pbr(&self) -> Byte {
+        self.pbr
+    }
+
+    pub fn set_pbr(&mut self, data: Byte) {
+        self.pbr = data;
+    }
+
+    pub fn get_pc(&self) -> Word {
+        self.pc
+    }
+
+    pub fn set_pc(&mut self, data: Word) {
+        self.pc = data;
+    }
+
+    pub fn get_status(&self) -> Byte {
+        self.status
+    }
+
+    pub fn get_cycles(&self) -> u64 {
+        self.core.get_cycles()
+    }
+
+    // Instructions retired, interrupt sequences don't count.
+    pub fn get_instructions(&self) -> u64 {
+        self.core.get_instructions()
+    }
+
+    // Zeroes the cycle and instruction counters and nothing else.
+    pub fn reset_counters(&mut self) {
+        self.core.reset_counters();
+    }
+
+    pub fn cycles_to_duration(&self, cycles: u64) -> Duration {
+        self.core.cycles_to_duration(cycles)
+    }
+
+    // Emulated time covered by `get_cycles()`.
+    pub fn elapsed(&self) -> Duration {
+        self.core.elapsed()
+    }
+
+    ////////// HELPER FUNCTIONS //////////
+
+    fn cycle(&mut self) {
+        // NOTE: Internal cycles show up on the bus as reads of
+        // the program counter, the same as the 6502.
+        self.read_byte_at_addr(Self::long_addr(self.pbr, self.pc));
+    }
+
+    fn on_core(&self, opcode: Byte) -> bool {
+        // NOTE: Anything that needs the direct page or a bank
+        // register away from zero, or an opcode the 6502 doesn't
+        // have, is left to the 65C816 side.
+        self.e && self.dp == 0x0000 && self.dbr == 0x00 && self.pbr == 0x00 && Mos6502::is_documented(opcode)
+    }
+
+    fn sync_to_core(&mut self) {
+        // NOTE: Emulation mode keeps X and Y at 8 bits and the stack
+        // in page one, so the low bytes are the whole 6502 state.
+        let [_, a] = self.a.to_be_bytes();
+        let [_, x] = self.x.to_be_bytes();
+        let [_, y] = self.y.to_be_bytes();
+        let [_, sp] = self.sp.to_be_bytes();
+        self.core.set_registers(RegisterState { a, x, y, sp, pc: self.pc, status: self.status });
+    }
+
+    fn sync_from_core(&mut self) {
+        let regs: RegisterState = self.core.registers();
+        self.a = (self.a & 0xFF00) | Word::from(regs.a);
+        (self.x, self.y) = (Word::from(regs.x), Word::from(regs.y));
+        self.sp = 0x0100 | Word::from(regs.sp);
+        self.pc = regs.pc;
+        self.status = regs.status;
+        self.widths_changed();
+    }
+
+    fn m8(&self) -> bool {
+        W65c816Flags::M.get(self.status)
+    }
+
+    fn x8(&self) -> bool {
+        W65c816Flags::X.get(self.status)
+    }
+
+    fn widths_changed(&mut self) {
+        // NOTE: Emulation mode pins M and X to 8 bits and the stack
+        // to page one. An 8-bit index width clears the high bytes
+        // of X and Y for good, B survives an 8-bit accumulator.
+        if self.e {
+            self.status |= W65c816Flags::M as Byte | W65c816Flags::X as Byte;
+            self.sp = 0x0100 | (self.sp & 0x00FF);
+        }
+        if self.x8() {
+            self.x &= 0x00FF;
+            self.y &= 0x00FF;
+        }
+    }
+
+    fn long_addr(bank: Byte, addr: Word) -> Addr {
+        (Addr::from(bank) << 16) | Addr::from(addr)
+    }
+
+    fn offset_addr(addr: Addr, offset: Word) -> Addr {
+        addr.wrapping_add(Addr::from(offset)) & ADDR_MASK
+    }
+
+    fn page_crossed(base: Addr, addr: Addr) -> bool {
+        base & 0x00FF_FF00 != addr & 0x00FF_FF00
+    }
+
+    fn read_byte_at_addr(&mut self, addr: Addr) -> Byte {
+        self.core.read_long(addr & ADDR_MASK)
+    }
+
+    fn write_byte_at_addr(&mut self, addr: Addr, data: Byte) {
+        self.core.write_long(addr & ADDR_MASK, data);
+    }
+
+    fn read_data(&mut self, addr: Addr, wide: bool) -> Word {
+        let lo = Word::from(self.read_byte_at_addr(addr));
+        if !wide {
+            return lo;
+        }
+        let hi = Word::from(self.read_byte_at_addr(Self::offset_addr(addr, 1)));
+        (hi << 8) | lo
+    }
+
+    fn write_data(&mut self, addr: Addr, data: Word, wide: bool) {
+        self.write_byte_at_addr(addr, (data & 0xFF) as Byte);
+        if wide {
+            self.write_byte_at_addr(Self::offset_addr(addr, 1), (data >> 8) as Byte);
+        }
+    }
+
+    fn read_modify_write(&mut self, addr: Addr, op: fn(&mut Self, Word, bool) -> Word) {
+        // NOTE: Emulation mode writes the unmodified value back like
+        // the NMOS part, native mode spends an internal cycle instead.
+        let wide = !self.m8();
+        let data: Word = self.read_data(addr, wide);
+        if self.e {
+            self.write_data(addr, data, wide);
+        } else {
+            self.cycle();
+        }
+        let result: Word = op(self, data, wide);
+        self.write_data(addr, result, wide);
+    }
+
+    fn fetch_next_byte(&mut self) -> Byte {
+        // NOTE: The program counter wraps within the program bank.
+        let b: Byte = self.read_byte_at_addr(Self::long_addr(self.pbr, self.pc));
+        self.pc = self.pc.wrapping_add(1);
+        b
+    }
+
+    fn fetch_word(&mut self) -> Word {
+        let lo = Word::from(self.fetch_next_byte());
+        let hi = Word::from(self.fetch_next_byte());
+        (hi << 8) | lo
+    }
+
+    fn fetch_long(&mut self) -> Addr {
+        let addr = Addr::from(self.fetch_word());
+        let bank = Addr::from(self.fetch_next_byte());
+        (bank << 16) | addr
+    }
+
+    fn fetch_data(&mut self, wide: bool) -> Word {
+        if wide {
+            self.fetch_word()
+        } else {
+            Word::from(self.fetch_next_byte())
+        }
+    }
+
+    fn push_byte(&mut self, data: Byte) {
+        self.write_byte_at_addr(Addr::from(self.sp), data);
+        self.sp = self.sp.wrapping_sub(1);
+        if self.e {
+            self.sp = 0x0100 | (self.sp & 0x00FF);
+        }
+    }
+
+    fn push_word(&mut self, data: Word) {
+        self.push_byte((data >> 8) as Byte);
+        self.push_byte((data & 0xFF) as Byte);
+    }
+
+    fn push_data(&mut self, data: Word, wide: bool) {
+        if wide {
+            self.push_word(data);
+        } else {
+            self.push_byte((data & 0xFF) as Byte);
+        }
+    }
+
+    fn pull_byte(&mut self) -> Byte {
+        self.sp = self.sp.wrapping_add(1);
+        if self.e {
+            self.sp = 0x0100 | (self.sp & 0x00FF);
+        }
+        self.read_byte_at_addr(Addr::from(self.sp))
+    }
+
+    fn pull_word(&mut self) -> Word {
+        let lo = Word::from(self.pull_byte());
+        let hi = Word::from(self.pull_byte());
+        (hi << 8) | lo
+    }
+
+    fn pull_data(&mut self, wide: bool) -> Word {
+        if wide {
+            self.pull_word()
+        } else {
+            Word::from(self.pull_byte())
+        }
+    }
+
+    fn status_for_push(&self, brk: bool) -> Byte {
+        // NOTE: In emulation mode bit 4 is the 6502's B flag and
+        // bit 5 is always pushed set. Native mode pushes M and X.
+        if !self.e {
+            return self.status;
+        }
+        let mut status = self.status | W65c816Flags::M as Byte;
+        W65c816Flags::X.assign(&mut status, brk);
+        status
+    }
+
+    fn status_from_pull(&mut self, data: Byte) {
+        self.status = data;
+        self.widths_changed();
+    }
+
+    fn acc(&self) -> Word {
+        if self.m8() {
+            self.a & 0x00FF
+        } else {
+            self.a
+        }
+    }
+
+    fn acc_assign(&mut self, data: Word) {
+        if self.m8() {
+            self.a = (self.a & 0xFF00) | (data & 0x00FF);
+        } else {
+            self.a = data;
+        }
+        self.nz_set_status(data, !self.m8());
+    }
+
+    fn index_assign(&mut self, data: Word) -> Word {
+        let data: Word = if self.x8() { data & 0x00FF } else { data };
+        self.nz_set_status(data, !self.x8());
+        data
+    }
+
+    ////////// INTERRUPT FUNCTIONS //////////
+
+    fn interrupt(&mut self, vector: Word, brk: bool) {
+        // NOTE: Native mode also pushes the program bank and leaves
+        // decimal mode, emulation mode leaves D alone like the NMOS
+        // part and lets an NMI hijack a BRK or COP the same way.
+        // Every handler starts in bank zero.
+        if !self.e {
+            self.push_byte(self.pbr);
+        }
+        self.push_word(self.pc);
+        self.push_byte(self.status_for_push(brk));
+        W65c816Flags::I.set(&mut self.status);
+        if !self.e {
+            W65c816Flags::D.clear(&mut self.status);
+        }
+        let vector: Word = if brk && self.e && self.core.nmi_hijack() { EMU_NMI_VECTOR } else { vector };
+        self.pbr = 0x00;
+        self.pc = self.read_data(Addr::from(vector), true);
+    }
+
+    ////////// ADDRESSING MODE FUNCTIONS //////////
+
+    fn dp_addr(&self, offset: Word) -> Addr {
+        // NOTE: In emulation mode with the direct page on a page
+        // boundary, indexing wraps within the page like the 6502
+        // zero page does.
+        if self.e && self.dp.trailing_zeros() >= 8 {
+            Addr::from(self.dp | (offset & 0x00FF))
+        } else {
+            Addr::from(self.dp.wrapping_add(offset))
+        }
+    }
+
+    fn dp_penalty(&mut self) {
+        if self.dp & 0x00FF != 0 {
+            self.cycle();
+        }
+    }
+
+    fn read_dp_word(&mut self, offset: Word) -> Word {
+        let lo = Word::from(self.read_byte_at_addr(self.dp_addr(offset)));
+        let hi = Word::from(self.read_byte_at_addr(self.dp_addr(offset.wrapping_add(1))));
+        (hi << 8) | lo
+    }
+
+    fn read_dp_long(&mut self, offset: Word) -> Addr {
+        let addr = Addr::from(self.read_dp_word(offset));
+        let bank = Addr::from(self.read_byte_at_addr(self.dp_addr(offset.wrapping_add(2))));
+        (bank << 16) | addr
+    }
+
+    fn indexed(&mut self, base: Addr, index: Word, force_penalty: bool) -> Addr {
+        // NOTE: A 16-bit index always costs the extra cycle, an
+        // 8-bit one only when a page is crossed.
+        let addr: Addr = Self::offset_addr(base, index);
+        if force_penalty || !self.x8() || Self::page_crossed(base, addr) {
+            self.cycle();
+        }
+        addr
+    }
+
+    fn addr_dp(&mut self) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.dp_penalty();
+        self.dp_addr(offset)
+    }
+
+    fn addr_dpx(&mut self) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.dp_penalty();
+        self.cycle();
+        self.dp_addr(offset.wrapping_add(self.x))
+    }
+
+    fn addr_dpy(&mut self) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.dp_penalty();
+        self.cycle();
+        self.dp_addr(offset.wrapping_add(self.y))
+    }
+
+    fn addr_dp_ind(&mut self) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.dp_penalty();
+        let ptr: Word = self.read_dp_word(offset);
+        Self::long_addr(self.dbr, ptr)
+    }
+
+    fn addr_dpx_ind(&mut self) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.dp_penalty();
+        self.cycle();
+        let ptr: Word = self.read_dp_word(offset.wrapping_add(self.x));
+        Self::long_addr(self.dbr, ptr)
+    }
+
+    fn addr_dpy_ind(&mut self, force_penalty: bool) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.dp_penalty();
+        let ptr: Word = self.read_dp_word(offset);
+        self.indexed(Self::long_addr(self.dbr, ptr), self.y, force_penalty)
+    }
+
+    fn addr_dp_ind_long(&mut self) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.dp_penalty();
+        self.read_dp_long(offset)
+    }
+
+    fn addr_dpy_ind_long(&mut self) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.dp_penalty();
+        let base: Addr = self.read_dp_long(offset);
+        Self::offset_addr(base, self.y)
+    }
+
+    fn addr_abs(&mut self) -> Addr {
+        let addr: Word = self.fetch_word();
+        Self::long_addr(self.dbr, addr)
+    }
+
+    fn addr_absx(&mut self, force_penalty: bool) -> Addr {
+        let base: Addr = self.addr_abs();
+        self.indexed(base, self.x, force_penalty)
+    }
+
+    fn addr_absy(&mut self, force_penalty: bool) -> Addr {
+        let base: Addr = self.addr_abs();
+        self.indexed(base, self.y, force_penalty)
+    }
+
+    fn addr_long(&mut self) -> Addr {
+        self.fetch_long()
+    }
+
+    fn addr_longx(&mut self) -> Addr {
+        let base: Addr = self.fetch_long();
+        Self::offset_addr(base, self.x)
+    }
+
+    fn addr_sr(&mut self) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.cycle();
+        Addr::from(self.sp.wrapping_add(offset))
+    }
+
+    fn addr_sry_ind(&mut self) -> Addr {
+        let offset = Word::from(self.fetch_next_byte());
+        self.cycle();
+        let ptr: Word = self.read_data(Addr::from(self.sp.wrapping_add(offset)), true);
+        self.cycle();
+        Self::offset_addr(Self::long_addr(self.dbr, ptr), self.y)
+    }
+
+    ////////// SET STATUS FUNCTIONS //////////
+
+    fn nz_set_status(&mut self, data: Word, wide: bool) {
+        let (zero, negative) = if wide {
+            (data == 0, data & 0x8000 != 0)
+        } else {
+            (data.trailing_zeros() >= 8, data & 0x0080 != 0)
+        };
+        W65c816Flags::Z.assign(&mut self.status, zero);
+        W65c816Flags::N.assign(&mut self.status, negative);
+    }
+
+    ////////// ALU FUNCTIONS //////////
+
+    fn width_mask(wide: bool) -> Word {
+        if wide { 0xFFFF } else { 0x00FF }
+    }
+
+    fn sign_bit(wide: bool) -> Word {
+        if wide { 0x8000 } else { 0x0080 }
+    }
+
+    fn alu_on_core(&mut self, op: fn(&mut Mos6502, Byte), data: Word) {
+        // NOTE: Emulation mode does its arithmetic on the core, so
+        // decimal mode leaves the NMOS flags behind.
+        self.sync_to_core();
+        op(&mut self.core, (data & 0xFF) as Byte);
+        self.sync_from_core();
+    }
+
+    fn add_with_carry(&mut self, data: Word) {
+        if self.e {
+            self.alu_on_core(Mos6502::add_with_carry, data);
+            return;
+        }
+        let wide = !self.m8();
+        let (a, carry) = (self.acc(), Word::from(W65c816Flags::C.get(self.status)));
+
+        // NOTE: Decimal mode works a nibble at a time and, unlike
+        // the NMOS 6502, leaves valid flags behind.
+        let (result, carry_out): (Word, bool) = if W65c816Flags::D.get(self.status) {
+            let (mut result, mut carry): (Word, Word) = (0, carry);
+            for shift in (0..if wide { 16 } else { 8 }).step_by(4) {
+                let mut digit: Word = ((a >> shift) & 0x0F) + ((data >> shift) & 0x0F) + carry;
+                carry = Word::from(digit > 0x09);
+                if carry != 0 {
+                    digit = (digit + 0x06) & 0x0F;
+                }
+                result |= digit << shift;
+            }
+            (result, carry != 0)
+        } else {
+            let mask: Word = Self::width_mask(wide);
+            let sum: Addr = Addr::from(a) + Addr::from(data) + Addr::from(carry);
+            ((sum & 0xFFFF) as Word & mask, sum > Addr::from(mask))
+        };
+
+        W65c816Flags::C.assign(&mut self.status, carry_out);
+        W65c816Flags::V.assign(&mut self.status, !(a ^ data) & (a ^ result) & Self::sign_bit(wide) != 0);
+        self.acc_assign(result);
+    }
+
+    fn subtract_with_borrow(&mut self, data: Word) {
+        if self.e {
+            self.alu_on_core(Mos6502::subtract_with_borrow, data);
+            return;
+        }
+        let wide = !self.m8();
+        if !W65c816Flags::D.get(self.status) {
+            self.add_with_carry(!data & Self::width_mask(wide));
+            return;
+        }
+
+        let (a, mut borrow) = (self.acc(), Word::from(!W65c816Flags::C.get(self.status)));
+        let mut result: Word = 0;
+        for shift in (0..if wide { 16 } else { 8 }).step_by(4) {
+            let (x, m) = ((a >> shift) & 0x0F, ((data >> shift) & 0x0F) + borrow);
+            borrow = Word::from(x < m);
+            let digit: Word = if borrow != 0 { x + 0x0A - m } else { x - m };
+            result |= digit << shift;
+        }
+
+        W65c816Flags::C.assign(&mut self.status, borrow == 0);
+        W65c816Flags::V.assign(&mut self.status, (a ^ data) & (a ^ result) & Self::sign_bit(wide) != 0);
+        self.acc_assign(result);
+    }
+
+    fn compare(&mut self, reg: Word, data: Word, wide: bool) {
+        W65c816Flags::C.assign(&mut self.status, reg >= data);
+        self.nz_set_status(reg.wrapping_sub(data), wide);
+    }
+
+    fn shift_left(&mut self, data: Word, wide: bool) -> Word {
+        W65c816Flags::C.assign(&mut self.status, data & Self::sign_bit(wide) != 0);
+        let result: Word = (data << 1) & Self::width_mask(wide);
+        self.nz_set_status(result, wide);
+        result
+    }
+
+    fn shift_right(&mut self, data: Word, wide: bool) -> Word {
+        W65c816Flags::C.assign(&mut self.status, data & 0x0001 != 0);
+        let result: Word = data >> 1;
+        self.nz_set_status(result, wide);
+        result
+    }
+
+    fn rotate_left(&mut self, data: Word, wide: bool) -> Word {
+        let carry = Word::from(W65c816Flags::C.get(self.status));
+        W65c816Flags::C.assign(&mut self.status, data & Self::sign_bit(wide) != 0);
+        let result: Word = ((data << 1) | carry) & Self::width_mask(wide);
+        self.nz_set_status(result, wide);
+        result
+    }
+
+    fn rotate_right(&mut self, data: Word, wide: bool) -> Word {
+        let carry: Word = if W65c816Flags::C.get(self.status) { Self::sign_bit(wide) } else { 0 };
+        W65c816Flags::C.assign(&mut self.status, data & 0x0001 != 0);
+        let result: Word = (data >> 1) | carry;
+        self.nz_set_status(result, wide);
+        result
+    }
+
+    fn increment(&mut self, data: Word, wide: bool) -> Word {
+        let result: Word = data.wrapping_add(1) & Self::width_mask(wide);
+        self.nz_set_status(result, wide);
+        result
+    }
+
+    fn decrement(&mut self, data: Word, wide: bool) -> Word {
+        let result: Word = data.wrapping_sub(1) & Self::width_mask(wide);
+        self.nz_set_status(result, wide);
+        result
+    }
+
+    fn test_and_reset_bits(&mut self, data: Word, _wide: bool) -> Word {
+        let zero: bool = self.acc() & data == 0;
+        W65c816Flags::Z.assign(&mut self.status, zero);
+        data & !self.acc()
+    }
+
+    fn test_and_set_bits(&mut self, data: Word, _wide: bool) -> Word {
+        let zero: bool = self.acc() & data == 0;
+        W65c816Flags::Z.assign(&mut self.status, zero);
+        data | self.acc()
+    }
+
+    fn bit_test(&mut self, data: Word) {
+        let wide = !self.m8();
+        let zero: bool = self.acc() & data == 0;
+        W65c816Flags::Z.assign(&mut self.status, zero);
+        W65c816Flags::N.assign(&mut self.status, data & Self::sign_bit(wide) != 0);
+        W65c816Flags::V.assign(&mut self.status, data & (Self::sign_bit(wide) >> 1) != 0);
+    }
+
+    fn branch_if(&mut self, cond: bool) {
+        // NOTE: Crossing a page only costs the extra cycle in
+        // emulation mode.
+        let offset = i8::from_le_bytes([self.fetch_next_byte()]);
+        if cond {
+            let target: Word = self.pc.wrapping_add_signed(i16::from(offset));
+            self.cycle();
+            if self.e && self.pc & 0xFF00 != target & 0xFF00 {
+                self.cycle();
+            }
+            self.pc = target;
+        }
+    }
+
+    ////////// CPU INSTRUCTION FUNCTIONS //////////
+
+    fn ora(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.m8());
+        self.acc_assign(self.acc() | data);
+    }
+
+    fn and(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.m8());
+        self.acc_assign(self.acc() & data);
+    }
+
+    fn eor(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.m8());
+        self.acc_assign(self.acc() ^ data);
+    }
+
+    fn adc(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.m8());
+        self.add_with_carry(data);
+    }
+
+    fn sta(&mut self, addr: Addr) {
+        self.write_data(addr, self.acc(), !self.m8());
+    }
+
+    fn lda(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.m8());
+        self.acc_assign(data);
+    }
+
+    fn cmp(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.m8());
+        self.compare(self.acc(), data, !self.m8());
+    }
+
+    fn sbc(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.m8());
+        self.subtract_with_borrow(data);
+    }
+
+    fn asl(&mut self, addr: Addr) {
+        self.read_modify_write(addr, Self::shift_left);
+    }
+
+    fn rol(&mut self, addr: Addr) {
+        self.read_modify_write(addr, Self::rotate_left);
+    }
+
+    fn lsr(&mut self, addr: Addr) {
+        self.read_modify_write(addr, Self::shift_right);
+    }
+
+    fn ror(&mut self, addr: Addr) {
+        self.read_modify_write(addr, Self::rotate_right);
+    }
+
+    fn inc(&mut self, addr: Addr) {
+        self.read_modify_write(addr, Self::increment);
+    }
+
+    fn dec(&mut self, addr: Addr) {
+        self.read_modify_write(addr, Self::decrement);
+    }
+
+    fn tsb(&mut self, addr: Addr) {
+        self.read_modify_write(addr, Self::test_and_set_bits);
+    }
+
+    fn trb(&mut self, addr: Addr) {
+        self.read_modify_write(addr, Self::test_and_reset_bits);
+    }
+
+    fn bit(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.m8());
+        self.bit_test(data);
+    }
+
+    fn stz(&mut self, addr: Addr) {
+        self.write_data(addr, 0x0000, !self.m8());
+    }
+
+    fn ldx(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.x8());
+        self.x = self.index_assign(data);
+    }
+
+    fn ldy(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.x8());
+        self.y = self.index_assign(data);
+    }
+
+    fn stx(&mut self, addr: Addr) {
+        self.write_data(addr, self.x, !self.x8());
+    }
+
+    fn sty(&mut self, addr: Addr) {
+        self.write_data(addr, self.y, !self.x8());
+    }
+
+    fn cpx(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.x8());
+        self.compare(self.x, data, !self.x8());
+    }
+
+    fn cpy(&mut self, addr: Addr) {
+        let data: Word = self.read_data(addr, !self.x8());
+        self.compare(self.y, data, !self.x8());
+    }
+
+    ///// Immediate /////
+
+    fn ora_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.m8());
+        self.acc_assign(self.acc() | data);
+    }
+
+    fn and_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.m8());
+        self.acc_assign(self.acc() & data);
+    }
+
+    fn eor_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.m8());
+        self.acc_assign(self.acc() ^ data);
+    }
+
+    fn adc_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.m8());
+        self.add_with_carry(data);
+    }
+
+    fn lda_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.m8());
+        self.acc_assign(data);
+    }
+
+    fn cmp_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.m8());
+        self.compare(self.acc(), data, !self.m8());
+    }
+
+    fn sbc_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.m8());
+        self.subtract_with_borrow(data);
+    }
+
+    fn bit_imm(&mut self) {
+        // NOTE: Only Z is affected.
+        let data: Word = self.fetch_data(!self.m8());
+        let zero: bool = self.acc() & data == 0;
+        W65c816Flags::Z.assign(&mut self.status, zero);
+    }
+
+    fn ldx_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.x8());
+        self.x = self.index_assign(data);
+    }
+
+    fn ldy_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.x8());
+        self.y = self.index_assign(data);
+    }
+
+    fn cpx_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.x8());
+        self.compare(self.x, data, !self.x8());
+    }
+
+    fn cpy_imm(&mut self) {
+        let data: Word = self.fetch_data(!self.x8());
+        self.compare(self.y, data, !self.x8());
+    }
+
+    fn rep_imm(&mut self) {
+        let mask: Byte = self.fetch_next_byte();
+        self.cycle();
+        self.status &= !mask;
+        self.widths_changed();
+    }
+
+    fn sep_imm(&mut self) {
+        let mask: Byte = self.fetch_next_byte();
+        self.cycle();
+        self.status |= mask;
+        self.widths_changed();
+    }
+
+    ///// Accumulator /////
+
+    fn asl_acc(&mut self) {
+        let result: Word = self.shift_left(self.acc(), !self.m8());
+        self.acc_assign(result);
+        self.cycle();
+    }
+
+    fn rol_acc(&mut self) {
+        let result: Word = self.rotate_left(self.acc(), !self.m8());
+        self.acc_assign(result);
+        self.cycle();
+    }
+
+    fn lsr_acc(&mut self) {
+        let result: Word = self.shift_right(self.acc(), !self.m8());
+        self.acc_assign(result);
+        self.cycle();
+    }
+
+    fn ror_acc(&mut self) {
+        let result: Word = self.rotate_right(self.acc(), !self.m8());
+        self.acc_assign(result);
+        self.cycle();
+    }
+
+    fn inc_acc(&mut self) {
+        self.acc_assign(self.acc().wrapping_add(1));
+        self.cycle();
+    }
+
+    fn dec_acc(&mut self) {
+        self.acc_assign(self.acc().wrapping_sub(1));
+        self.cycle();
+    }
+
+    ///// Implied /////
+
+    fn inx_imp(&mut self) {
+        self.x = self.index_assign(self.x.wrapping_add(1));
+        self.cycle();
+    }
+
+    fn iny_imp(&mut self) {
+        self.y = self.index_assign(self.y.wrapping_add(1));
+        self.cycle();
+    }
+
+    fn dex_imp(&mut self) {
+        self.x = self.index_assign(self.x.wrapping_sub(1));
+        self.cycle();
+    }
+
+    fn dey_imp(&mut self) {
+        self.y = self.index_assign(self.y.wrapping_sub(1));
+        self.cycle();
+    }
+
+    fn tax_imp(&mut self) {
+        self.x = self.index_assign(self.a);
+        self.cycle();
+    }
+
+    fn tay_imp(&mut self) {
+        self.y = self.index_assign(self.a);
+        self.cycle();
+    }
+
+    fn txa_imp(&mut self) {
+        self.acc_assign(self.x);
+        self.cycle();
+    }
+
+    fn tya_imp(&mut self) {
+        self.acc_assign(self.y);
+        self.cycle();
+    }
+
+    fn tsx_imp(&mut self) {
+        self.x = self.index_assign(self.sp);
+        self.cycle();
+    }
+
+    fn txs_imp(&mut self) {
+        self.sp = self.x;
+        self.widths_changed();
+        self.cycle();
+    }
+
+    fn txy_imp(&mut self) {
+        self.y = self.index_assign(self.x);
+        self.cycle();
+    }
+
+    fn tyx_imp(&mut self) {
+        self.x = self.index_assign(self.y);
+        self.cycle();
+    }
+
+    fn tcd_imp(&mut self) {
+        self.dp = self.a;
+        self.nz_set_status(self.dp, true);
+        self.cycle();
+    }
+
+    fn tdc_imp(&mut self) {
+        self.a = self.dp;
+        self.nz_set_status(self.a, true);
+        self.cycle();
+    }
+
+    fn tcs_imp(&mut self) {
+        self.sp = self.a;
+        self.widths_changed();
+        self.cycle();
+    }
+
+    fn tsc_imp(&mut self) {
+        self.a = self.sp;
+        self.nz_set_status(self.a, true);
+        self.cycle();
+    }
+
+    fn xba_imp(&mut self) {
+        // NOTE: Flags always come from the new 8-bit A.
+        self.a = self.a.rotate_left(8);
+        self.nz_set_status(self.a, false);
+        self.cycle();
+        self.cycle();
+    }
+
+    fn xce_imp(&mut self) {
+        let carry: bool = W65c816Flags::C.get(self.status);
+        W65c816Flags::C.assign(&mut self.status, self.e);
+        self.e = carry;
+        self.widths_changed();
+        self.cycle();
+    }
+
+    fn clc_imp(&mut self) {
+        W65c816Flags::C.clear(&mut self.status);
+        self.cycle();
+    }
+
+    fn sec_imp(&mut self) {
+        W65c816Flags::C.set(&mut self.status);
+        self.cycle();
+    }
+
+    fn cli_imp(&mut self) {
+        W65c816Flags::I.clear(&mut self.status);
+        self.cycle();
+    }
+
+    fn sei_imp(&mut self) {
+        W65c816Flags::I.set(&mut self.status);
+        self.cycle();
+    }
+
+    fn cld_imp(&mut self) {
+        W65c816Flags::D.clear(&mut self.status);
+        self.cycle();
+    }
+
+    fn sed_imp(&mut self) {
+        W65c816Flags::D.set(&mut self.status);
+        self.cycle();
+    }
+
+    fn clv_imp(&mut self) {
+        W65c816Flags::V.clear(&mut self.status);
+        self.cycle();
+    }
+
+    fn nop_imp(&mut self) {
+        self.cycle();
+    }
+
+    fn wdm_imp(&mut self) {
+        // NOTE: Reserved for future expansion, a two byte NOP.
+        self.fetch_next_byte();
+    }
+
+    ///// Stack /////
+
+    fn pha_imp(&mut self) {
+        self.cycle();
+        self.push_data(self.acc(), !self.m8());
+    }
+
+    fn phx_imp(&mut self) {
+        self.cycle();
+        self.push_data(self.x, !self.x8());
+    }
+
+    fn phy_imp(&mut self) {
+        self.cycle();
+        self.push_data(self.y, !self.x8());
+    }
+
+    fn php_imp(&mut self) {
+        self.cycle();
+        self.push_byte(self.status_for_push(true));
+    }
+
+    fn phb_imp(&mut self) {
+        self.cycle();
+        self.push_byte(self.dbr);
+    }
+
+    fn phd_imp(&mut self) {
+        self.cycle();
+        self.push_word(self.dp);
+    }
+
+    fn phk_imp(&mut self) {
+        self.cycle();
+        self.push_byte(self.pbr);
+    }
+
+    fn pla_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let data: Word = self.pull_data(!self.m8());
+        self.acc_assign(data);
+    }
+
+    fn plx_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let data: Word = self.pull_data(!self.x8());
+        self.x = self.index_assign(data);
+    }
+
+    fn ply_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let data: Word = self.pull_data(!self.x8());
+        self.y = self.index_assign(data);
+    }
+
+    fn plp_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let data: Byte = self.pull_byte();
+        self.status_from_pull(data);
+    }
+
+    fn plb_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        self.dbr = self.pull_byte();
+        self.nz_set_status(Word::from(self.dbr), false);
+    }
+
+    fn pld_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        self.dp = self.pull_word();
+        self.nz_set_status(self.dp, true);
+    }
+
+    fn pea_abs(&mut self) {
+        let data: Word = self.fetch_word();
+        self.push_word(data);
+    }
+
+    fn pei_dp_ind(&mut self) {
+        let offset = Word::from(self.fetch_next_byte());
+        self.dp_penalty();
+        let data: Word = self.read_dp_word(offset);
+        self.push_word(data);
+    }
+
+    fn per_rel_long(&mut self) {
+        let offset: Word = self.fetch_word();
+        self.cycle();
+        self.push_word(self.pc.wrapping_add(offset));
+    }
+
+    ///// Branches /////
+
+    fn bpl_rel(&mut self) {
+        self.branch_if(!W65c816Flags::N.get(self.status));
+    }
+
+    fn bmi_rel(&mut self) {
+        self.branch_if(W65c816Flags::N.get(self.status));
+    }
+
+    fn bvc_rel(&mut self) {
+        self.branch_if(!W65c816Flags::V.get(self.status));
+    }
+
+    fn bvs_rel(&mut self) {
+        self.branch_if(W65c816Flags::V.get(self.status));
+    }
+
+    fn bcc_rel(&mut self) {
+        self.branch_if(!W65c816Flags::C.get(self.status));
+    }
+
+    fn bcs_rel(&mut self) {
+        self.branch_if(W65c816Flags::C.get(self.status));
+    }
+
+    fn bne_rel(&mut self) {
+        self.branch_if(!W65c816Flags::Z.get(self.status));
+    }
+
+    fn beq_rel(&mut self) {
+        self.branch_if(W65c816Flags::Z.get(self.status));
+    }
+
+    fn bra_rel(&mut self) {
+        self.branch_if(true);
+    }
+
+    fn brl_rel_long(&mut self) {
+        let offset: Word = self.fetch_word();
+        self.cycle();
+        self.pc = self.pc.wrapping_add(offset);
+    }
+
+    ///// Jumps, Calls and Returns /////
+
+    fn jmp_abs(&mut self) {
+        self.pc = self.fetch_word();
+    }
+
+    fn jmp_abs_ind(&mut self) {
+        // NOTE: The pointer always lives in bank zero. Emulation mode
+        // keeps the NMOS page wrap, a pointer at $xxFF takes its high
+        // byte from $xx00.
+        let ptr: Word = self.fetch_word();
+        let hi_ptr: Word = if self.e && self.core.config().jmp_indirect_bug {
+            (ptr & 0xFF00) | (ptr.wrapping_add(1) & 0x00FF)
+        } else {
+            ptr.wrapping_add(1)
+        };
+        let lo = Word::from(self.read_byte_at_addr(Addr::from(ptr)));
+        let hi = Word::from(self.read_byte_at_addr(Addr::from(hi_ptr)));
+        self.pc = (hi << 8) | lo;
+    }
+
+    fn jmp_absx_ind(&mut self) {
+        // NOTE: The pointer lives in the program bank.
+        let base: Word = self.fetch_word();
+        self.cycle();
+        self.pc = self.read_data(Self::long_addr(self.pbr, base.wrapping_add(self.x)), true);
+    }
+
+    fn jml_long(&mut self) {
+        let addr: Addr = self.fetch_long();
+        self.pbr = ((addr >> 16) & 0xFF) as Byte;
+        self.pc = (addr & 0xFFFF) as Word;
+    }
+
+    fn jml_abs_ind_long(&mut self) {
+        let ptr = Addr::from(self.fetch_word());
+        self.pc = self.read_data(ptr, true);
+        self.pbr = self.read_byte_at_addr(Self::offset_addr(ptr, 2));
+    }
+
+    fn jsr_abs(&mut self) {
+        // NOTE: Like the 6502 the pushed address points at the last
+        // byte of the instruction.
+        let addr: Word = self.fetch_word();
+        self.cycle();
+        self.push_word(self.pc.wrapping_sub(1));
+        self.pc = addr;
+    }
+
+    fn jsr_absx_ind(&mut self) {
+        let lo = Word::from(self.fetch_next_byte());
+        self.push_word(self.pc);
+        let hi = Word::from(self.fetch_next_byte());
+        self.cycle();
+        let base: Word = (hi << 8) | lo;
+        self.pc = self.read_data(Self::long_addr(self.pbr, base.wrapping_add(self.x)), true);
+    }
+
+    fn jsl_long(&mut self) {
+        let addr: Word = self.fetch_word();
+        self.push_byte(self.pbr);
+        self.cycle();
+        let bank: Byte = self.fetch_next_byte();
+        self.push_word(self.pc.wrapping_sub(1));
+        self.pbr = bank;
+        self.pc = addr;
+    }
+
+    fn rts_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let addr: Word = self.pull_word();
+        self.cycle();
+        self.pc = addr.wrapping_add(1);
+    }
+
+    fn rtl_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let addr: Word = self.pull_word();
+        self.pbr = self.pull_byte();
+        self.pc = addr.wrapping_add(1);
+    }
+
+    fn rti_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        let data: Byte = self.pull_byte();
+        self.status_from_pull(data);
+        self.pc = self.pull_word();
+        if !self.e {
+            self.pbr = self.pull_byte();
+        }
+    }
+
+    fn brk_imp(&mut self) {
+        // NOTE: Like the 6502, the byte after BRK is skipped.
+        self.fetch_next_byte();
+        let vector: Word = if self.e { EMU_IRQ_VECTOR } else { BRK_VECTOR };
+        self.interrupt(vector, true);
+    }
+
+    fn cop_imp(&mut self) {
+        self.fetch_next_byte();
+        let vector: Word = if self.e { EMU_COP_VECTOR } else { COP_VECTOR };
+        self.interrupt(vector, true);
+    }
+
+    ///// Block Moves /////
+
+    fn block_move(&mut self, step: Word) {
+        // NOTE: Moves one byte and rewinds the program counter
+        // until the count in C runs out, so interrupts can still
+        // be taken in between bytes.
+        let dst: Byte = self.fetch_next_byte();
+        let src: Byte = self.fetch_next_byte();
+        self.dbr = dst;
+
+        let data: Byte = self.read_byte_at_addr(Self::long_addr(src, self.x));
+        self.write_byte_at_addr(Self::long_addr(dst, self.y), data);
+        self.cycle();
+        self.cycle();
+
+        let mask: Word = Self::width_mask(!self.x8());
+        self.x = self.x.wrapping_add(step) & mask;
+        self.y = self.y.wrapping_add(step) & mask;
+        self.a = self.a.wrapping_sub(1);
+        if self.a != 0xFFFF {
+            self.pc = self.pc.wrapping_sub(3);
+        }
+    }
+
+    fn mvn_blk(&mut self) {
+        self.block_move(0x0001);
+    }
+
+    fn mvp_blk(&mut self) {
+        self.block_move(0xFFFF);
+    }
+
+    ///// Addressed /////
+
+    fn adc_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.adc(addr);
+    }
+
+    fn adc_absx(&mut self) {
+        let addr: Addr = self.addr_absx(false);
+        self.adc(addr);
+    }
+
+    fn adc_absy(&mut self) {
+        let addr: Addr = self.addr_absy(false);
+        self.adc(addr);
+    }
+
+    fn adc_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.adc(addr);
+    }
+
+    fn adc_dp_ind(&mut self) {
+        let addr: Addr = self.addr_dp_ind();
+        self.adc(addr);
+    }
+
+    fn adc_dp_ind_long(&mut self) {
+        let addr: Addr = self.addr_dp_ind_long();
+        self.adc(addr);
+    }
+
+    fn adc_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.adc(addr);
+    }
+
+    fn adc_dpx_ind(&mut self) {
+        let addr: Addr = self.addr_dpx_ind();
+        self.adc(addr);
+    }
+
+    fn adc_dpy_ind(&mut self) {
+        let addr: Addr = self.addr_dpy_ind(false);
+        self.adc(addr);
+    }
+
+    fn adc_dpy_ind_long(&mut self) {
+        let addr: Addr = self.addr_dpy_ind_long();
+        self.adc(addr);
+    }
+
+    fn adc_long(&mut self) {
+        let addr: Addr = self.addr_long();
+        self.adc(addr);
+    }
+
+    fn adc_longx(&mut self) {
+        let addr: Addr = self.addr_longx();
+        self.adc(addr);
+    }
+
+    fn adc_sr(&mut self) {
+        let addr: Addr = self.addr_sr();
+        self.adc(addr);
+    }
+
+    fn adc_sry_ind(&mut self) {
+        let addr: Addr = self.addr_sry_ind();
+        self.adc(addr);
+    }
+
+    fn and_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.and(addr);
+    }
+
+    fn and_absx(&mut self) {
+        let addr: Addr = self.addr_absx(false);
+        self.and(addr);
+    }
+
+    fn and_absy(&mut self) {
+        let addr: Addr = self.addr_absy(false);
+        self.and(addr);
+    }
+
+    fn and_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.and(addr);
+    }
+
+    fn and_dp_ind(&mut self) {
+        let addr: Addr = self.addr_dp_ind();
+        self.and(addr);
+    }
+
+    fn and_dp_ind_long(&mut self) {
+        let addr: Addr = self.addr_dp_ind_long();
+        self.and(addr);
+    }
+
+    fn and_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.and(addr);
+    }
+
+    fn and_dpx_ind(&mut self) {
+        let addr: Addr = self.addr_dpx_ind();
+        self.and(addr);
+    }
+
+    fn and_dpy_ind(&mut self) {
+        let addr: Addr = self.addr_dpy_ind(false);
+        self.and(addr);
+    }
+
+    fn and_dpy_ind_long(&mut self) {
+        let addr: Addr = self.addr_dpy_ind_long();
+        self.and(addr);
+    }
+
+    fn and_long(&mut self) {
+        let addr: Addr = self.addr_long();
+        self.and(addr);
+    }
+
+    fn and_longx(&mut self) {
+        let addr: Addr = self.addr_longx();
+        self.and(addr);
+    }
+
+    fn and_sr(&mut self) {
+        let addr: Addr = self.addr_sr();
+        self.and(addr);
+    }
+
+    fn and_sry_ind(&mut self) {
+        let addr: Addr = self.addr_sry_ind();
+        self.and(addr);
+    }
+
+    fn asl_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.asl(addr);
+    }
+
+    fn asl_absx(&mut self) {
+        let addr: Addr = self.addr_absx(true);
+        self.asl(addr);
+    }
+
+    fn asl_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.asl(addr);
+    }
+
+    fn asl_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.asl(addr);
+    }
+
+    fn bit_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.bit(addr);
+    }
+
+    fn bit_absx(&mut self) {
+        let addr: Addr = self.addr_absx(false);
+        self.bit(addr);
+    }
+
+    fn bit_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.bit(addr);
+    }
+
+    fn bit_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.bit(addr);
+    }
+
+    fn cmp_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.cmp(addr);
+    }
+
+    fn cmp_absx(&mut self) {
+        let addr: Addr = self.addr_absx(false);
+        self.cmp(addr);
+    }
+
+    fn cmp_absy(&mut self) {
+        let addr: Addr = self.addr_absy(false);
+        self.cmp(addr);
+    }
+
+    fn cmp_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.cmp(addr);
+    }
+
+    fn cmp_dp_ind(&mut self) {
+        let addr: Addr = self.addr_dp_ind();
+        self.cmp(addr);
+    }
+
+    fn cmp_dp_ind_long(&mut self) {
+        let addr: Addr = self.addr_dp_ind_long();
+        self.cmp(addr);
+    }
+
+    fn cmp_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.cmp(addr);
+    }
+
+    fn cmp_dpx_ind(&mut self) {
+        let addr: Addr = self.addr_dpx_ind();
+        self.cmp(addr);
+    }
+
+    fn cmp_dpy_ind(&mut self) {
+        let addr: Addr = self.addr_dpy_ind(false);
+        self.cmp(addr);
+    }
+
+    fn cmp_dpy_ind_long(&mut self) {
+        let addr: Addr = self.addr_dpy_ind_long();
+        self.cmp(addr);
+    }
+
+    fn cmp_long(&mut self) {
+        let addr: Addr = self.addr_long();
+        self.cmp(addr);
+    }
+
+    fn cmp_longx(&mut self) {
+        let addr: Addr = self.addr_longx();
+        self.cmp(addr);
+    }
+
+    fn cmp_sr(&mut self) {
+        let addr: Addr = self.addr_sr();
+        self.cmp(addr);
+    }
+
+    fn cmp_sry_ind(&mut self) {
+        let addr: Addr = self.addr_sry_ind();
+        self.cmp(addr);
+    }
+
+    fn cpx_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.cpx(addr);
+    }
+
+    fn cpx_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.cpx(addr);
+    }
+
+    fn cpy_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.cpy(addr);
+    }
+
+    fn cpy_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.cpy(addr);
+    }
+
+    fn dec_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.dec(addr);
+    }
+
+    fn dec_absx(&mut self) {
+        let addr: Addr = self.addr_absx(true);
+        self.dec(addr);
+    }
+
+    fn dec_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.dec(addr);
+    }
+
+    fn dec_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.dec(addr);
+    }
+
+    fn eor_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.eor(addr);
+    }
+
+    fn eor_absx(&mut self) {
+        let addr: Addr = self.addr_absx(false);
+        self.eor(addr);
+    }
+
+    fn eor_absy(&mut self) {
+        let addr: Addr = self.addr_absy(false);
+        self.eor(addr);
+    }
+
+    fn eor_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.eor(addr);
+    }
+
+    fn eor_dp_ind(&mut self) {
+        let addr: Addr = self.addr_dp_ind();
+        self.eor(addr);
+    }
+
+    fn eor_dp_ind_long(&mut self) {
+        let addr: Addr = self.addr_dp_ind_long();
+        self.eor(addr);
+    }
+
+    fn eor_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.eor(addr);
+    }
+
+    fn eor_dpx_ind(&mut self) {
+        let addr: Addr = self.addr_dpx_ind();
+        self.eor(addr);
+    }
+
+    fn eor_dpy_ind(&mut self) {
+        let addr: Addr = self.addr_dpy_ind(false);
+        self.eor(addr);
+    }
+
+    fn eor_dpy_ind_long(&mut self) {
+        let addr: Addr = self.addr_dpy_ind_long();
+        self.eor(addr);
+    }
+
+    fn eor_long(&mut self) {
+        let addr: Addr = self.addr_long();
+        self.eor(addr);
+    }
+
+    fn eor_longx(&mut self) {
+        let addr: Addr = self.addr_longx();
+        self.eor(addr);
+    }
+
+    fn eor_sr(&mut self) {
+        let addr: Addr = self.addr_sr();
+        self.eor(addr);
+    }
+
+    fn eor_sry_ind(&mut self) {
+        let addr: Addr = self.addr_sry_ind();
+        self.eor(addr);
+    }
+
+    fn inc_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.inc(addr);
+    }
+
+    fn inc_absx(&mut self) {
+        let addr: Addr = self.addr_absx(true);
+        self.inc(addr);
+    }
+
+    fn inc_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.inc(addr);
+    }
+
+    fn inc_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.inc(addr);
+    }
+
+    fn lda_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.lda(addr);
+    }
+
+    fn lda_absx(&mut self) {
+        let addr: Addr = self.addr_absx(false);
+        self.lda(addr);
+    }
+
+    fn lda_absy(&mut self) {
+        let addr: Addr = self.addr_absy(false);
+        self.lda(addr);
+    }
+
+    fn lda_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.lda(addr);
+    }
+
+    fn lda_dp_ind(&mut self) {
+        let addr: Addr = self.addr_dp_ind();
+        self.lda(addr);
+    }
+
+    fn lda_dp_ind_long(&mut self) {
+        let addr: Addr = self.addr_dp_ind_long();
+        self.lda(addr);
+    }
+
+    fn lda_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.lda(addr);
+    }
+
+    fn lda_dpx_ind(&mut self) {
+        let addr: Addr = self.addr_dpx_ind();
+        self.lda(addr);
+    }
+
+    fn lda_dpy_ind(&mut self) {
+        let addr: Addr = self.addr_dpy_ind(false);
+        self.lda(addr);
+    }
+
+    fn lda_dpy_ind_long(&mut self) {
+        let addr: Addr = self.addr_dpy_ind_long();
+        self.lda(addr);
+    }
+
+    fn lda_long(&mut self) {
+        let addr: Addr = self.addr_long();
+        self.lda(addr);
+    }
+
+    fn lda_longx(&mut self) {
+        let addr: Addr = self.addr_longx();
+        self.lda(addr);
+    }
+
+    fn lda_sr(&mut self) {
+        let addr: Addr = self.addr_sr();
+        self.lda(addr);
+    }
+
+    fn lda_sry_ind(&mut self) {
+        let addr: Addr = self.addr_sry_ind();
+        self.lda(addr);
+    }
+
+    fn ldx_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.ldx(addr);
+    }
+
+    fn ldx_absy(&mut self) {
+        let addr: Addr = self.addr_absy(false);
+        self.ldx(addr);
+    }
+
+    fn ldx_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.ldx(addr);
+    }
+
+    fn ldx_dpy(&mut self) {
+        let addr: Addr = self.addr_dpy();
+        self.ldx(addr);
+    }
+
+    fn ldy_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.ldy(addr);
+    }
+
+    fn ldy_absx(&mut self) {
+        let addr: Addr = self.addr_absx(false);
+        self.ldy(addr);
+    }
+
+    fn ldy_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.ldy(addr);
+    }
+
+    fn ldy_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.ldy(addr);
+    }
+
+    fn lsr_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.lsr(addr);
+    }
+
+    fn lsr_absx(&mut self) {
+        let addr: Addr = self.addr_absx(true);
+        self.lsr(addr);
+    }
+
+    fn lsr_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.lsr(addr);
+    }
+
+    fn lsr_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.lsr(addr);
+    }
+
+    fn ora_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.ora(addr);
+    }
+
+    fn ora_absx(&mut self) {
+        let addr: Addr = self.addr_absx(false);
+        self.ora(addr);
+    }
+
+    fn ora_absy(&mut self) {
+        let addr: Addr = self.addr_absy(false);
+        self.ora(addr);
+    }
+
+    fn ora_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.ora(addr);
+    }
+
+    fn ora_dp_ind(&mut self) {
+        let addr: Addr = self.addr_dp_ind();
+        self.ora(addr);
+    }
+
+    fn ora_dp_ind_long(&mut self) {
+        let addr: Addr = self.addr_dp_ind_long();
+        self.ora(addr);
+    }
+
+    fn ora_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.ora(addr);
+    }
+
+    fn ora_dpx_ind(&mut self) {
+        let addr: Addr = self.addr_dpx_ind();
+        self.ora(addr);
+    }
+
+    fn ora_dpy_ind(&mut self) {
+        let addr: Addr = self.addr_dpy_ind(false);
+        self.ora(addr);
+    }
+
+    fn ora_dpy_ind_long(&mut self) {
+        let addr: Addr = self.addr_dpy_ind_long();
+        self.ora(addr);
+    }
+
+    fn ora_long(&mut self) {
+        let addr: Addr = self.addr_long();
+        self.ora(addr);
+    }
+
+    fn ora_longx(&mut self) {
+        let addr: Addr = self.addr_longx();
+        self.ora(addr);
+    }
+
+    fn ora_sr(&mut self) {
+        let addr: Addr = self.addr_sr();
+        self.ora(addr);
+    }
+
+    fn ora_sry_ind(&mut self) {
+        let addr: Addr = self.addr_sry_ind();
+        self.ora(addr);
+    }
+
+    fn rol_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.rol(addr);
+    }
+
+    fn rol_absx(&mut self) {
+        let addr: Addr = self.addr_absx(true);
+        self.rol(addr);
+    }
+
+    fn rol_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.rol(addr);
+    }
+
+    fn rol_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.rol(addr);
+    }
+
+    fn ror_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.ror(addr);
+    }
+
+    fn ror_absx(&mut self) {
+        let addr: Addr = self.addr_absx(true);
+        self.ror(addr);
+    }
+
+    fn ror_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.ror(addr);
+    }
+
+    fn ror_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.ror(addr);
+    }
+
+    fn sbc_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.sbc(addr);
+    }
+
+    fn sbc_absx(&mut self) {
+        let addr: Addr = self.addr_absx(false);
+        self.sbc(addr);
+    }
+
+    fn sbc_absy(&mut self) {
+        let addr: Addr = self.addr_absy(false);
+        self.sbc(addr);
+    }
+
+    fn sbc_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.sbc(addr);
+    }
+
+    fn sbc_dp_ind(&mut self) {
+        let addr: Addr = self.addr_dp_ind();
+        self.sbc(addr);
+    }
+
+    fn sbc_dp_ind_long(&mut self) {
+        let addr: Addr = self.addr_dp_ind_long();
+        self.sbc(addr);
+    }
+
+    fn sbc_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.sbc(addr);
+    }
+
+    fn sbc_dpx_ind(&mut self) {
+        let addr: Addr = self.addr_dpx_ind();
+        self.sbc(addr);
+    }
+
+    fn sbc_dpy_ind(&mut self) {
+        let addr: Addr = self.addr_dpy_ind(false);
+        self.sbc(addr);
+    }
+
+    fn sbc_dpy_ind_long(&mut self) {
+        let addr: Addr = self.addr_dpy_ind_long();
+        self.sbc(addr);
+    }
+
+    fn sbc_long(&mut self) {
+        let addr: Addr = self.addr_long();
+        self.sbc(addr);
+    }
+
+    fn sbc_longx(&mut self) {
+        let addr: Addr = self.addr_longx();
+        self.sbc(addr);
+    }
+
+    fn sbc_sr(&mut self) {
+        let addr: Addr = self.addr_sr();
+        self.sbc(addr);
+    }
+
+    fn sbc_sry_ind(&mut self) {
+        let addr: Addr = self.addr_sry_ind();
+        self.sbc(addr);
+    }
+
+    fn sta_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.sta(addr);
+    }
+
+    fn sta_absx(&mut self) {
+        let addr: Addr = self.addr_absx(true);
+        self.sta(addr);
+    }
+
+    fn sta_absy(&mut self) {
+        let addr: Addr = self.addr_absy(true);
+        self.sta(addr);
+    }
+
+    fn sta_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.sta(addr);
+    }
+
+    fn sta_dp_ind(&mut self) {
+        let addr: Addr = self.addr_dp_ind();
+        self.sta(addr);
+    }
+
+    fn sta_dp_ind_long(&mut self) {
+        let addr: Addr = self.addr_dp_ind_long();
+        self.sta(addr);
+    }
+
+    fn sta_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.sta(addr);
+    }
+
+    fn sta_dpx_ind(&mut self) {
+        let addr: Addr = self.addr_dpx_ind();
+        self.sta(addr);
+    }
+
+    fn sta_dpy_ind(&mut self) {
+        let addr: Addr = self.addr_dpy_ind(true);
+        self.sta(addr);
+    }
+
+    fn sta_dpy_ind_long(&mut self) {
+        let addr: Addr = self.addr_dpy_ind_long();
+        self.sta(addr);
+    }
+
+    fn sta_long(&mut self) {
+        let addr: Addr = self.addr_long();
+        self.sta(addr);
+    }
+
+    fn sta_longx(&mut self) {
+        let addr: Addr = self.addr_longx();
+        self.sta(addr);
+    }
+
+    fn sta_sr(&mut self) {
+        let addr: Addr = self.addr_sr();
+        self.sta(addr);
+    }
+
+    fn sta_sry_ind(&mut self) {
+        let addr: Addr = self.addr_sry_ind();
+        self.sta(addr);
+    }
+
+    fn stx_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.stx(addr);
+    }
+
+    fn stx_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.stx(addr);
+    }
+
+    fn stx_dpy(&mut self) {
+        let addr: Addr = self.addr_dpy();
+        self.stx(addr);
+    }
+
+    fn sty_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.sty(addr);
+    }
+
+    fn sty_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.sty(addr);
+    }
+
+    fn sty_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.sty(addr);
+    }
+
+    fn stz_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.stz(addr);
+    }
+
+    fn stz_absx(&mut self) {
+        let addr: Addr = self.addr_absx(true);
+        self.stz(addr);
+    }
+
+    fn stz_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.stz(addr);
+    }
+
+    fn stz_dpx(&mut self) {
+        let addr: Addr = self.addr_dpx();
+        self.stz(addr);
+    }
+
+    fn trb_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.trb(addr);
+    }
+
+    fn trb_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.trb(addr);
+    }
+
+    fn tsb_abs(&mut self) {
+        let addr: Addr = self.addr_abs();
+        self.tsb(addr);
+    }
+
+    fn tsb_dp(&mut self) {
+        let addr: Addr = self.addr_dp();
+        self.tsb(addr);
+    }
+
+    ///// Low Power /////
+
+    fn wai_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        self.core.set_state(CpuState::Waiting);
+    }
+
+    fn stp_imp(&mut self) {
+        self.cycle();
+        self.cycle();
+        self.core.set_state(CpuState::Stopped);
+    }
+}
+
+impl Core for W65c816 {
+    fn core(&self) -> &Mos6502 {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Mos6502 {
+        &mut self.core
+    }
+
+    fn breakpoint(&self) -> Option<StopReason> {
+        let addr: Addr = Self::long_addr(self.pbr, self.pc);
+        self.breakpoints.contains(&addr).then_some(StopReason::Breakpoint(self.pc))
+    }
+
+    fn irq_masked(&self) -> bool {
+        W65c816Flags::I.get(self.status)
+    }
+
+    fn idle(&mut self) {
+        self.cycle();
+    }
+
+    fn service_interrupt(&mut self, nmi: bool) {
+        // NOTE: Emulation mode ignores a BRK that arrives with an
+        // IRQ, the same as the 6502.
+        let opcode: Byte = self.core.mem.get_byte(Self::long_addr(self.pbr, self.pc) as usize);
+        if self.e && !nmi && opcode == BRK_IMP {
+            self.pc = self.pc.wrapping_add(2);
+        }
+        self.cycle();
+        self.cycle();
+        let vector: Word = match (self.e, nmi) {
+            (true, true) => EMU_NMI_VECTOR,
+            (true, false) => EMU_IRQ_VECTOR,
+            (false, true) => NMI_VECTOR,
+            (false, false) => IRQ_VECTOR,
+        };
+        self.interrupt(vector, false);
+    }
+
+    fn run_instruction(&mut self) -> Result<(), ExecError> {
+        let pc: Word = self.pc;
+        let opcode: Byte = self.core.fetch_opcode_long(Self::long_addr(self.pbr, self.pc));
+        self.pc = self.pc.wrapping_add(1);
+        if self.on_core(opcode) {
+            self.sync_to_core();
+            let result = self.core.dispatch(pc, opcode);
+            self.sync_from_core();
+            return result;
+        }
+        LOOKUP[usize::from(opcode)](self);
+        self.core.count_instruction();
+        Ok(())
+    }
+
+    fn save_registers(&mut self) {
+        self.tick_regs = self.registers();
+    }
+
+    fn restore_registers(&mut self) {
+        self.set_registers(self.tick_regs);
+    }
+}
@@ -0,0 +1,902 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::w65c816::{
+        W65c816,
+        W65c816Flags,
+    };
+    use crate::mos6502::{
+        BusCycle,
+        CpuState,
+        Mos6502Config,
+        StopReason,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+        LONG_MEM_MAX,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+
+    // CLC, XCE
+    const NATIVE: [Byte; 2] = [instructions::CLC_IMP, instructions::XCE_IMP];
+    const NATIVE_CCOST: u64 = instructions::CLC_IMP_CCOST + instructions::XCE_IMP_CCOST;
+
+    fn cpu_816(program: &[Byte], mem: &[(usize, Byte)]) -> W65c816 {
+        let mut cpu = W65c816::with_long_memory();
+        for (i, b) in program.iter().enumerate() {
+            *cpu.mem_mut().at(0x0200 + i) = *b;
+        }
+        for (addr, b) in mem {
+            *cpu.mem_mut().at(*addr) = *b;
+        }
+        cpu.power_on();
+        cpu.set_pc(0x0200);
+        cpu.reset_counters();
+        cpu
+    }
+
+    fn cpu_816_native(program: &[Byte], mem: &[(usize, Byte)]) -> W65c816 {
+        cpu_816(&[&NATIVE[..], program].concat(), mem)
+    }
+
+    const RESET_CCOST: u64 = 7;
+
+    #[test]
+    fn w65c816_power_on() {
+        let mut cpu = cpu_816(&[], &[(0xFFFC, 0x00), (0xFFFD, 0x80)]);
+        cpu.set_accumulator(0x1234);
+        cpu.set_status_flag(W65c816Flags::C);
+
+        cpu.power_on();
+
+        assert!(cpu.emulation_flag());
+        assert!(cpu.memory_width_flag());
+        assert!(cpu.index_width_flag());
+        assert!(cpu.interrupts_disable_flag());
+        assert!(!cpu.decimal_mode_flag());
+        assert!(!cpu.carry_flag());
+        assert_eq!(cpu.get_accumulator(), 0x0000);
+        assert_eq!(cpu.get_pc(), 0x8000);
+        assert_eq!(cpu.get_sp(), 0x01FD);
+        assert_eq!(cpu.get_dp(), 0x0000);
+        assert_eq!(cpu.get_dbr(), 0x00);
+        assert_eq!(cpu.get_pbr(), 0x00);
+        assert_eq!(cpu.get_cycles(), RESET_CCOST);
+        assert_eq!(cpu.get_instructions(), 0);
+    }
+
+    #[test]
+    fn w65c816_reset() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x30,
+            instructions::LDX_IMM, 0x34, 0x12,
+            instructions::SED_IMP,
+        ], &[(0xFFFC, 0x00), (0xFFFD, 0x80), (0x00_8000, instructions::NOP_IMP)]);
+        let ccost = NATIVE_CCOST + 3 + 3 + instructions::SED_IMP_CCOST;
+        cpu.exe(Some(ccost)).unwrap();
+        cpu.set_accumulator(0xABCD);
+        cpu.set_sp(0x1F50);
+        cpu.set_dp(0x2000);
+        cpu.set_dbr(0x03);
+        cpu.set_pbr(0x04);
+
+        cpu.reset();
+
+        assert!(cpu.emulation_flag());
+        assert!(cpu.memory_width_flag());
+        assert!(cpu.index_width_flag());
+        assert!(cpu.interrupts_disable_flag());
+        // D is left alone like on the NMOS part.
+        assert!(cpu.decimal_mode_flag());
+        assert_eq!(cpu.get_pc(), 0x8000);
+        assert_eq!((cpu.get_dp(), cpu.get_dbr(), cpu.get_pbr()), (0x0000, 0x00, 0x00));
+        // A and B survive, X loses its high byte and SP is forced
+        // into page one before it drops by three.
+        assert_eq!(cpu.get_accumulator(), 0xABCD);
+        assert_eq!(cpu.get_xreg(), 0x0034);
+        assert_eq!(cpu.get_sp(), 0x014D);
+        assert_eq!(cpu.get_cycles(), ccost + RESET_CCOST);
+    }
+
+    #[test]
+    fn w65c816_reset_wsuppressed_pushes() {
+        let mut cpu = cpu_816(&[], &[(0x0150, 0x11), (0x014F, 0x22), (0x014E, 0x33)]);
+        cpu.set_sp(0x0150);
+
+        cpu.reset();
+
+        // SP moves as if three bytes were pushed but nothing is written.
+        assert_eq!(cpu.get_sp(), 0x014D);
+        assert_eq!(cpu.mem().get_byte(0x0150), 0x11);
+        assert_eq!(cpu.mem().get_byte(0x014F), 0x22);
+        assert_eq!(cpu.mem().get_byte(0x014E), 0x33);
+    }
+
+    #[test]
+    fn w65c816_reset_wtick_in_flight() {
+        let mut cpu = cpu_816(&[instructions::STA_ABS, 0x00, 0x40], &[(0xFFFC, 0x00), (0xFFFD, 0x80)]);
+        cpu.set_accumulator(0x0037);
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+
+        cpu.reset();
+        cpu.exe(Some(cpu.get_cycles())).unwrap();
+
+        // The STA never finishes.
+        assert_eq!(cpu.mem().get_byte(0x4000), 0x00);
+        assert_eq!(cpu.get_pc(), 0x8000);
+    }
+
+    #[test]
+    fn w65c816_emulation_program() {
+        let mut cpu = cpu_816(&[
+            instructions::LDA_IMM, 0x05,
+            instructions::CLC_IMP,
+            instructions::ADC_IMM, 0x03,
+            instructions::LDX_IMM, 0xFF,
+            instructions::LDY_ZPX, 0x80,
+        ], &[(0x007F, 0x42), (0x017F, 0x99)]);
+        let ccost = instructions::LDA_IMM_CCOST
+            + instructions::CLC_IMP_CCOST
+            + instructions::ADC_IMM_CCOST
+            + instructions::LDX_IMM_CCOST
+            + instructions::LDY_ZPX_CCOST;
+        cpu.exe(Some(ccost)).unwrap();
+
+        // Direct page indexing wraps within page zero like the 6502.
+        assert_eq!(cpu.get_accumulator(), 0x0008);
+        assert_eq!(cpu.get_yreg(), 0x0042);
+        assert_eq!(cpu.get_cycles(), ccost);
+    }
+
+    #[test]
+    fn w65c816_emulation_branch_wpage_boundary() {
+        let mut cpu = cpu_816(&[], &[(0x02F0, instructions::BRA_REL), (0x02F1, 0x20)]);
+        cpu.set_pc(0x02F0);
+        cpu.exe(Some(instructions::BRA_REL_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x0312);
+        assert_eq!(cpu.get_cycles(), instructions::BRA_REL_CCOST + 1);
+    }
+
+    #[test]
+    fn w65c816_native_branch_wpage_boundary() {
+        let mut cpu = cpu_816(&[], &[
+            (0x02EE, instructions::CLC_IMP),
+            (0x02EF, instructions::XCE_IMP),
+            (0x02F0, instructions::BRA_REL),
+            (0x02F1, 0x20),
+        ]);
+        cpu.set_pc(0x02EE);
+        cpu.exe(Some(NATIVE_CCOST + instructions::BRA_REL_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x0312);
+    }
+
+    #[test]
+    fn xce_imp() {
+        let mut cpu = cpu_816(&NATIVE, &[]);
+        cpu.exe(Some(NATIVE_CCOST)).unwrap();
+
+        assert!(!cpu.emulation_flag());
+        assert!(cpu.carry_flag());
+        assert!(cpu.memory_width_flag());
+        assert!(cpu.index_width_flag());
+    }
+
+    #[test]
+    fn xce_imp_wback_to_emulation() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x30,
+            instructions::LDX_IMM, 0x34, 0x12,
+            instructions::SEC_IMP,
+            instructions::XCE_IMP,
+        ], &[]);
+        cpu.set_sp(0x1FF0);
+        cpu.exe(Some(NATIVE_CCOST + 3 + 3 + 2 + 2)).unwrap();
+
+        assert!(cpu.emulation_flag());
+        assert!(cpu.memory_width_flag());
+        assert!(cpu.index_width_flag());
+        assert_eq!(cpu.get_xreg(), 0x0034);
+        assert_eq!(cpu.get_sp(), 0x01F0);
+    }
+
+    #[test]
+    fn rep_imm_wemulation() {
+        let mut cpu = cpu_816(&[instructions::REP_IMM, 0x30], &[]);
+        cpu.exe(Some(instructions::REP_IMM_CCOST)).unwrap();
+
+        assert!(cpu.memory_width_flag());
+        assert!(cpu.index_width_flag());
+    }
+
+    #[test]
+    fn rep_imm_wide_immediates() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x30,
+            instructions::LDA_IMM, 0x34, 0x12,
+            instructions::LDX_IMM, 0xCD, 0xAB,
+        ], &[]);
+        let ccost = NATIVE_CCOST + instructions::REP_IMM_CCOST + 3 + 3;
+        cpu.exe(Some(ccost)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x1234);
+        assert_eq!(cpu.get_xreg(), 0xABCD);
+        assert!(cpu.negative_flag());
+        assert_eq!(cpu.get_pc(), 0x020A);
+        assert_eq!(cpu.get_cycles(), ccost);
+    }
+
+    #[test]
+    fn sep_imm_wclears_index_high_bytes() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x10,
+            instructions::LDX_IMM, 0x34, 0x12,
+            instructions::LDY_IMM, 0x78, 0x56,
+            instructions::SEP_IMM, 0x10,
+        ], &[]);
+        cpu.exe(Some(NATIVE_CCOST + 3 + 3 + 3 + 3)).unwrap();
+
+        assert_eq!(cpu.get_xreg(), 0x0034);
+        assert_eq!(cpu.get_yreg(), 0x0078);
+    }
+
+    #[test]
+    fn sep_imm_wkeeps_b() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x20,
+            instructions::LDA_IMM, 0x34, 0x12,
+            instructions::SEP_IMM, 0x20,
+            instructions::LDA_IMM, 0xFF,
+        ], &[]);
+        cpu.exe(Some(NATIVE_CCOST + 3 + 3 + 3 + 2)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x12FF);
+    }
+
+    #[test]
+    fn adc_imm_wide() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x20,
+            instructions::CLC_IMP,
+            instructions::LDA_IMM, 0xFF, 0x80,
+            instructions::ADC_IMM, 0x01, 0x7F,
+        ], &[]);
+        cpu.exe(Some(NATIVE_CCOST + 3 + 2 + 3 + 3)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0000);
+        assert!(cpu.carry_flag());
+        assert!(cpu.zero_flag());
+        assert!(!cpu.overflow_flag());
+        assert!(!cpu.negative_flag());
+    }
+
+    #[test]
+    fn adc_imm_wide_wdecimal() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x20,
+            instructions::SED_IMP,
+            instructions::CLC_IMP,
+            instructions::LDA_IMM, 0x99, 0x19,
+            instructions::ADC_IMM, 0x01, 0x00,
+        ], &[]);
+        cpu.exe(Some(NATIVE_CCOST + 3 + 2 + 2 + 3 + 3)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x2000);
+        assert!(!cpu.carry_flag());
+    }
+
+    #[test]
+    fn sbc_imm_wide_wdecimal() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x20,
+            instructions::SED_IMP,
+            instructions::SEC_IMP,
+            instructions::LDA_IMM, 0x00, 0x20,
+            instructions::SBC_IMM, 0x01, 0x00,
+        ], &[]);
+        cpu.exe(Some(NATIVE_CCOST + 3 + 2 + 2 + 3 + 3)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x1999);
+        assert!(cpu.carry_flag());
+    }
+
+    #[test]
+    fn sta_abs_wide() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x20,
+            instructions::LDA_IMM, 0xEF, 0xBE,
+            instructions::STA_ABS, 0x00, 0x40,
+        ], &[]);
+        let ccost = NATIVE_CCOST + 3 + 3 + instructions::STA_ABS_CCOST + 1;
+        cpu.exe(Some(ccost)).unwrap();
+
+        assert_eq!(cpu.mem().get_byte(0x4000), 0xEF);
+        assert_eq!(cpu.mem().get_byte(0x4001), 0xBE);
+        assert_eq!(cpu.get_cycles(), ccost);
+    }
+
+    #[test]
+    fn tcd_imp_wdp_penalty() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x20,
+            instructions::LDA_IMM, 0x01, 0x10,
+            instructions::TCD_IMP,
+            instructions::SEP_IMM, 0x20,
+            instructions::LDA_ZP, 0x10,
+        ], &[(0x1011, 0x42)]);
+        let ccost = NATIVE_CCOST + 3 + 3 + instructions::TCD_IMP_CCOST + 3 + instructions::LDA_ZP_CCOST + 1;
+        cpu.exe(Some(ccost)).unwrap();
+
+        assert_eq!(cpu.get_dp(), 0x1001);
+        assert_eq!(cpu.get_accumulator() & 0xFF, 0x42);
+        assert_eq!(cpu.get_cycles(), ccost);
+    }
+
+    #[test]
+    fn lda_abs_wdbr() {
+        let mut cpu = cpu_816(&[instructions::LDA_ABS, 0x34, 0x12], &[(0x02_1234, 0x42)]);
+        cpu.set_dbr(0x02);
+        cpu.exe(Some(instructions::LDA_ABS_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0042);
+    }
+
+    #[test]
+    fn lda_longx_wbank_boundary() {
+        let mut cpu = cpu_816(&[
+            instructions::LDX_IMM, 0x01,
+            instructions::LDA_LONGX, 0xFF, 0xFF, 0x01,
+        ], &[(0x02_0000, 0x42)]);
+        let ccost = instructions::LDX_IMM_CCOST + instructions::LDA_LONGX_CCOST;
+        cpu.exe(Some(ccost)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0042);
+        assert_eq!(cpu.get_cycles(), ccost);
+    }
+
+    #[test]
+    fn lda_long() {
+        let mut cpu = cpu_816(&[instructions::LDA_LONG, 0x56, 0x34, 0x12], &[(0x12_3456, 0x42)]);
+        cpu.exe(Some(instructions::LDA_LONG_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0042);
+        assert_eq!(cpu.get_pc(), 0x0204);
+    }
+
+    #[test]
+    fn lda_dpy_ind_long() {
+        let mut cpu = cpu_816(&[
+            instructions::LDY_IMM, 0x10,
+            instructions::LDA_DPY_IND_LONG, 0x20,
+        ], &[(0x0020, 0x00), (0x0021, 0x80), (0x0022, 0x03), (0x03_8010, 0x42)]);
+        let ccost = instructions::LDY_IMM_CCOST + instructions::LDA_DPY_IND_LONG_CCOST;
+        cpu.exe(Some(ccost)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0042);
+        assert_eq!(cpu.get_cycles(), ccost);
+    }
+
+    #[test]
+    fn lda_sr() {
+        let mut cpu = cpu_816(&[
+            instructions::LDA_IMM, 0x42,
+            instructions::PHA_IMP,
+            instructions::LDA_IMM, 0x00,
+            instructions::LDA_SR, 0x01,
+        ], &[]);
+        let ccost = 2 * instructions::LDA_IMM_CCOST + instructions::PHA_IMP_CCOST + instructions::LDA_SR_CCOST;
+        cpu.exe(Some(ccost)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0042);
+        assert_eq!(cpu.get_cycles(), ccost);
+    }
+
+    #[test]
+    fn jsl_long_rtl_imp() {
+        let mut cpu = cpu_816(&[instructions::JSL_LONG, 0x00, 0x80, 0x01], &[(0x01_8000, instructions::RTL_IMP)]);
+        cpu.exe(Some(instructions::JSL_LONG_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pbr(), 0x01);
+        assert_eq!(cpu.get_pc(), 0x8000);
+        assert_eq!(cpu.mem().get_byte(0x01FD), 0x00);
+        assert_eq!(cpu.mem().get_byte(0x01FC), 0x02);
+        assert_eq!(cpu.mem().get_byte(0x01FB), 0x03);
+
+        cpu.exe(Some(instructions::JSL_LONG_CCOST + instructions::RTL_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pbr(), 0x00);
+        assert_eq!(cpu.get_pc(), 0x0204);
+        assert_eq!(cpu.get_sp(), 0x01FD);
+    }
+
+    #[test]
+    fn jml_long() {
+        let mut cpu = cpu_816(&[instructions::JML_LONG, 0x34, 0x12, 0x0A], &[]);
+        cpu.exe(Some(instructions::JML_LONG_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pbr(), 0x0A);
+        assert_eq!(cpu.get_pc(), 0x1234);
+        assert_eq!(cpu.get_cycles(), instructions::JML_LONG_CCOST);
+    }
+
+    #[test]
+    fn brl_rel_long() {
+        let mut cpu = cpu_816(&[instructions::BRL_REL_LONG, 0x00, 0x01], &[]);
+        cpu.exe(Some(instructions::BRL_REL_LONG_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x0303);
+    }
+
+    #[test]
+    fn pea_abs_pei_dp_ind_per_rel_long() {
+        let mut cpu = cpu_816(&[
+            instructions::PEA_ABS, 0x34, 0x12,
+            instructions::PEI_DP_IND, 0x10,
+            instructions::PER_REL_LONG, 0x10, 0x00,
+        ], &[(0x0010, 0x78), (0x0011, 0x56)]);
+        let ccost = instructions::PEA_ABS_CCOST + instructions::PEI_DP_IND_CCOST + instructions::PER_REL_LONG_CCOST;
+        cpu.exe(Some(ccost)).unwrap();
+
+        assert_eq!(cpu.mem().get_byte(0x01FD), 0x12);
+        assert_eq!(cpu.mem().get_byte(0x01FC), 0x34);
+        assert_eq!(cpu.mem().get_byte(0x01FB), 0x56);
+        assert_eq!(cpu.mem().get_byte(0x01FA), 0x78);
+        assert_eq!(cpu.mem().get_byte(0x01F9), 0x02);
+        assert_eq!(cpu.mem().get_byte(0x01F8), 0x18);
+        assert_eq!(cpu.get_cycles(), ccost);
+    }
+
+    #[test]
+    fn mvn_blk() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x30,
+            instructions::LDA_IMM, 0x02, 0x00,
+            instructions::LDX_IMM, 0x00, 0x10,
+            instructions::LDY_IMM, 0x00, 0x20,
+            instructions::MVN_BLK, 0x00, 0x01,
+            instructions::STP_IMP,
+        ], &[(0x01_1000, 0x11), (0x01_1001, 0x22), (0x01_1002, 0x33)]);
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Stopped);
+
+        assert_eq!(cpu.mem().get_byte(0x2000), 0x11);
+        assert_eq!(cpu.mem().get_byte(0x2001), 0x22);
+        assert_eq!(cpu.mem().get_byte(0x2002), 0x33);
+        assert_eq!(cpu.get_accumulator(), 0xFFFF);
+        assert_eq!(cpu.get_xreg(), 0x1003);
+        assert_eq!(cpu.get_yreg(), 0x2003);
+        assert_eq!(cpu.get_dbr(), 0x00);
+        assert_eq!(cpu.get_cycles(), NATIVE_CCOST + 3 + 3 + 3 + 3 + 3 * instructions::MVN_BLK_CCOST + instructions::STP_IMP_CCOST);
+    }
+
+    #[test]
+    fn mvp_blk() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x30,
+            instructions::LDA_IMM, 0x01, 0x00,
+            instructions::LDX_IMM, 0x01, 0x10,
+            instructions::LDY_IMM, 0x01, 0x20,
+            instructions::MVP_BLK, 0x03, 0x00,
+            instructions::STP_IMP,
+        ], &[(0x1000, 0x11), (0x1001, 0x22)]);
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Stopped);
+
+        assert_eq!(cpu.mem().get_byte(0x03_2000), 0x11);
+        assert_eq!(cpu.mem().get_byte(0x03_2001), 0x22);
+        assert_eq!(cpu.get_xreg(), 0x0FFF);
+        assert_eq!(cpu.get_yreg(), 0x1FFF);
+        assert_eq!(cpu.get_dbr(), 0x03);
+    }
+
+    #[test]
+    fn xba_imp() {
+        let mut cpu = cpu_816(&[instructions::XBA_IMP], &[]);
+        cpu.set_accumulator(0x8012);
+        cpu.exe(Some(instructions::XBA_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x1280);
+        assert!(cpu.negative_flag());
+        assert!(!cpu.zero_flag());
+    }
+
+    #[test]
+    fn tcs_imp_wnative() {
+        let mut cpu = cpu_816_native(&[
+            instructions::REP_IMM, 0x20,
+            instructions::LDA_IMM, 0xF0, 0x1F,
+            instructions::TCS_IMP,
+            instructions::PHA_IMP,
+        ], &[]);
+        let ccost = NATIVE_CCOST + 3 + 3 + instructions::TCS_IMP_CCOST + instructions::PHA_IMP_CCOST + 1;
+        cpu.exe(Some(ccost)).unwrap();
+
+        assert_eq!(cpu.get_sp(), 0x1FEE);
+        assert_eq!(cpu.mem().get_byte(0x1FF0), 0x1F);
+        assert_eq!(cpu.mem().get_byte(0x1FEF), 0xF0);
+    }
+
+    #[test]
+    fn brk_imp_wnative() {
+        let mut cpu = cpu_816_native(&[instructions::BRK_IMP, 0x00], &[(0xFFE6, 0x00), (0xFFE7, 0x90)]);
+        cpu.set_status_flag(W65c816Flags::D);
+        cpu.exe(Some(NATIVE_CCOST + instructions::BRK_IMP_CCOST + 1)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x9000);
+        assert_eq!(cpu.mem().get_byte(0x01FD), 0x00);
+        assert_eq!(cpu.mem().get_byte(0x01FC), 0x02);
+        assert_eq!(cpu.mem().get_byte(0x01FB), 0x04);
+        assert_eq!(cpu.mem().get_byte(0x01FA), 0x3D);
+        assert!(cpu.interrupts_disable_flag());
+        assert!(!cpu.decimal_mode_flag());
+    }
+
+    #[test]
+    fn brk_imp_wemulation() {
+        let mut cpu = cpu_816(&[instructions::BRK_IMP, 0x00], &[(0xFFFE, 0x00), (0xFFFF, 0x90)]);
+        cpu.clear_status_flag(W65c816Flags::I);
+        cpu.exe(Some(instructions::BRK_IMP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x9000);
+        assert_eq!(cpu.mem().get_byte(0x01FD), 0x02);
+        assert_eq!(cpu.mem().get_byte(0x01FC), 0x02);
+        assert_eq!(cpu.mem().get_byte(0x01FB), 0x30);
+    }
+
+    #[test]
+    fn rti_imp_wnative() {
+        let mut cpu = cpu_816_native(&[instructions::CLI_IMP], &[
+            (0xFFEE, 0x00),
+            (0xFFEF, 0x80),
+            (0x8000, instructions::RTI_IMP),
+        ]);
+        let ccost = NATIVE_CCOST + instructions::CLI_IMP_CCOST;
+        cpu.exe(Some(ccost)).unwrap();
+
+        cpu.set_irq_line(true);
+        cpu.exe(Some(ccost + 8)).unwrap();
+        assert_eq!(cpu.get_pc(), 0x8000);
+        assert_eq!(cpu.mem().get_byte(0x01FD), 0x00);
+        assert!(cpu.interrupts_disable_flag());
+
+        cpu.set_irq_line(false);
+        cpu.exe(Some(ccost + 8 + instructions::RTI_IMP_CCOST + 1)).unwrap();
+        assert_eq!(cpu.get_pc(), 0x0203);
+        assert!(!cpu.interrupts_disable_flag());
+    }
+
+    #[test]
+    fn wai_imp() {
+        let mut cpu = cpu_816(&[instructions::WAI_IMP], &[]);
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Waiting);
+        assert_eq!(cpu.state(), CpuState::Waiting);
+        assert_eq!(cpu.get_cycles(), instructions::WAI_IMP_CCOST);
+    }
+
+    #[test]
+    fn adc_imm_wemulation_wdecimal() {
+        let mut cpu = cpu_816(&[
+            instructions::SED_IMP,
+            instructions::CLC_IMP,
+            instructions::LDA_IMM, 0x99,
+            instructions::ADC_IMM, 0x01,
+        ], &[]);
+        cpu.exe(Some(2 * instructions::SED_IMP_CCOST + instructions::LDA_IMM_CCOST + instructions::ADC_IMM_CCOST)).unwrap();
+
+        // Emulation mode runs the NMOS decimal adder, Z and N come
+        // from the binary sum.
+        assert_eq!(cpu.get_accumulator(), 0x0000);
+        assert!(cpu.carry_flag());
+        assert!(!cpu.zero_flag());
+        assert!(cpu.negative_flag());
+    }
+
+    #[test]
+    fn jmp_abs_ind_wemulation_wpage_boundary() {
+        let mut cpu = cpu_816(&[instructions::JMP_ABS_IND, 0xFF, 0x10], &[
+            (0x10FF, 0x34),
+            (0x1000, 0x12),
+            (0x1100, 0x56),
+        ]);
+        cpu.exe(Some(instructions::JMP_ABS_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x1234);
+    }
+
+    // Emulation mode with the program in bank 1, data in bank 2 and
+    // the direct page at $0100.
+    fn cpu_816_wbanks(program: &[Byte], mem: &[(usize, Byte)]) -> W65c816 {
+        let mut cpu = cpu_816(&[], mem);
+        for (i, b) in program.iter().enumerate() {
+            *cpu.mem_mut().at(0x01_0200 + i) = *b;
+        }
+        cpu.set_pbr(0x01);
+        cpu.set_dbr(0x02);
+        cpu.set_dp(0x0100);
+        cpu
+    }
+
+    #[test]
+    fn jmp_abs_ind_wemulation_wbanks() {
+        let mut cpu = cpu_816_wbanks(&[instructions::JMP_ABS_IND, 0xFF, 0x10], &[
+            (0x10FF, 0x34),
+            (0x1000, 0x12),
+            (0x1100, 0x56),
+        ]);
+        cpu.exe(Some(instructions::JMP_ABS_IND_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x1234);
+        assert_eq!(cpu.get_pbr(), 0x01);
+    }
+
+    #[test]
+    fn inc_abs_wemulation_wbanks() {
+        let mut cpu = cpu_816_wbanks(&[instructions::INC_ABS, 0x00, 0x40], &[(0x02_4000, 0x41)]);
+
+        let bus: Vec<BusCycle> = (0..instructions::INC_ABS_CCOST).map(|_| cpu.tick().unwrap()).collect();
+
+        // The NMOS read-modify-write writes the old value back before
+        // the new one.
+        assert_eq!(bus.iter().map(|b| b.read).collect::<Vec<_>>(), [true, true, true, true, false, false]);
+        assert_eq!(bus[3], BusCycle { bank: 0x02, addr: 0x4000, data: 0x41, read: true, sync: false });
+        assert_eq!(bus[4], BusCycle { bank: 0x02, addr: 0x4000, data: 0x41, read: false, sync: false });
+        assert_eq!(bus[5], BusCycle { bank: 0x02, addr: 0x4000, data: 0x42, read: false, sync: false });
+    }
+
+    #[test]
+    fn adc_zp_wemulation_wdecimal_wbanks() {
+        let mut cpu = cpu_816_wbanks(&[
+            instructions::SED_IMP,
+            instructions::CLC_IMP,
+            instructions::LDA_IMM, 0x99,
+            instructions::ADC_ZP, 0x10,
+        ], &[(0x0110, 0x01)]);
+        cpu.exe(Some(2 * instructions::SED_IMP_CCOST + instructions::LDA_IMM_CCOST + instructions::ADC_ZP_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_accumulator(), 0x0000);
+        assert!(cpu.carry_flag());
+        assert!(!cpu.zero_flag());
+        assert!(cpu.negative_flag());
+    }
+
+    #[test]
+    fn lda_sr_wemulation() {
+        let program = [instructions::LDA_SR, 0x03];
+        let mem = [(0x01F3, 0x42)];
+
+        // $A3 is undocumented on the NMOS part and always runs as the
+        // 65C816 instruction, whatever the direct page and banks hold.
+        for mut cpu in [cpu_816(&program, &mem), cpu_816_wbanks(&program, &mem)] {
+            cpu.set_sp(0x01F0);
+            cpu.exe(Some(instructions::LDA_SR_CCOST)).unwrap();
+
+            assert_eq!(cpu.get_accumulator(), 0x0042);
+            assert_eq!(cpu.get_pc(), 0x0202);
+        }
+    }
+
+    #[test]
+    fn irq_wemulation_wbanks() {
+        let mut cpu = cpu_816_wbanks(&[instructions::NOP_IMP], &[(0xFFFE, 0x00), (0xFFFF, 0x90)]);
+        cpu.clear_status_flag(W65c816Flags::I);
+        cpu.set_status_flag(W65c816Flags::D);
+        cpu.set_irq_line(true);
+        cpu.exe(Some(7)).unwrap();
+
+        // PBR is not pushed in emulation mode and D is left alone
+        // like on the NMOS part.
+        assert_eq!((cpu.get_pbr(), cpu.get_pc()), (0x00, 0x9000));
+        assert_eq!(cpu.mem().get_byte(0x01FD), 0x02);
+        assert_eq!(cpu.mem().get_byte(0x01FC), 0x00);
+        assert_eq!(cpu.mem().get_byte(0x01FB), 0x28);
+        assert!(cpu.interrupts_disable_flag());
+        assert!(cpu.decimal_mode_flag());
+    }
+
+    #[test]
+    fn brk_imp_wemulation_wirq_wbanks() {
+        let mut cpu = cpu_816_wbanks(&[instructions::BRK_IMP, 0x00], &[(0xFFFE, 0x00), (0xFFFF, 0x90)]);
+        cpu.clear_status_flag(W65c816Flags::I);
+        cpu.set_irq_line(true);
+        cpu.exe(Some(instructions::BRK_IMP_CCOST)).unwrap();
+
+        // The IRQ takes over the BRK, B is clear in the pushed status
+        // and the return address skips the BRK and its signature.
+        assert_eq!((cpu.get_pbr(), cpu.get_pc()), (0x00, 0x9000));
+        assert_eq!(cpu.mem().get_byte(0x01FD), 0x02);
+        assert_eq!(cpu.mem().get_byte(0x01FC), 0x02);
+        assert_eq!(cpu.mem().get_byte(0x01FB), 0x20);
+        assert_eq!(cpu.get_instructions(), 0);
+    }
+
+    #[test]
+    fn brk_imp_wemulation_wnmi_hijack_wbanks() {
+        let mut cpu = cpu_816_wbanks(&[instructions::BRK_IMP, 0x00], &[
+            (0xFFFA, 0x00),
+            (0xFFFB, 0x50),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ]);
+        cpu.clear_status_flag(W65c816Flags::I);
+
+        for _ in 0..5 {
+            cpu.tick().unwrap();
+        }
+        cpu.trigger_nmi();
+        let vector = cpu.tick().unwrap();
+        cpu.tick().unwrap();
+
+        assert_eq!((vector.bank, vector.addr), (0x00, 0xFFFA));
+        assert_eq!((cpu.get_pbr(), cpu.get_pc()), (0x00, 0x5000));
+        assert_eq!(cpu.mem().get_byte(0x01FB), 0x30);
+
+        // It was serviced by the hijack and is not taken again.
+        assert!(cpu.tick().unwrap().sync);
+    }
+
+    // SED, CLC, LDA #$99, ADC #$01, PHP, CLD, then ASL $40,X for X
+    // from 3 down to 1, STA $4000, JMP ($10FF) into a BRK whose
+    // handler bumps $41 and returns to LDY #$7F.
+    const PARITY: [(u16, Byte); 30] = [
+        (0x0200, instructions::SED_IMP),
+        (0x0201, instructions::CLC_IMP),
+        (0x0202, instructions::LDA_IMM), (0x0203, 0x99),
+        (0x0204, instructions::ADC_IMM), (0x0205, 0x01),
+        (0x0206, instructions::PHP_IMP),
+        (0x0207, instructions::CLD_IMP),
+        (0x0208, instructions::LDX_IMM), (0x0209, 0x03),
+        (0x020A, instructions::ASL_ZPX), (0x020B, 0x40),
+        (0x020C, instructions::DEX_IMP),
+        (0x020D, instructions::BNE_REL), (0x020E, 0xFB),
+        (0x020F, instructions::STA_ABS), (0x0210, 0x00), (0x0211, 0x40),
+        (0x0212, instructions::JMP_ABS_IND), (0x0213, 0xFF), (0x0214, 0x10),
+        (0x10FF, 0x34), (0x1000, 0x12), (0x1100, 0x56),
+        (0x1234, instructions::BRK_IMP),
+        (0x1236, instructions::LDY_IMM), (0x1237, 0x7F),
+        (0x3000, instructions::INC_ZP), (0x3001, 0x41),
+        (0x3002, instructions::RTI_IMP),
+    ];
+    const PARITY_VECTORS: [(u16, Byte); 5] = [
+        (0xFFFE, 0x00), (0xFFFF, 0x30),
+        (0x0041, 0x81), (0x0042, 0x40), (0x0043, 0x01),
+    ];
+
+    fn parity_cpus() -> (crate::mos6502::Mos6502, W65c816) {
+        let program: Vec<(u16, Byte)> = [&PARITY[..], &PARITY_VECTORS[..]].concat();
+        let mut nmos = tests_utils::cpu_mem_set(program.clone());
+        nmos.set_pc(0x0200);
+        let mem: Vec<(usize, Byte)> = program.iter().map(|&(addr, b)| (usize::from(addr), b)).collect();
+        let mut cpu = cpu_816(&[], &mem);
+        // Both start from the same flags, `cpu_mem_set` clears I.
+        cpu.clear_status_flag(W65c816Flags::I);
+        cpu.reset_counters();
+        (nmos, cpu)
+    }
+
+    #[test]
+    fn w65c816_emulation_parity_wmos6502() {
+        let (mut nmos, mut cpu) = parity_cpus();
+        nmos.add_breakpoint(0x1238);
+        cpu.add_breakpoint(0x00_1238);
+
+        assert_eq!(nmos.exe(None).unwrap(), StopReason::Breakpoint(0x1238));
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Breakpoint(0x1238));
+
+        assert_eq!(cpu.get_accumulator(), Word::from(nmos.get_accumulator()));
+        assert_eq!(cpu.get_xreg(), Word::from(nmos.get_xreg()));
+        assert_eq!(cpu.get_yreg(), Word::from(nmos.get_yreg()));
+        assert_eq!(cpu.get_sp(), 0x0100 | Word::from(nmos.get_sp()));
+        assert_eq!(cpu.get_pc(), nmos.get_pc());
+        // Bits 4 and 5 are M and X on the 65C816, B and U on the 6502.
+        assert_eq!(cpu.get_status() & 0xCF, nmos.get_status() & 0xCF);
+        for addr in (0x0000..0x0200).chain([0x4000]) {
+            assert_eq!(cpu.mem().get_byte(addr), nmos.mem.get_byte(addr), "addr: {addr:04x}");
+        }
+        assert_eq!(cpu.get_cycles(), nmos.get_cycles());
+        assert_eq!(cpu.get_instructions(), nmos.get_instructions());
+    }
+
+    #[test]
+    fn w65c816_emulation_tick_parity_wmos6502() {
+        let (mut probe, _) = parity_cpus();
+        probe.add_breakpoint(0x1238);
+        probe.exe(None).unwrap();
+        let (mut nmos, mut cpu) = parity_cpus();
+
+        for i in 0..probe.get_cycles() {
+            assert_eq!(cpu.tick().unwrap(), nmos.tick().unwrap(), "cycle: {i}");
+        }
+        assert_eq!(cpu.get_pc(), 0x1238);
+    }
+
+    #[test]
+    fn w65c816_tick_wlong_addr() {
+        let mut cpu = cpu_816(&[instructions::LDA_LONG, 0x56, 0x34, 0x12], &[(0x12_3456, 0x42)]);
+
+        let bus: Vec<BusCycle> = (0..instructions::LDA_LONG_CCOST).map(|_| cpu.tick().unwrap()).collect();
+
+        assert!(bus[0].sync);
+        assert_eq!(bus[4], BusCycle { bank: 0x12, addr: 0x3456, data: 0x42, read: true, sync: false });
+        assert_eq!(cpu.get_accumulator(), 0x0042);
+        assert_eq!(cpu.get_cycles(), instructions::LDA_LONG_CCOST);
+    }
+
+    #[test]
+    fn w65c816_breakpoint_wbank() {
+        let mut cpu = cpu_816(&[instructions::JML_LONG, 0x00, 0x80, 0x01], &[(0x01_8000, instructions::NOP_IMP)]);
+        cpu.add_breakpoint(0x00_8000);
+        cpu.add_breakpoint(0x01_8000);
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Breakpoint(0x8000));
+        assert_eq!(cpu.get_pbr(), 0x01);
+        assert_eq!(cpu.get_instructions(), 1);
+    }
+
+    #[test]
+    fn w65c816_halt() {
+        let mut cpu = cpu_816(&[instructions::NOP_IMP, instructions::NOP_IMP], &[]);
+        cpu.halt_handle().store(true, std::sync::atomic::Ordering::Relaxed);
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::HaltRequested);
+        assert_eq!(cpu.get_pc(), 0x0200);
+    }
+
+    #[test]
+    fn w65c816_step() {
+        let mut cpu = cpu_816(&[instructions::XBA_IMP], &[]);
+        cpu.set_accumulator(0x8012);
+
+        let record = cpu.step().unwrap().unwrap();
+
+        assert_eq!((record.pc, record.opcode), (0x00_0200, instructions::XBA_IMP));
+        assert_eq!(record.cycles, instructions::XBA_IMP_CCOST);
+        assert_eq!(record.before.a, 0x8012);
+        assert_eq!(record.after.a, 0x1280);
+    }
+
+    #[test]
+    fn w65c816_run_for_wwai() {
+        let mut cpu = cpu_816(&[instructions::WAI_IMP], &[]);
+
+        assert_eq!(cpu.run_for(100).unwrap(), (100, StopReason::Waiting));
+        assert_eq!(cpu.get_instructions(), 1);
+    }
+
+    #[test]
+    fn w65c816_with_config() {
+        let mut cpu = W65c816::with_config(Memory::with_size(LONG_MEM_MAX), Mos6502Config {
+            jmp_indirect_bug: false,
+            clock_hz: 2_000_000,
+            ..Mos6502Config::default()
+        });
+        *cpu.mem_mut().at(0x0200) = instructions::JMP_ABS_IND;
+        *cpu.mem_mut().at(0x0201) = 0xFF;
+        *cpu.mem_mut().at(0x0202) = 0x10;
+        *cpu.mem_mut().at(0x10FF) = 0x34;
+        *cpu.mem_mut().at(0x1100) = 0x12;
+        cpu.power_on();
+        cpu.set_pc(0x0200);
+        cpu.reset_counters();
+        cpu.exe(Some(instructions::JMP_ABS_IND_CCOST)).unwrap();
+
+        // Without the bug the pointer's high byte comes from the
+        // next page.
+        assert_eq!(cpu.get_pc(), 0x1234);
+        assert_eq!(cpu.cycles_to_duration(2_000_000), Duration::from_secs(1));
+        assert_eq!(cpu.elapsed(), Duration::from_nanos(2_500));
+        assert_eq!(cpu.get_instructions(), 1);
+
+        cpu.reset_counters();
+        assert_eq!((cpu.get_cycles(), cpu.get_instructions()), (0, 0));
+        assert_eq!(cpu.elapsed(), Duration::ZERO);
+    }
+}
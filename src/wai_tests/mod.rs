@@ -0,0 +1,103 @@
+#![allow(unused_imports)]
+
+#[cfg(test)]
+mod tests {
+    use crate::mos6502::{
+        Mos6502,
+        Mos6502Flags,
+        Mos6502Variant,
+        CpuState,
+        StopReason,
+    };
+    use crate::memory::{
+        Memory,
+        Word,
+        Byte,
+    };
+    use crate::instructions;
+    use crate::tests_utils;
+    use crate::tests_utils::PC_START;
+
+    const IRQ_CCOST: u64 = 7;
+
+    fn wai_cpu() -> Mos6502 {
+        let mut cpu = tests_utils::cpu_mem_set_wvariant(vec![
+            (0x0200, instructions::WAI_IMP),
+            (0x0201, instructions::LDA_IMM),
+            (0x0202, 0x84),
+            (0xFFFA, 0x00),
+            (0xFFFB, 0x50),
+            (0xFFFE, 0x00),
+            (0xFFFF, 0x40),
+        ], Mos6502Variant::Wdc65C02S);
+        cpu.set_pc(0x0200);
+        cpu
+    }
+
+    #[test]
+    fn wai_imp() {
+        let mut cpu = wai_cpu();
+
+        assert_eq!(cpu.exe(None).unwrap(), StopReason::Waiting);
+        assert_eq!(cpu.state(), CpuState::Waiting);
+        assert_eq!(cpu.get_pc(), 0x0201);
+        assert_eq!(cpu.get_cycles(), instructions::WAI_IMP_CCOST);
+
+        // Nothing runs until an interrupt line is asserted, the
+        // cycles still pass.
+        assert_eq!(cpu.exe(Some(100)).unwrap(), StopReason::Waiting);
+        assert_eq!(cpu.get_pc(), 0x0201);
+        assert_eq!(cpu.get_cycles(), 100);
+    }
+
+    #[test]
+    fn wai_imp_wirq() {
+        let mut cpu = wai_cpu();
+        cpu.exe(None).unwrap();
+
+        cpu.set_irq_line(true);
+        cpu.exe(Some(instructions::WAI_IMP_CCOST + IRQ_CCOST)).unwrap();
+
+        assert_eq!(cpu.state(), CpuState::Running);
+        assert_eq!(cpu.get_pc(), 0x4000);
+        assert_eq!(cpu.mem.get_byte(0x01FC), 0x01);
+    }
+
+    #[test]
+    fn wai_imp_wmasked_irq() {
+        let mut cpu = wai_cpu();
+        cpu.set_status_flag(Mos6502Flags::I);
+        cpu.exe(None).unwrap();
+
+        cpu.set_irq_line(true);
+        cpu.exe(Some(instructions::WAI_IMP_CCOST + instructions::LDA_IMM_CCOST)).unwrap();
+
+        // Resumes after the WAI without taking the interrupt.
+        assert_eq!(cpu.get_accumulator(), 0x84);
+        assert_eq!(cpu.get_pc(), 0x0203);
+    }
+
+    #[test]
+    fn wai_imp_wnmi() {
+        let mut cpu = wai_cpu();
+        cpu.set_status_flag(Mos6502Flags::I);
+        cpu.exe(None).unwrap();
+
+        cpu.trigger_nmi();
+        cpu.exe(Some(instructions::WAI_IMP_CCOST + IRQ_CCOST)).unwrap();
+
+        assert_eq!(cpu.get_pc(), 0x5000);
+    }
+
+    #[test]
+    fn wai_imp_wrockwell() {
+        let mut cpu = wai_cpu();
+        cpu.config_mut().variant = Mos6502Variant::Rockwell65C02;
+
+        // Not decoded on the R65C02, $CB is a one cycle NOP there.
+        cpu.exe(Some(1 + instructions::LDA_IMM_CCOST)).unwrap();
+
+        assert_eq!(cpu.state(), CpuState::Running);
+        assert_eq!(cpu.get_accumulator(), 0x84);
+    }
+}